@@ -16,7 +16,7 @@ pub enum Alignment {
 impl From<Horizontal> for Alignment {
     fn from(horizontal: Horizontal) -> Self {
         match horizontal {
-            Horizontal::Left => Self::Start,
+            Horizontal::Left | Horizontal::Justified => Self::Start,
             Horizontal::Center => Self::Center,
             Horizontal::Right => Self::End,
         }
@@ -44,6 +44,13 @@ pub enum Horizontal {
 
     /// Align right
     Right,
+
+    /// Align left, stretching the lines of text to fill the available
+    /// width
+    ///
+    /// The last line of each paragraph is not stretched. Anything other than
+    /// text is aligned left.
+    Justified,
 }
 
 impl From<Alignment> for Horizontal {
@@ -282,6 +282,14 @@ where
         self.widget.diff(tree);
     }
 
+    fn semantics(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+    ) -> Option<widget::Semantics> {
+        self.widget.semantics(tree, layout)
+    }
+
     fn size(&self) -> Size<Length> {
         self.widget.size()
     }
@@ -426,6 +434,14 @@ where
         self.element.widget.diff(tree);
     }
 
+    fn semantics(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+    ) -> Option<widget::Semantics> {
+        self.element.widget.semantics(tree, layout)
+    }
+
     fn layout(
         &self,
         tree: &mut Tree,
@@ -0,0 +1,104 @@
+//! Listen to and request input method editors (IME).
+use crate::Rectangle;
+
+use std::ops::Range;
+
+/// The input method requested by a window.
+///
+/// A widget requests an [`InputMethod`] through its [`Shell`] while it is
+/// focused and accepts text input.
+///
+/// [`Shell`]: crate::Shell
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum InputMethod {
+    /// No input method is allowed.
+    #[default]
+    Disabled,
+    /// Input methods are allowed.
+    Enabled {
+        /// The area of the text cursor, in logical coordinates.
+        ///
+        /// Input methods use it to place their candidate windows.
+        cursor: Rectangle,
+        /// The [`Purpose`] of the input.
+        purpose: Purpose,
+    },
+}
+
+impl InputMethod {
+    /// Returns true if the [`InputMethod`] is [`InputMethod::Enabled`].
+    pub fn is_enabled(&self) -> bool {
+        matches!(self, Self::Enabled { .. })
+    }
+
+    /// Merges two [`InputMethod`] requests into one.
+    ///
+    /// The first enabled request takes precedence:
+    ///
+    /// ```
+    /// use iced_core::input_method::{InputMethod, Purpose};
+    /// use iced_core::Rectangle;
+    ///
+    /// let first = InputMethod::Enabled {
+    ///     cursor: Rectangle::new([10.0, 10.0].into(), [1.0, 20.0].into()),
+    ///     purpose: Purpose::Normal,
+    /// };
+    ///
+    /// let second = InputMethod::Enabled {
+    ///     cursor: Rectangle::new([50.0, 50.0].into(), [1.0, 20.0].into()),
+    ///     purpose: Purpose::Secure,
+    /// };
+    ///
+    /// let mut input_method = InputMethod::Disabled;
+    /// input_method.merge(first);
+    /// input_method.merge(second);
+    ///
+    /// assert_eq!(input_method, first);
+    /// ```
+    pub fn merge(&mut self, other: Self) {
+        if let Self::Disabled = self {
+            *self = other;
+        }
+    }
+}
+
+/// The purpose of an [`InputMethod`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Purpose {
+    /// Regular text.
+    #[default]
+    Normal,
+    /// Sensitive text, like a password.
+    Secure,
+}
+
+/// An input method event.
+///
+/// Input methods let users compose text that cannot be typed directly with
+/// their keyboard, like Chinese, Japanese or Korean characters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(crate = "serde")
+)]
+pub enum Event {
+    /// The input method was opened.
+    Opened,
+
+    /// The text being composed changed.
+    ///
+    /// The range, if any, is the byte range of the text being composed
+    /// that should be highlighted.
+    ///
+    /// An empty text means composition has been cleared.
+    Preedit(String, Option<Range<usize>>),
+
+    /// Some text was composed and should be inserted.
+    Commit(String),
+
+    /// The input method was closed.
+    ///
+    /// Any text being composed should be cleared.
+    Closed,
+}
@@ -1,5 +1,10 @@
 /// The location of a key on the keyboard.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(crate = "serde")
+)]
 pub enum Location {
     /// The standard group of keys on the keyboard.
     Standard,
@@ -1,9 +1,11 @@
 //! Position your widgets properly.
+mod direction;
 mod limits;
 mod node;
 
 pub mod flex;
 
+pub use direction::Direction;
 pub use limits::Limits;
 pub use node::Node;
 
@@ -13,6 +15,7 @@ use crate::{Length, Padding, Point, Rectangle, Size, Vector};
 #[derive(Debug, Clone, Copy)]
 pub struct Layout<'a> {
     position: Point,
+    direction: Direction,
     node: &'a Node,
 }
 
@@ -29,6 +32,7 @@ impl<'a> Layout<'a> {
 
         Self {
             position: Point::new(bounds.x, bounds.y) + offset,
+            direction: node.direction().unwrap_or_default(),
             node,
         }
     }
@@ -38,6 +42,14 @@ impl<'a> Layout<'a> {
         self.position
     }
 
+    /// Returns the [`Direction`] of the [`Layout`].
+    ///
+    /// It is inherited from the closest ancestor [`Node`] with an explicit
+    /// [`Direction`].
+    pub fn direction(&self) -> Direction {
+        self.direction
+    }
+
     /// Returns the bounds of the [`Layout`].
     ///
     /// The returned [`Rectangle`] describes the position and size of a
@@ -56,10 +68,14 @@ impl<'a> Layout<'a> {
     /// Returns an iterator over the [`Layout`] of the children of a [`Node`].
     pub fn children(self) -> impl DoubleEndedIterator<Item = Layout<'a>> {
         self.node.children().iter().map(move |node| {
-            Layout::with_offset(
-                Vector::new(self.position.x, self.position.y),
+            let bounds = node.bounds();
+
+            Layout {
+                position: Point::new(bounds.x, bounds.y)
+                    + Vector::new(self.position.x, self.position.y),
+                direction: node.direction().unwrap_or(self.direction),
                 node,
-            )
+            }
         })
     }
 }
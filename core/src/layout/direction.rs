@@ -0,0 +1,39 @@
+use crate::alignment;
+
+/// The horizontal direction of a layout.
+///
+/// A right-to-left layout is the mirror image of its left-to-right
+/// counterpart: rows are filled from the right, and the left and right
+/// sides of alignments and paddings are swapped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Direction {
+    /// Content flows from left to right.
+    #[default]
+    LeftToRight,
+    /// Content flows from right to left.
+    RightToLeft,
+}
+
+impl Direction {
+    /// Returns true if the [`Direction`] is [`Direction::RightToLeft`].
+    pub fn is_right_to_left(self) -> bool {
+        self == Self::RightToLeft
+    }
+
+    /// Mirrors the given [`alignment::Horizontal`] if the [`Direction`] is
+    /// right to left.
+    pub fn align_x(
+        self,
+        alignment: alignment::Horizontal,
+    ) -> alignment::Horizontal {
+        match (self, alignment) {
+            (Self::RightToLeft, alignment::Horizontal::Left) => {
+                alignment::Horizontal::Right
+            }
+            (Self::RightToLeft, alignment::Horizontal::Right) => {
+                alignment::Horizontal::Left
+            }
+            _ => alignment,
+        }
+    }
+}
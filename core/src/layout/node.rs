@@ -1,3 +1,4 @@
+use crate::layout::Direction;
 use crate::{Alignment, Padding, Point, Rectangle, Size, Vector};
 
 /// The bounds of an element and its children.
@@ -5,6 +6,7 @@ use crate::{Alignment, Padding, Point, Rectangle, Size, Vector};
 pub struct Node {
     bounds: Rectangle,
     children: Vec<Node>,
+    direction: Option<Direction>,
 }
 
 impl Node {
@@ -23,6 +25,7 @@ impl Node {
                 height: size.height,
             },
             children,
+            direction: None,
         }
     }
 
@@ -49,6 +52,38 @@ impl Node {
         &self.children
     }
 
+    /// Returns the [`Direction`] of the [`Node`], if it was set explicitly.
+    pub fn direction(&self) -> Option<Direction> {
+        self.direction
+    }
+
+    /// Sets the [`Direction`] of the [`Node`] and its descendants.
+    ///
+    /// The [`Node`] is expected to be laid out from left to right; its
+    /// descendants are mirrored horizontally if the [`Direction`] is
+    /// [`Direction::RightToLeft`]. Descendants with a [`Direction`] of their
+    /// own keep their contents as they are.
+    pub fn with_direction(mut self, direction: Direction) -> Self {
+        if direction.is_right_to_left() {
+            self.mirror_children();
+        }
+
+        self.direction = Some(direction);
+        self
+    }
+
+    fn mirror_children(&mut self) {
+        let width = self.bounds.width;
+
+        for child in &mut self.children {
+            child.bounds.x = width - child.bounds.x - child.bounds.width;
+
+            if child.direction.is_none() {
+                child.mirror_children();
+            }
+        }
+    }
+
     /// Aligns the [`Node`] in the given space.
     pub fn align(
         mut self,
@@ -113,3 +148,57 @@ impl Node {
         self.bounds = self.bounds + translation.into();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::Layout;
+
+    #[test]
+    fn mirror_descendants_right_to_left() {
+        let nested = Node::with_children(
+            Size::new(60.0, 10.0),
+            vec![Node::new(Size::new(10.0, 10.0))],
+        );
+
+        let explicit = nested
+            .clone()
+            .with_direction(Direction::LeftToRight)
+            .move_to(Point::new(0.0, 10.0));
+
+        let node = Node::with_children(
+            Size::new(100.0, 20.0),
+            vec![
+                Node::new(Size::new(30.0, 10.0)),
+                nested.move_to(Point::new(40.0, 0.0)),
+                explicit,
+            ],
+        )
+        .with_direction(Direction::RightToLeft);
+
+        let x = |node: &Node| node.bounds().x;
+        let [first, second, third] = node.children() else {
+            panic!("node must have three children");
+        };
+
+        assert_eq!(x(first), 70.0);
+        assert_eq!(x(second), 0.0);
+        assert_eq!(x(&second.children()[0]), 50.0);
+        assert_eq!(x(third), 40.0);
+        assert_eq!(x(&third.children()[0]), 0.0);
+
+        let directions: Vec<_> = Layout::new(&node)
+            .children()
+            .map(|layout| layout.direction())
+            .collect();
+
+        assert_eq!(
+            directions,
+            [
+                Direction::RightToLeft,
+                Direction::RightToLeft,
+                Direction::LeftToRight
+            ]
+        );
+    }
+}
@@ -1,5 +1,10 @@
 /// The button of a mouse.
 #[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(crate = "serde")
+)]
 pub enum Button {
     /// The left mouse button.
     Left,
@@ -3,7 +3,8 @@
 mod null;
 
 use crate::{
-    Background, Border, Color, Rectangle, Shadow, Size, Transformation, Vector,
+    Background, Border, Color, Font, Pixels, Rectangle, Shadow, Size,
+    Transformation, Vector,
 };
 
 /// A component that can be used by widgets to draw themselves on a screen.
@@ -100,3 +101,23 @@ impl Default for Style {
         }
     }
 }
+
+/// A headless renderer is a renderer that can draw offscreen, without
+/// needing a window.
+pub trait Headless {
+    /// Creates a new [`Headless`] renderer with the given default [`Font`]
+    /// and text size.
+    fn new(default_font: Font, default_text_size: Pixels) -> Self;
+
+    /// Draws the recorded primitives offscreen with the given physical size
+    /// and scale factor.
+    ///
+    /// It returns the resulting pixels as `RGBA` bytes in the `sRGB` color
+    /// space.
+    fn screenshot(
+        &mut self,
+        size: Size<u32>,
+        scale_factor: f32,
+        background_color: Color,
+    ) -> Vec<u8>;
+}
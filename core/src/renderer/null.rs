@@ -26,6 +26,19 @@ impl Renderer for () {
     }
 }
 
+impl renderer::Headless for () {
+    fn new(_default_font: Font, _default_text_size: Pixels) -> Self {}
+
+    fn screenshot(
+        &mut self,
+        size: Size<u32>,
+        _scale_factor: f32,
+        _background_color: Color,
+    ) -> Vec<u8> {
+        vec![0; size.width as usize * size.height as usize * 4]
+    }
+}
+
 impl text::Renderer for () {
     type Font = Font;
     type Paragraph = ();
@@ -44,6 +44,18 @@ pub struct Text<Content = String, Font = crate::Font> {
 
     /// The [`Wrapping`] strategy of the [`Text`].
     pub wrapping: Wrapping,
+
+    /// The [`Ellipsis`] strategy of the [`Text`].
+    pub ellipsis: Ellipsis,
+
+    /// The maximum amount of lines of the [`Text`], if any.
+    ///
+    /// Any lines past the limit are truncated. At least one line is
+    /// always kept.
+    pub max_lines: Option<usize>,
+
+    /// The spacing between the paragraphs of the [`Text`].
+    pub paragraph_spacing: Pixels,
 }
 
 /// The shaping strategy of some text.
@@ -86,6 +98,23 @@ pub enum Wrapping {
     WordOrGlyph,
 }
 
+/// The truncation strategy of some text that overflows its bounds or its
+/// maximum amount of lines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Ellipsis {
+    /// The overflowing text is clipped.
+    ///
+    /// This is the default.
+    #[default]
+    None,
+    /// The end of the text is replaced with an ellipsis (`…`).
+    End,
+    /// The middle of the text is replaced with an ellipsis (`…`).
+    Middle,
+    /// The start of the text is replaced with an ellipsis (`…`).
+    Start,
+}
+
 /// The height of a line of text in a paragraph.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum LineHeight {
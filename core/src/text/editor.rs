@@ -27,6 +27,29 @@ pub trait Editor: Sized + Default {
     /// Returns the current selected text of the [`Editor`].
     fn selection(&self) -> Option<String>;
 
+    /// Returns the start and end [`Position`] of the current selection of
+    /// the [`Editor`], if any.
+    fn selection_range(&self) -> Option<(Position, Position)> {
+        None
+    }
+
+    /// Returns the [`Cursor`] of every caret of the [`Editor`], starting
+    /// with the current one.
+    fn cursors(&self) -> Vec<Cursor> {
+        vec![self.cursor()]
+    }
+
+    /// Returns the start and end [`Position`] of every caret of the
+    /// [`Editor`], in order.
+    ///
+    /// The start and end of a caret without a selection are the same.
+    fn selection_ranges(&self) -> Vec<(Position, Position)> {
+        let (line, column) = self.cursor_position();
+        let position = Position::new(line, column);
+
+        vec![self.selection_range().unwrap_or((position, position))]
+    }
+
     /// Returns the text of the given line in the [`Editor`], if it exists.
     fn line(&self, index: usize) -> Option<&str>;
 
@@ -43,6 +66,20 @@ pub trait Editor: Sized + Default {
     /// the [`Editor`].
     fn min_bounds(&self) -> Size;
 
+    /// Returns the amount of pixels the contents of the [`Editor`] are
+    /// scrolled horizontally.
+    fn horizontal_scroll(&self) -> f32 {
+        0.0
+    }
+
+    /// Returns the index and bounds of every visible line of the [`Editor`],
+    /// in order.
+    ///
+    /// The bounds of a wrapped line cover all of its visual lines.
+    fn visible_lines(&self) -> Vec<(usize, Rectangle)> {
+        Vec::new()
+    }
+
     /// Updates the [`Editor`] with some new attributes.
     fn update(
         &mut self,
@@ -87,12 +124,66 @@ pub enum Action {
         /// The amount of lines to scroll.
         lines: i32,
     },
+    /// Scroll the [`Editor`] horizontally a certain amount of pixels.
+    ScrollHorizontally {
+        /// The amount of pixels to scroll.
+        pixels: f32,
+    },
+    /// Add a new caret at the given [`Point`], keeping the existing ones.
+    ///
+    /// Any further [`Motion`] or [`Edit`] applies to every caret, until
+    /// the [`Editor`] is clicked again.
+    AddCursor(Point),
+    /// Select the rectangular region between the given [`Point`]s, placing
+    /// a caret on every line.
+    SelectBox {
+        /// The corner where the selection starts.
+        start: Point,
+        /// The corner where the selection ends, where the current caret is
+        /// placed.
+        end: Point,
+    },
+    /// Move the cursor to the given [`Position`], clearing any selection.
+    MoveTo(Position),
+    /// Select the text between the given [`Position`]s.
+    SelectRange {
+        /// The start of the selection.
+        start: Position,
+        /// The end of the selection, where the cursor is placed.
+        end: Position,
+    },
+    /// Undo the last edit.
+    ///
+    /// An [`Editor`] does not keep any history of its own; its owner is
+    /// in charge of handling this action.
+    Undo,
+    /// Redo the last undone edit.
+    ///
+    /// An [`Editor`] does not keep any history of its own; its owner is
+    /// in charge of handling this action.
+    Redo,
 }
 
 impl Action {
     /// Returns whether the [`Action`] is an editing action.
     pub fn is_edit(&self) -> bool {
-        matches!(self, Self::Edit(_))
+        matches!(self, Self::Edit(_) | Self::Undo | Self::Redo)
+    }
+}
+
+/// A position in the text of an [`Editor`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Position {
+    /// The index of the line.
+    pub line: usize,
+    /// The byte offset in the line.
+    pub column: usize,
+}
+
+impl Position {
+    /// Creates a new [`Position`] with the given line and column.
+    pub const fn new(line: usize, column: usize) -> Self {
+        Self { line, column }
     }
 }
 
@@ -105,10 +196,64 @@ pub enum Edit {
     Paste(Arc<String>),
     /// Break the current line.
     Enter,
+    /// Break the current line, copying its leading whitespace to the new
+    /// one.
+    EnterIndented,
     /// Delete the previous character.
     Backspace,
     /// Delete the next character.
     Delete,
+    /// Indent the selected lines, or insert one level of [`Indentation`]
+    /// at the cursor if the selection does not span multiple lines.
+    Indent(Indentation),
+    /// Remove one level of [`Indentation`] from the selected lines, or the
+    /// current one.
+    Unindent(Indentation),
+    /// Insert the given opening and closing characters around the
+    /// selection, or around the cursor if nothing is selected.
+    ///
+    /// If both characters are the same and it is already next to the
+    /// cursor, the cursor moves past it instead.
+    InsertPair(char, char),
+    /// Insert the given closing character, or move the cursor past it if
+    /// it is already next to the cursor.
+    InsertClosing(char),
+    /// Comment out the selected lines, or the current one, with the given
+    /// line comment prefix; or uncomment them if they are all commented.
+    ToggleComment(Arc<String>),
+}
+
+/// The indentation of some text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Indentation {
+    /// The amount of columns of a level of indentation.
+    pub width: usize,
+    /// Whether a level of indentation is made of spaces instead of a tab.
+    pub soft_tabs: bool,
+}
+
+impl Indentation {
+    /// Creates a new [`Indentation`] made of the given amount of spaces.
+    pub const fn spaces(width: usize) -> Self {
+        Self {
+            width,
+            soft_tabs: true,
+        }
+    }
+
+    /// Creates a new [`Indentation`] made of tabs of the given width.
+    pub const fn tabs(width: usize) -> Self {
+        Self {
+            width,
+            soft_tabs: false,
+        }
+    }
+}
+
+impl Default for Indentation {
+    fn default() -> Self {
+        Self::tabs(4)
+    }
 }
 
 /// A cursor movement.
@@ -40,6 +40,12 @@ pub trait Highlighter: 'static {
     /// If `change_line` has been called, this will normally be the least index
     /// that changed.
     fn current_line(&self) -> usize;
+
+    /// Returns the prefix of a line comment in the language being
+    /// highlighted, if it has any.
+    fn line_comment(&self) -> Option<&str> {
+        None
+    }
 }
 
 /// A highlighter that highlights nothing.
@@ -3,6 +3,8 @@ use crate::alignment;
 use crate::text::{Difference, Hit, Span, Text};
 use crate::{Point, Rectangle, Size};
 
+use std::ops::Range;
+
 /// A text paragraph.
 pub trait Paragraph: Sized + Default {
     /// The font of this [`Paragraph`].
@@ -35,6 +37,9 @@ pub trait Paragraph: Sized + Default {
 
     /// Tests whether the provided point is within the boundaries of the
     /// [`Paragraph`], returning information about the nearest character.
+    ///
+    /// Character offsets are byte offsets into the whole text of the
+    /// [`Paragraph`], including any line breaks.
     fn hit_test(&self, point: Point) -> Option<Hit>;
 
     /// Tests whether the provided point is within the boundaries of a
@@ -46,6 +51,12 @@ pub trait Paragraph: Sized + Default {
     /// A [`Span`] can have multiple bounds for each line it's on.
     fn span_bounds(&self, index: usize) -> Vec<Rectangle>;
 
+    /// Returns all bounds for the text in the provided byte range of the
+    /// [`Paragraph`], one for each line the range is on.
+    fn range_bounds(&self, _range: Range<usize>) -> Vec<Rectangle> {
+        Vec::new()
+    }
+
     /// Returns the distance to the given grapheme index in the [`Paragraph`].
     fn grapheme_position(&self, line: usize, index: usize) -> Option<Point>;
 
@@ -96,6 +107,9 @@ impl<P: Paragraph> Plain<P> {
             vertical_alignment: text.vertical_alignment,
             shaping: text.shaping,
             wrapping: text.wrapping,
+            ellipsis: text.ellipsis,
+            max_lines: text.max_lines,
+            paragraph_spacing: text.paragraph_spacing,
         }) {
             Difference::None => {}
             Difference::Bounds => {
@@ -3,6 +3,11 @@ use crate::Point;
 
 /// A touch interaction.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(crate = "serde")
+)]
 #[allow(missing_docs)]
 pub enum Event {
     /// A touch interaction was started.
@@ -20,4 +25,9 @@ pub enum Event {
 
 /// A unique identifier representing a finger on a touch interaction.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(crate = "serde")
+)]
 pub struct Finger(pub u64);
@@ -1,5 +1,10 @@
 /// A 2D vector.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(crate = "serde")
+)]
 pub struct Vector<T = f32> {
     /// The X component of the [`Vector`]
     pub x: T,
@@ -1,5 +1,6 @@
 //! Create custom widgets and operate on them.
 pub mod operation;
+pub mod semantics;
 pub mod text;
 pub mod tree;
 
@@ -7,6 +8,7 @@ mod id;
 
 pub use id::Id;
 pub use operation::Operation;
+pub use semantics::Semantics;
 pub use text::Text;
 pub use tree::Tree;
 
@@ -99,14 +101,29 @@ where
     /// Reconciles the [`Widget`] with the provided [`Tree`].
     fn diff(&self, _tree: &mut Tree) {}
 
+    /// Returns the [`Semantics`] describing the [`Widget`] to assistive
+    /// technologies, if any.
+    ///
+    /// By default, [`operate`](Self::operate) reports them through
+    /// [`Operation::semantics`]. Widgets overriding it should report them
+    /// as well.
+    fn semantics(&self, _state: &Tree, _layout: Layout<'_>) -> Option<Semantics> {
+        None
+    }
+
     /// Applies an [`Operation`] to the [`Widget`].
+    ///
+    /// By default, it only reports the [`Semantics`] of the [`Widget`].
     fn operate(
         &self,
-        _state: &mut Tree,
-        _layout: Layout<'_>,
+        state: &mut Tree,
+        layout: Layout<'_>,
         _renderer: &Renderer,
-        _operation: &mut dyn Operation,
+        operation: &mut dyn Operation,
     ) {
+        if let Some(semantics) = self.semantics(state, layout) {
+            operation.semantics(None, layout.bounds(), &semantics);
+        }
     }
 
     /// Processes a runtime [`Event`].
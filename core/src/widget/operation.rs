@@ -1,5 +1,6 @@
 //! Query or update internal widget state.
 pub mod focusable;
+pub mod query;
 pub mod scrollable;
 pub mod text_input;
 
@@ -7,7 +8,7 @@ pub use focusable::Focusable;
 pub use scrollable::Scrollable;
 pub use text_input::TextInput;
 
-use crate::widget::Id;
+use crate::widget::{Id, Semantics};
 use crate::{Rectangle, Vector};
 
 use std::any::Any;
@@ -30,7 +31,13 @@ pub trait Operation<T = ()>: Send {
     );
 
     /// Operates on a widget that can be focused.
-    fn focusable(&mut self, _state: &mut dyn Focusable, _id: Option<&Id>) {}
+    fn focusable(
+        &mut self,
+        _state: &mut dyn Focusable,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+    ) {
+    }
 
     /// Operates on a widget that can be scrolled.
     fn scrollable(
@@ -43,10 +50,61 @@ pub trait Operation<T = ()>: Send {
     }
 
     /// Operates on a widget that has text input.
-    fn text_input(&mut self, _state: &mut dyn TextInput, _id: Option<&Id>) {}
+    fn text_input(
+        &mut self,
+        _state: &mut dyn TextInput,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+    ) {
+    }
+
+    /// Operates on a widget of the given kind.
+    ///
+    /// The kind is a short, lowercase name describing the type of the
+    /// widget; like `"button"` or `"text_input"`.
+    fn kind(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        _kind: &'static str,
+    ) {
+    }
+
+    /// Operates on a widget that describes itself to assistive
+    /// technologies with some [`Semantics`].
+    ///
+    /// Widgets report the [`Semantics`] returned by
+    /// [`Widget::semantics`](crate::Widget::semantics), along with their
+    /// current state and value, while being operated in
+    /// [`Widget::operate`](crate::Widget::operate).
+    fn semantics(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        _semantics: &Semantics,
+    ) {
+    }
+
+    /// Operates on a widget that contains some text.
+    ///
+    /// The text is only formatted if the [`Operation`] needs it, since
+    /// producing it may be expensive for some widgets; like a text editor.
+    fn text(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        _text: &dyn fmt::Display,
+    ) {
+    }
 
     /// Operates on a custom widget with some state.
-    fn custom(&mut self, _state: &mut dyn Any, _id: Option<&Id>) {}
+    fn custom(
+        &mut self,
+        _state: &mut dyn Any,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+    ) {
+    }
 
     /// Finishes the [`Operation`] and returns its [`Outcome`].
     fn finish(&self) -> Outcome<T> {
@@ -67,8 +125,13 @@ where
         self.as_mut().container(id, bounds, operate_on_children);
     }
 
-    fn focusable(&mut self, state: &mut dyn Focusable, id: Option<&Id>) {
-        self.as_mut().focusable(state, id);
+    fn focusable(
+        &mut self,
+        state: &mut dyn Focusable,
+        id: Option<&Id>,
+        bounds: Rectangle,
+    ) {
+        self.as_mut().focusable(state, id, bounds);
     }
 
     fn scrollable(
@@ -81,12 +144,44 @@ where
         self.as_mut().scrollable(state, id, bounds, translation);
     }
 
-    fn text_input(&mut self, state: &mut dyn TextInput, id: Option<&Id>) {
-        self.as_mut().text_input(state, id);
+    fn text_input(
+        &mut self,
+        state: &mut dyn TextInput,
+        id: Option<&Id>,
+        bounds: Rectangle,
+    ) {
+        self.as_mut().text_input(state, id, bounds);
+    }
+
+    fn kind(&mut self, id: Option<&Id>, bounds: Rectangle, kind: &'static str) {
+        self.as_mut().kind(id, bounds, kind);
+    }
+
+    fn semantics(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        semantics: &Semantics,
+    ) {
+        self.as_mut().semantics(id, bounds, semantics);
+    }
+
+    fn text(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        text: &dyn fmt::Display,
+    ) {
+        self.as_mut().text(id, bounds, text);
     }
 
-    fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
-        self.as_mut().custom(state, id);
+    fn custom(
+        &mut self,
+        state: &mut dyn Any,
+        id: Option<&Id>,
+        bounds: Rectangle,
+    ) {
+        self.as_mut().custom(state, id, bounds);
     }
 
     fn finish(&self) -> Outcome<O> {
@@ -142,8 +237,13 @@ where
             });
         }
 
-        fn focusable(&mut self, state: &mut dyn Focusable, id: Option<&Id>) {
-            self.operation.focusable(state, id);
+        fn focusable(
+            &mut self,
+            state: &mut dyn Focusable,
+            id: Option<&Id>,
+            bounds: Rectangle,
+        ) {
+            self.operation.focusable(state, id, bounds);
         }
 
         fn scrollable(
@@ -156,12 +256,49 @@ where
             self.operation.scrollable(state, id, bounds, translation);
         }
 
-        fn text_input(&mut self, state: &mut dyn TextInput, id: Option<&Id>) {
-            self.operation.text_input(state, id);
+        fn text_input(
+            &mut self,
+            state: &mut dyn TextInput,
+            id: Option<&Id>,
+            bounds: Rectangle,
+        ) {
+            self.operation.text_input(state, id, bounds);
+        }
+
+        fn kind(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            kind: &'static str,
+        ) {
+            self.operation.kind(id, bounds, kind);
+        }
+
+        fn semantics(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            semantics: &Semantics,
+        ) {
+            self.operation.semantics(id, bounds, semantics);
+        }
+
+        fn text(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            text: &dyn fmt::Display,
+        ) {
+            self.operation.text(id, bounds, text);
         }
 
-        fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
-            self.operation.custom(state, id);
+        fn custom(
+            &mut self,
+            state: &mut dyn Any,
+            id: Option<&Id>,
+            bounds: Rectangle,
+        ) {
+            self.operation.custom(state, id, bounds);
         }
 
         fn finish(&self) -> Outcome<O> {
@@ -231,20 +368,54 @@ where
                     &mut self,
                     state: &mut dyn Focusable,
                     id: Option<&Id>,
+                    bounds: Rectangle,
                 ) {
-                    self.operation.focusable(state, id);
+                    self.operation.focusable(state, id, bounds);
                 }
 
                 fn text_input(
                     &mut self,
                     state: &mut dyn TextInput,
                     id: Option<&Id>,
+                    bounds: Rectangle,
+                ) {
+                    self.operation.text_input(state, id, bounds);
+                }
+
+                fn kind(
+                    &mut self,
+                    id: Option<&Id>,
+                    bounds: Rectangle,
+                    kind: &'static str,
+                ) {
+                    self.operation.kind(id, bounds, kind);
+                }
+
+                fn semantics(
+                    &mut self,
+                    id: Option<&Id>,
+                    bounds: Rectangle,
+                    semantics: &Semantics,
+                ) {
+                    self.operation.semantics(id, bounds, semantics);
+                }
+
+                fn text(
+                    &mut self,
+                    id: Option<&Id>,
+                    bounds: Rectangle,
+                    text: &dyn fmt::Display,
                 ) {
-                    self.operation.text_input(state, id);
+                    self.operation.text(id, bounds, text);
                 }
 
-                fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
-                    self.operation.custom(state, id);
+                fn custom(
+                    &mut self,
+                    state: &mut dyn Any,
+                    id: Option<&Id>,
+                    bounds: Rectangle,
+                ) {
+                    self.operation.custom(state, id, bounds);
                 }
             }
 
@@ -253,8 +424,13 @@ where
             MapRef { operation }.container(id, bounds, operate_on_children);
         }
 
-        fn focusable(&mut self, state: &mut dyn Focusable, id: Option<&Id>) {
-            self.operation.focusable(state, id);
+        fn focusable(
+            &mut self,
+            state: &mut dyn Focusable,
+            id: Option<&Id>,
+            bounds: Rectangle,
+        ) {
+            self.operation.focusable(state, id, bounds);
         }
 
         fn scrollable(
@@ -267,12 +443,49 @@ where
             self.operation.scrollable(state, id, bounds, translation);
         }
 
-        fn text_input(&mut self, state: &mut dyn TextInput, id: Option<&Id>) {
-            self.operation.text_input(state, id);
+        fn text_input(
+            &mut self,
+            state: &mut dyn TextInput,
+            id: Option<&Id>,
+            bounds: Rectangle,
+        ) {
+            self.operation.text_input(state, id, bounds);
+        }
+
+        fn kind(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            kind: &'static str,
+        ) {
+            self.operation.kind(id, bounds, kind);
+        }
+
+        fn semantics(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            semantics: &Semantics,
+        ) {
+            self.operation.semantics(id, bounds, semantics);
         }
 
-        fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
-            self.operation.custom(state, id);
+        fn text(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            text: &dyn fmt::Display,
+        ) {
+            self.operation.text(id, bounds, text);
+        }
+
+        fn custom(
+            &mut self,
+            state: &mut dyn Any,
+            id: Option<&Id>,
+            bounds: Rectangle,
+        ) {
+            self.operation.custom(state, id, bounds);
         }
 
         fn finish(&self) -> Outcome<B> {
@@ -332,8 +545,13 @@ where
             });
         }
 
-        fn focusable(&mut self, state: &mut dyn Focusable, id: Option<&Id>) {
-            self.operation.focusable(state, id);
+        fn focusable(
+            &mut self,
+            state: &mut dyn Focusable,
+            id: Option<&Id>,
+            bounds: Rectangle,
+        ) {
+            self.operation.focusable(state, id, bounds);
         }
 
         fn scrollable(
@@ -346,12 +564,49 @@ where
             self.operation.scrollable(state, id, bounds, translation);
         }
 
-        fn text_input(&mut self, state: &mut dyn TextInput, id: Option<&Id>) {
-            self.operation.text_input(state, id);
+        fn text_input(
+            &mut self,
+            state: &mut dyn TextInput,
+            id: Option<&Id>,
+            bounds: Rectangle,
+        ) {
+            self.operation.text_input(state, id, bounds);
+        }
+
+        fn kind(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            kind: &'static str,
+        ) {
+            self.operation.kind(id, bounds, kind);
+        }
+
+        fn semantics(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            semantics: &Semantics,
+        ) {
+            self.operation.semantics(id, bounds, semantics);
+        }
+
+        fn text(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            text: &dyn fmt::Display,
+        ) {
+            self.operation.text(id, bounds, text);
         }
 
-        fn custom(&mut self, state: &mut dyn std::any::Any, id: Option<&Id>) {
-            self.operation.custom(state, id);
+        fn custom(
+            &mut self,
+            state: &mut dyn std::any::Any,
+            id: Option<&Id>,
+            bounds: Rectangle,
+        ) {
+            self.operation.custom(state, id, bounds);
         }
 
         fn finish(&self) -> Outcome<B> {
@@ -0,0 +1,277 @@
+//! Query the widgets of a widget tree.
+use crate::widget::operation::{
+    self, Focusable, Operation, Outcome, Scrollable, TextInput,
+};
+use crate::widget::Id;
+use crate::{Point, Rectangle, Vector};
+
+use std::any::Any;
+use std::fmt;
+
+/// A widget found while querying a widget tree.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Match {
+    /// The kind of the widget, if reported.
+    pub kind: Option<&'static str>,
+
+    /// The [`Id`] of the widget, if any.
+    pub id: Option<Id>,
+
+    /// The visible bounds of the widget, taking into account any scrolling.
+    pub bounds: Rectangle,
+
+    /// The text of the widget, if any.
+    pub text: Option<String>,
+}
+
+/// A strategy used to select widgets while querying a widget tree.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Selector {
+    /// Selects the widget with the given [`Id`].
+    Id(Id),
+
+    /// Selects the widgets whose [`Id`] name starts with the given prefix.
+    IdPrefix(String),
+
+    /// Selects the widgets of the given kind.
+    Kind(&'static str),
+
+    /// Selects the widgets whose text is equal to the given text.
+    Text(String),
+
+    /// Selects the widgets whose text contains the given text.
+    TextContains(String),
+
+    /// Selects the widgets whose bounds contain the given [`Point`].
+    ContainsPoint(Point),
+
+    /// Selects the widgets matching all of the given selectors.
+    All(Vec<Selector>),
+
+    /// Selects the widgets matching any of the given selectors.
+    Any(Vec<Selector>),
+}
+
+impl Selector {
+    /// Combines the [`Selector`] with another one, selecting only the widgets
+    /// matching both.
+    pub fn and(self, other: impl Into<Self>) -> Self {
+        match self {
+            Self::All(mut selectors) => {
+                selectors.push(other.into());
+
+                Self::All(selectors)
+            }
+            selector => Self::All(vec![selector, other.into()]),
+        }
+    }
+
+    /// Returns true if the given [`Match`] is selected by the [`Selector`].
+    pub fn matches(&self, candidate: &Match) -> bool {
+        match self {
+            Self::Id(id) => candidate.id.as_ref() == Some(id),
+            Self::IdPrefix(prefix) => candidate
+                .id
+                .as_ref()
+                .and_then(Id::name)
+                .is_some_and(|name| name.starts_with(prefix.as_str())),
+            Self::Kind(kind) => candidate.kind == Some(*kind),
+            Self::Text(text) => candidate.text.as_ref() == Some(text),
+            Self::TextContains(text) => candidate
+                .text
+                .as_ref()
+                .is_some_and(|candidate| candidate.contains(text.as_str())),
+            Self::ContainsPoint(point) => candidate.bounds.contains(*point),
+            Self::All(selectors) => {
+                selectors.iter().all(|selector| selector.matches(candidate))
+            }
+            Self::Any(selectors) => {
+                selectors.iter().any(|selector| selector.matches(candidate))
+            }
+        }
+    }
+}
+
+impl From<Id> for Selector {
+    fn from(id: Id) -> Self {
+        Self::Id(id)
+    }
+}
+
+impl From<&str> for Selector {
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_owned())
+    }
+}
+
+impl From<String> for Selector {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl From<Point> for Selector {
+    fn from(point: Point) -> Self {
+        Self::ContainsPoint(point)
+    }
+}
+
+/// Produces an [`Operation`] that returns all the widgets matching the given
+/// [`Selector`], in tree order.
+pub fn find_all(selector: impl Into<Selector>) -> impl Operation<Vec<Match>> {
+    Query {
+        selector: selector.into(),
+        candidates: Vec::new(),
+        translation: Vector::ZERO,
+        scrollable: None,
+    }
+}
+
+/// Produces an [`Operation`] that returns the first widget matching the given
+/// [`Selector`], if any.
+pub fn find(selector: impl Into<Selector>) -> impl Operation<Option<Match>> {
+    operation::map(find_all(selector), |matches| matches.into_iter().next())
+}
+
+#[derive(Debug)]
+struct Query {
+    selector: Selector,
+    candidates: Vec<Match>,
+    translation: Vector,
+    scrollable: Option<(Rectangle, Vector)>,
+}
+
+impl Query {
+    fn visit(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        kind: Option<&'static str>,
+        text: Option<String>,
+    ) {
+        let bounds = bounds - self.translation;
+
+        // Widgets may report themselves through multiple hooks, so we merge
+        // consecutive reports of the same widget into a single candidate.
+        if let Some(last) = self.candidates.last_mut() {
+            let is_same_widget = last.bounds == bounds
+                && (id.is_none()
+                    || last.id.is_none()
+                    || last.id.as_ref() == id)
+                && (kind.is_none() || last.kind.is_none())
+                && (text.is_none() || last.text.is_none());
+
+            if is_same_widget {
+                if last.id.is_none() {
+                    last.id = id.cloned();
+                }
+
+                if last.kind.is_none() {
+                    last.kind = kind;
+                }
+
+                if last.text.is_none() {
+                    last.text = text;
+                }
+
+                return;
+            }
+        }
+
+        self.candidates.push(Match {
+            kind,
+            id: id.cloned(),
+            bounds,
+            text,
+        });
+    }
+
+    fn visit_id(&mut self, id: Option<&Id>, bounds: Rectangle) {
+        if id.is_some() {
+            self.visit(id, bounds, None, None);
+        }
+    }
+}
+
+impl Operation<Vec<Match>> for Query {
+    fn container(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<Vec<Match>>),
+    ) {
+        self.visit_id(id, bounds);
+
+        let translation = match self.scrollable.take() {
+            Some((scrollable, translation)) if scrollable == bounds => {
+                translation
+            }
+            _ => Vector::ZERO,
+        };
+
+        self.translation = self.translation + translation;
+        operate_on_children(self);
+        self.translation = self.translation - translation;
+    }
+
+    fn focusable(
+        &mut self,
+        _state: &mut dyn Focusable,
+        id: Option<&Id>,
+        bounds: Rectangle,
+    ) {
+        self.visit_id(id, bounds);
+    }
+
+    fn scrollable(
+        &mut self,
+        _state: &mut dyn Scrollable,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        translation: Vector,
+    ) {
+        self.visit_id(id, bounds);
+        self.scrollable = Some((bounds, translation));
+    }
+
+    fn text_input(
+        &mut self,
+        _state: &mut dyn TextInput,
+        id: Option<&Id>,
+        bounds: Rectangle,
+    ) {
+        self.visit_id(id, bounds);
+    }
+
+    fn kind(&mut self, id: Option<&Id>, bounds: Rectangle, kind: &'static str) {
+        self.visit(id, bounds, Some(kind), None);
+    }
+
+    fn text(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        text: &dyn fmt::Display,
+    ) {
+        self.visit(id, bounds, None, Some(text.to_string()));
+    }
+
+    fn custom(
+        &mut self,
+        _state: &mut dyn Any,
+        id: Option<&Id>,
+        bounds: Rectangle,
+    ) {
+        self.visit_id(id, bounds);
+    }
+
+    fn finish(&self) -> Outcome<Vec<Match>> {
+        Outcome::Some(
+            self.candidates
+                .iter()
+                .filter(|candidate| self.selector.matches(candidate))
+                .cloned()
+                .collect(),
+        )
+    }
+}
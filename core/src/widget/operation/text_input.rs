@@ -23,7 +23,12 @@ pub fn move_cursor_to_front<T>(target: Id) -> impl Operation<T> {
     }
 
     impl<T> Operation<T> for MoveCursor {
-        fn text_input(&mut self, state: &mut dyn TextInput, id: Option<&Id>) {
+        fn text_input(
+            &mut self,
+            state: &mut dyn TextInput,
+            id: Option<&Id>,
+            _bounds: Rectangle,
+        ) {
             match id {
                 Some(id) if id == &self.target => {
                     state.move_cursor_to_front();
@@ -53,7 +58,12 @@ pub fn move_cursor_to_end<T>(target: Id) -> impl Operation<T> {
     }
 
     impl<T> Operation<T> for MoveCursor {
-        fn text_input(&mut self, state: &mut dyn TextInput, id: Option<&Id>) {
+        fn text_input(
+            &mut self,
+            state: &mut dyn TextInput,
+            id: Option<&Id>,
+            _bounds: Rectangle,
+        ) {
             match id {
                 Some(id) if id == &self.target => {
                     state.move_cursor_to_end();
@@ -84,7 +94,12 @@ pub fn move_cursor_to<T>(target: Id, position: usize) -> impl Operation<T> {
     }
 
     impl<T> Operation<T> for MoveCursor {
-        fn text_input(&mut self, state: &mut dyn TextInput, id: Option<&Id>) {
+        fn text_input(
+            &mut self,
+            state: &mut dyn TextInput,
+            id: Option<&Id>,
+            _bounds: Rectangle,
+        ) {
             match id {
                 Some(id) if id == &self.target => {
                     state.move_cursor_to(self.position);
@@ -113,7 +128,12 @@ pub fn select_all<T>(target: Id) -> impl Operation<T> {
     }
 
     impl<T> Operation<T> for MoveCursor {
-        fn text_input(&mut self, state: &mut dyn TextInput, id: Option<&Id>) {
+        fn text_input(
+            &mut self,
+            state: &mut dyn TextInput,
+            id: Option<&Id>,
+            _bounds: Rectangle,
+        ) {
             match id {
                 Some(id) if id == &self.target => {
                     state.select_all();
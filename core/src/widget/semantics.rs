@@ -0,0 +1,132 @@
+//! Describe widgets to assistive technologies.
+
+/// The meaning of a widget for assistive technologies, like screen readers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Semantics {
+    /// The [`Role`] of the widget.
+    pub role: Role,
+
+    /// The label of the widget, announced when it is reached.
+    pub label: Option<String>,
+
+    /// An additional description of the widget.
+    pub description: Option<String>,
+
+    /// Whether the widget is toggled, checked, or selected; if it can be.
+    pub toggled: Option<bool>,
+
+    /// The numeric [`Value`] of the widget, if any.
+    pub value: Option<Value>,
+
+    /// Whether the widget is disabled.
+    pub is_disabled: bool,
+}
+
+impl Semantics {
+    /// Creates new [`Semantics`] with the given [`Role`] and no label.
+    pub fn new(role: Role) -> Self {
+        Self {
+            role,
+            label: None,
+            description: None,
+            toggled: None,
+            value: None,
+            is_disabled: false,
+        }
+    }
+
+    /// Sets the label of the [`Semantics`].
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets the description of the [`Semantics`].
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Sets the label of the [`Semantics`], unless it already has one.
+    pub fn or_label(mut self, label: impl Into<String>) -> Self {
+        if self.label.is_none() {
+            self.label = Some(label.into());
+        }
+
+        self
+    }
+
+    /// Sets whether the widget of the [`Semantics`] is toggled.
+    pub fn toggled(mut self, toggled: bool) -> Self {
+        self.toggled = Some(toggled);
+        self
+    }
+
+    /// Sets the numeric [`Value`] of the [`Semantics`].
+    pub fn value(mut self, value: Value) -> Self {
+        self.value = Some(value);
+        self
+    }
+
+    /// Sets whether the widget of the [`Semantics`] is disabled.
+    pub fn disabled(mut self, is_disabled: bool) -> Self {
+        self.is_disabled = is_disabled;
+        self
+    }
+}
+
+/// The numeric value of a widget, like a slider or a progress bar.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Value {
+    /// The current value.
+    pub current: f64,
+
+    /// The minimum value.
+    pub min: f64,
+
+    /// The maximum value.
+    pub max: f64,
+
+    /// The amount the value changes in a single step, if any.
+    pub step: Option<f64>,
+}
+
+/// The semantic role of a widget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Role {
+    /// A clickable button.
+    Button,
+
+    /// A checkbox that can be toggled.
+    CheckBox,
+
+    /// A switch that can be toggled on and off.
+    Switch,
+
+    /// One choice of a group of mutually exclusive options.
+    RadioButton,
+
+    /// A control used to pick a value from a range.
+    Slider,
+
+    /// A single-line text field.
+    TextInput,
+
+    /// A multi-line text field.
+    MultilineTextInput,
+
+    /// A control used to pick an option from a list.
+    ComboBox,
+
+    /// An image.
+    Image,
+
+    /// A piece of static text.
+    Label,
+
+    /// An indicator of the progress of some task.
+    ProgressIndicator,
+
+    /// A group of related widgets.
+    Group,
+}
@@ -1,17 +1,23 @@
 //! Write some text for your users to read.
+pub mod selection;
+
+pub use selection::Selection;
+
 use crate::alignment;
+use crate::event;
 use crate::layout;
 use crate::mouse;
 use crate::renderer;
 use crate::text;
 use crate::text::paragraph::{self, Paragraph};
 use crate::widget::tree::{self, Tree};
+use crate::widget::Operation;
 use crate::{
-    Color, Element, Layout, Length, Pixels, Point, Rectangle, Size, Theme,
-    Widget,
+    Clipboard, Color, Element, Event, Layout, Length, Pixels, Point, Rectangle,
+    Shell, Size, Theme, Widget,
 };
 
-pub use text::{LineHeight, Shaping, Wrapping};
+pub use text::{Ellipsis, LineHeight, Shaping, Wrapping};
 
 /// A paragraph of text.
 #[allow(missing_debug_implementations)]
@@ -30,6 +36,10 @@ where
     font: Option<Renderer::Font>,
     shaping: Shaping,
     wrapping: Wrapping,
+    ellipsis: Ellipsis,
+    max_lines: Option<usize>,
+    paragraph_spacing: Pixels,
+    selectable: bool,
     class: Theme::Class<'a>,
 }
 
@@ -51,6 +61,10 @@ where
             vertical_alignment: alignment::Vertical::Top,
             shaping: Shaping::default(),
             wrapping: Wrapping::default(),
+            ellipsis: Ellipsis::default(),
+            max_lines: None,
+            paragraph_spacing: Pixels::ZERO,
+            selectable: false,
             class: Theme::default(),
         }
     }
@@ -123,6 +137,44 @@ where
         self
     }
 
+    /// Sets the [`Ellipsis`] strategy of the [`Text`].
+    ///
+    /// The [`Text`] is truncated when it overflows its bounds or its
+    /// maximum amount of lines, and the removed part is replaced with an
+    /// ellipsis.
+    pub fn ellipsis(mut self, ellipsis: Ellipsis) -> Self {
+        self.ellipsis = ellipsis;
+        self
+    }
+
+    /// Sets the maximum amount of lines of the [`Text`].
+    ///
+    /// Any lines past the limit are truncated, using the [`Ellipsis`]
+    /// strategy of the [`Text`].
+    pub fn max_lines(mut self, max_lines: usize) -> Self {
+        self.max_lines = Some(max_lines);
+        self
+    }
+
+    /// Sets the spacing between the paragraphs of the [`Text`].
+    ///
+    /// Paragraphs are separated by line breaks.
+    pub fn paragraph_spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.paragraph_spacing = spacing.into();
+        self
+    }
+
+    /// Sets whether the contents of the [`Text`] can be selected and copied.
+    ///
+    /// A selectable [`Text`] can be selected by dragging the mouse over it,
+    /// double clicking a word, or triple clicking a line. Once clicked,
+    /// all of its contents can be selected with `Ctrl+A` and the selection
+    /// copied with `Ctrl+C`.
+    pub fn selectable(mut self, selectable: bool) -> Self {
+        self.selectable = selectable;
+        self
+    }
+
     /// Sets the style of the [`Text`].
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
@@ -162,7 +214,14 @@ where
 
 /// The internal state of a [`Text`] widget.
 #[derive(Debug, Default)]
-pub struct State<P: Paragraph>(pub paragraph::Plain<P>);
+pub struct State<P: Paragraph>(pub paragraph::Plain<P>, Selection);
+
+impl<P: Paragraph> State<P> {
+    /// Returns the [`Selection`] of the [`Text`].
+    pub fn selection(&self) -> &Selection {
+        &self.1
+    }
+}
 
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
     for Text<'a, Theme, Renderer>
@@ -175,9 +234,7 @@ where
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(State::<Renderer::Paragraph>(
-            paragraph::Plain::default(),
-        ))
+        tree::State::new(State::<Renderer::Paragraph>::default())
     }
 
     fn size(&self) -> Size<Length> {
@@ -207,6 +264,9 @@ where
             self.vertical_alignment,
             self.shaping,
             self.wrapping,
+            self.ellipsis,
+            self.max_lines,
+            self.paragraph_spacing,
         )
     }
 
@@ -223,8 +283,76 @@ where
         let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
         let style = theme.style(&self.class);
 
+        if self.selectable {
+            state.1.draw(
+                renderer,
+                state.0.raw(),
+                &self.fragment,
+                layout,
+                style.color.unwrap_or(defaults.text_color).scale_alpha(0.3),
+            );
+        }
+
         draw(renderer, defaults, layout, state.0.raw(), style, viewport);
     }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        _shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        if !self.selectable {
+            return event::Status::Ignored;
+        }
+
+        let State(paragraph, selection) =
+            tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        selection.update(
+            &event,
+            layout,
+            cursor,
+            paragraph.raw(),
+            &self.fragment,
+            clipboard,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+
+        if self.selectable
+            && (cursor.is_over(layout.bounds()) || state.1.is_dragging())
+        {
+            mouse::Interaction::Text
+        } else {
+            mouse::Interaction::None
+        }
+    }
+
+    fn operate(
+        &self,
+        _state: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.kind(None, layout.bounds(), "text");
+        operation.text(None, layout.bounds(), &self.fragment);
+    }
 }
 
 /// Produces the [`layout::Node`] of a [`Text`] widget.
@@ -242,6 +370,9 @@ pub fn layout<Renderer>(
     vertical_alignment: alignment::Vertical,
     shaping: Shaping,
     wrapping: Wrapping,
+    ellipsis: Ellipsis,
+    max_lines: Option<usize>,
+    paragraph_spacing: Pixels,
 ) -> layout::Node
 where
     Renderer: text::Renderer,
@@ -252,7 +383,7 @@ where
         let size = size.unwrap_or_else(|| renderer.default_size());
         let font = font.unwrap_or_else(|| renderer.default_font());
 
-        let State(ref mut paragraph) = state;
+        let State(ref mut paragraph, _) = state;
 
         paragraph.update(text::Text {
             content,
@@ -264,6 +395,9 @@ where
             vertical_alignment,
             shaping,
             wrapping,
+            ellipsis,
+            max_lines,
+            paragraph_spacing,
         });
 
         paragraph.min_bounds()
@@ -290,11 +424,58 @@ pub fn draw<Renderer>(
 ) where
     Renderer: text::Renderer,
 {
+    renderer.fill_paragraph(
+        paragraph,
+        anchor(layout, paragraph),
+        appearance.color.unwrap_or(style.text_color),
+        *viewport,
+    );
+}
+
+/// Returns the top-left corner of a [`Paragraph`] drawn by [`draw`] in the
+/// given [`Layout`].
+///
+/// Hit tests and range bounds of the [`Paragraph`] are relative to this
+/// position.
+pub fn position<P: Paragraph>(layout: Layout<'_>, paragraph: &P) -> Point {
+    let anchor = anchor(layout, paragraph);
+    let size = paragraph.min_bounds();
+
+    let x = match paragraph.horizontal_alignment() {
+        alignment::Horizontal::Left | alignment::Horizontal::Justified => {
+            anchor.x
+        }
+        alignment::Horizontal::Center => anchor.x - size.width / 2.0,
+        alignment::Horizontal::Right => anchor.x - size.width,
+    };
+
+    let y = match paragraph.vertical_alignment() {
+        alignment::Vertical::Top => anchor.y,
+        alignment::Vertical::Center => anchor.y - size.height / 2.0,
+        alignment::Vertical::Bottom => anchor.y - size.height,
+    };
+
+    Point::new(x, y)
+}
+
+fn anchor<P: Paragraph>(layout: Layout<'_>, paragraph: &P) -> Point {
     let bounds = layout.bounds();
 
     let x = match paragraph.horizontal_alignment() {
-        alignment::Horizontal::Left => bounds.x,
+        alignment::Horizontal::Left | alignment::Horizontal::Justified
+            if layout.direction().is_right_to_left() =>
+        {
+            bounds.x + bounds.width - paragraph.min_width()
+        }
+        alignment::Horizontal::Left | alignment::Horizontal::Justified => {
+            bounds.x
+        }
         alignment::Horizontal::Center => bounds.center_x(),
+        alignment::Horizontal::Right
+            if layout.direction().is_right_to_left() =>
+        {
+            bounds.x + paragraph.min_width()
+        }
         alignment::Horizontal::Right => bounds.x + bounds.width,
     };
 
@@ -304,12 +485,7 @@ pub fn draw<Renderer>(
         alignment::Vertical::Bottom => bounds.y + bounds.height,
     };
 
-    renderer.fill_paragraph(
-        paragraph,
-        Point::new(x, y),
-        appearance.color.unwrap_or(style.text_color),
-        *viewport,
-    );
+    Point::new(x, y)
 }
 
 impl<'a, Message, Theme, Renderer> From<Text<'a, Theme, Renderer>>
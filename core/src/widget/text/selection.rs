@@ -0,0 +1,299 @@
+//! Select and copy the contents of read-only text.
+use crate::clipboard::{self, Clipboard};
+use crate::event;
+use crate::keyboard;
+use crate::mouse::{self, click};
+use crate::renderer;
+use crate::text::{self, Paragraph};
+use crate::{Color, Event, Layout, Point};
+
+use std::ops::Range;
+
+/// The selection of some read-only text.
+///
+/// A [`Selection`] can be dragged with the mouse, expanded to a word or a
+/// line by double or triple clicking, and copied to the [`Clipboard`].
+#[derive(Debug, Clone, Default)]
+pub struct Selection {
+    range: Range<usize>,
+    origin: Range<usize>,
+    drag: Option<click::Kind>,
+    last_click: Option<mouse::Click>,
+    is_focused: bool,
+    keyboard_modifiers: keyboard::Modifiers,
+}
+
+impl Selection {
+    /// Returns the selected byte range of the given content, if any.
+    pub fn range(&self, content: &str) -> Option<Range<usize>> {
+        let start = floor_char_boundary(content, self.range.start);
+        let end = floor_char_boundary(content, self.range.end);
+
+        (start < end).then_some(start..end)
+    }
+
+    /// Returns the selected text of the given content, if any.
+    pub fn text<'a>(&self, content: &'a str) -> Option<&'a str> {
+        self.range(content).map(|range| &content[range])
+    }
+
+    /// Selects the whole content.
+    pub fn select_all(&mut self, content: &str) {
+        self.range = 0..content.len();
+        self.origin = self.range.clone();
+    }
+
+    /// Clears the [`Selection`].
+    pub fn clear(&mut self) {
+        self.range = 0..0;
+        self.origin = 0..0;
+    }
+
+    /// Processes an [`Event`] for some text laid out in the given
+    /// [`Paragraph`] and drawn in the given [`Layout`].
+    pub fn update<P: Paragraph>(
+        &mut self,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        paragraph: &P,
+        content: &str,
+        clipboard: &mut dyn Clipboard,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        let origin = super::position(layout, paragraph) - Point::ORIGIN;
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    self.is_focused = false;
+                    self.clear();
+
+                    return event::Status::Ignored;
+                };
+
+                let position = position - origin;
+
+                let Some(offset) = hit(paragraph, content, position) else {
+                    return event::Status::Ignored;
+                };
+
+                let click = mouse::Click::new(position, self.last_click);
+                let kind = click.kind();
+
+                let unit = expand(content, offset, kind);
+
+                if matches!(kind, click::Kind::Single)
+                    && self.keyboard_modifiers.shift()
+                    && self.is_focused
+                {
+                    self.range = join(&self.origin, &unit);
+                } else {
+                    self.origin = unit.clone();
+                    self.range = unit;
+                }
+
+                self.drag = Some(kind);
+                self.last_click = Some(click);
+                self.is_focused = true;
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                let Some(kind) = self.drag else {
+                    return event::Status::Ignored;
+                };
+
+                let Some(position) = cursor.position() else {
+                    return event::Status::Ignored;
+                };
+
+                // Keep selecting while dragging outside of the text
+                let position = Point::new(
+                    position.x.clamp(bounds.x, bounds.x + bounds.width),
+                    position.y.clamp(bounds.y, bounds.y + bounds.height),
+                ) - origin;
+
+                if let Some(offset) = hit(paragraph, content, position) {
+                    self.range =
+                        join(&self.origin, &expand(content, offset, kind));
+                }
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                self.drag = None;
+
+                event::Status::Ignored
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed { key, .. })
+                if self.is_focused && self.keyboard_modifiers.command() =>
+            {
+                match key.as_ref() {
+                    keyboard::Key::Character("a") => {
+                        self.select_all(content);
+
+                        event::Status::Captured
+                    }
+                    keyboard::Key::Character("c") => {
+                        if let Some(text) = self.text(content) {
+                            clipboard.write(
+                                clipboard::Kind::Standard,
+                                text.to_owned(),
+                            );
+                        }
+
+                        event::Status::Captured
+                    }
+                    _ => event::Status::Ignored,
+                }
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                self.keyboard_modifiers = *modifiers;
+
+                event::Status::Ignored
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    /// Returns true if the [`Selection`] is being dragged.
+    pub fn is_dragging(&self) -> bool {
+        self.drag.is_some()
+    }
+
+    /// Draws the [`Selection`] of some text laid out in the given
+    /// [`Paragraph`] and drawn in the given [`Layout`].
+    pub fn draw<Renderer>(
+        &self,
+        renderer: &mut Renderer,
+        paragraph: &Renderer::Paragraph,
+        content: &str,
+        layout: Layout<'_>,
+        color: Color,
+    ) where
+        Renderer: text::Renderer,
+    {
+        let Some(range) = self.range(content) else {
+            return;
+        };
+
+        let translation = super::position(layout, paragraph) - Point::ORIGIN;
+
+        for bounds in paragraph.range_bounds(range) {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: bounds + translation,
+                    ..renderer::Quad::default()
+                },
+                color,
+            );
+        }
+    }
+}
+
+fn hit<P: Paragraph>(
+    paragraph: &P,
+    content: &str,
+    position: Point,
+) -> Option<usize> {
+    let offset = paragraph.hit_test(position)?.cursor();
+
+    Some(floor_char_boundary(content, offset))
+}
+
+/// Expands the given offset to the unit selected by a click of the given
+/// kind: nothing, a word, or a line.
+fn expand(content: &str, offset: usize, kind: click::Kind) -> Range<usize> {
+    match kind {
+        click::Kind::Single => offset..offset,
+        click::Kind::Double => word(content, offset),
+        click::Kind::Triple => line(content, offset),
+    }
+}
+
+fn word(content: &str, offset: usize) -> Range<usize> {
+    let class = |c: char| {
+        if c.is_alphanumeric() || c == '_' {
+            Some(0)
+        } else if c.is_whitespace() && c != '\n' {
+            Some(1)
+        } else {
+            None
+        }
+    };
+
+    let Some(current) = content[offset..].chars().next() else {
+        return offset..offset;
+    };
+
+    let Some(current_class) = class(current) else {
+        return offset..offset + current.len_utf8();
+    };
+
+    let start = content[..offset]
+        .char_indices()
+        .rev()
+        .take_while(|(_, c)| class(*c) == Some(current_class))
+        .last()
+        .map_or(offset, |(i, _)| i);
+
+    let end = content[offset..]
+        .char_indices()
+        .find(|(_, c)| class(*c) != Some(current_class))
+        .map_or(content.len(), |(i, _)| offset + i);
+
+    start..end
+}
+
+fn line(content: &str, offset: usize) -> Range<usize> {
+    let start = content[..offset].rfind('\n').map_or(0, |i| i + 1);
+
+    let end = content[offset..]
+        .find('\n')
+        .map_or(content.len(), |i| offset + i);
+
+    start..end
+}
+
+fn join(a: &Range<usize>, b: &Range<usize>) -> Range<usize> {
+    a.start.min(b.start)..a.end.max(b.end)
+}
+
+fn floor_char_boundary(content: &str, offset: usize) -> usize {
+    let mut offset = offset.min(content.len());
+
+    while !content.is_char_boundary(offset) {
+        offset -= 1;
+    }
+
+    offset
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn double_and_triple_clicks_expand_to_words_and_lines() {
+        let content = "Hello, wonderful world!\nGoodbye";
+
+        assert_eq!(&content[word(content, 9)], "wonderful");
+        assert_eq!(&content[word(content, 5)], ",");
+        assert_eq!(&content[word(content, 6)], " ");
+        assert_eq!(&content[line(content, 9)], "Hello, wonderful world!");
+        assert_eq!(&content[line(content, 26)], "Goodbye");
+    }
+
+    #[test]
+    fn range_is_clamped_to_the_content() {
+        let mut selection = Selection::default();
+        selection.select_all("Hello, world!");
+
+        assert_eq!(selection.text("Hello"), Some("Hello"));
+        assert_eq!(selection.text("¡Hola!"), Some("¡Hola!"));
+
+        selection.clear();
+        assert_eq!(selection.text("Hello"), None);
+    }
+}
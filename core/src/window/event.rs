@@ -5,6 +5,11 @@ use std::path::PathBuf;
 
 /// A window-related event.
 #[derive(PartialEq, Clone, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(crate = "serde")
+)]
 pub enum Event {
     /// A window was opened.
     Opened {
@@ -31,6 +36,10 @@ pub enum Event {
     /// A window redraw was requested.
     ///
     /// The [`Instant`] contains the current time.
+    ///
+    /// This event is skipped when serializing, since it is only meaningful
+    /// for the current process.
+    #[cfg_attr(feature = "serde", serde(skip))]
     RedrawRequested(Instant),
 
     /// The user has requested for the window to close.
@@ -3,6 +3,11 @@ use std::hash::Hash;
 use std::sync::atomic::{self, AtomicU64};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(crate = "serde")
+)]
 /// The id of the window.
 ///
 /// Internally Iced reserves `window::Id::MAIN` for the first window spawned.
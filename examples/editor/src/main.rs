@@ -167,7 +167,7 @@ impl Editor {
             ),
             pick_list(
                 highlighter::Theme::ALL,
-                Some(self.theme),
+                Some(self.theme.clone()),
                 Message::ThemeSelected
             )
             .text_size(14)
@@ -202,6 +202,7 @@ impl Editor {
             text_editor(&self.content)
                 .height(Fill)
                 .on_action(Message::ActionPerformed)
+                .tab_indents(true)
                 .wrapping(if self.word_wrap {
                     text::Wrapping::Word
                 } else {
@@ -213,7 +214,7 @@ impl Editor {
                         .and_then(Path::extension)
                         .and_then(ffi::OsStr::to_str)
                         .unwrap_or("rs"),
-                    self.theme,
+                    self.theme.clone(),
                 ),
             status,
         ]
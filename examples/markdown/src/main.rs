@@ -61,7 +61,7 @@ impl Markdown {
             .height(Fill)
             .padding(10)
             .font(Font::MONOSPACE)
-            .highlight("markdown", highlighter::Theme::Base16Ocean);
+            .highlight("markdown", highlighter::Theme::Palette);
 
         let preview = markdown(
             &self.items,
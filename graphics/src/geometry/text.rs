@@ -41,10 +41,15 @@ impl Text {
         let mut font_system =
             text::font_system().write().expect("Write font system");
 
+        let mut attributes =
+            cosmic_text::AttrsList::new(text::to_attributes(self.font));
+
+        font_system.apply_fallbacks(&self.content, &mut attributes);
+
         let mut buffer = cosmic_text::BufferLine::new(
             &self.content,
             cosmic_text::LineEnding::default(),
-            cosmic_text::AttrsList::new(text::to_attributes(self.font)),
+            attributes,
             text::to_shaping(self.shaping),
         );
 
@@ -58,7 +63,9 @@ impl Text {
         );
 
         let translation_x = match self.horizontal_alignment {
-            alignment::Horizontal::Left => self.position.x,
+            alignment::Horizontal::Left | alignment::Horizontal::Justified => {
+                self.position.x
+            }
             alignment::Horizontal::Center | alignment::Horizontal::Right => {
                 let mut line_width = 0.0f32;
 
@@ -15,7 +15,9 @@ use crate::core::text::{Shaping, Wrapping};
 use crate::core::{Color, Pixels, Point, Rectangle, Size, Transformation};
 
 use once_cell::sync::OnceCell;
+use rustc_hash::{FxHashMap, FxHashSet};
 use std::borrow::Cow;
+use std::ops::Range;
 use std::sync::{Arc, RwLock, Weak};
 
 /// A text primitive.
@@ -118,7 +120,8 @@ impl Text {
 
         if let Some(alignment) = horizontal_alignment {
             match alignment {
-                alignment::Horizontal::Left => {}
+                alignment::Horizontal::Left
+                | alignment::Horizontal::Justified => {}
                 alignment::Horizontal::Center => {
                     bounds.x -= bounds.width / 2.0;
                 }
@@ -158,16 +161,20 @@ pub fn font_system() -> &'static RwLock<FontSystem> {
     static FONT_SYSTEM: OnceCell<RwLock<FontSystem>> = OnceCell::new();
 
     FONT_SYSTEM.get_or_init(|| {
+        let raw = cosmic_text::FontSystem::new_with_fonts([
+            cosmic_text::fontdb::Source::Binary(Arc::new(
+                include_bytes!("../fonts/Iced-Icons.ttf").as_slice(),
+            )),
+            #[cfg(all(target_arch = "wasm32", feature = "fira-sans"))]
+            cosmic_text::fontdb::Source::Binary(Arc::new(
+                include_bytes!("../fonts/FiraSans-Regular.ttf").as_slice(),
+            )),
+        ]);
+
         RwLock::new(FontSystem {
-            raw: cosmic_text::FontSystem::new_with_fonts([
-                cosmic_text::fontdb::Source::Binary(Arc::new(
-                    include_bytes!("../fonts/Iced-Icons.ttf").as_slice(),
-                )),
-                #[cfg(all(target_arch = "wasm32", feature = "fira-sans"))]
-                cosmic_text::fontdb::Source::Binary(Arc::new(
-                    include_bytes!("../fonts/FiraSans-Regular.ttf").as_slice(),
-                )),
-            ]),
+            raw,
+            fallbacks: Vec::new(),
+            faces: FxHashMap::default(),
             version: Version::default(),
         })
     })
@@ -177,9 +184,18 @@ pub fn font_system() -> &'static RwLock<FontSystem> {
 #[allow(missing_debug_implementations)]
 pub struct FontSystem {
     raw: cosmic_text::FontSystem,
+    fallbacks: Vec<font::Family>,
+    faces: FxHashMap<FaceKey, Option<cosmic_text::fontdb::ID>>,
     version: Version,
 }
 
+type FaceKey = (
+    cosmic_text::FamilyOwned,
+    cosmic_text::Weight,
+    cosmic_text::Stretch,
+    cosmic_text::Style,
+);
+
 impl FontSystem {
     /// Returns the raw [`cosmic_text::FontSystem`].
     pub fn raw(&mut self) -> &mut cosmic_text::FontSystem {
@@ -192,9 +208,254 @@ impl FontSystem {
             cosmic_text::fontdb::Source::Binary(Arc::new(bytes.into_owned())),
         );
 
+        self.faces.clear();
         self.version = Version(self.version.0 + 1);
     }
 
+    /// Returns the [`font::Face`] of every font in the [`FontSystem`],
+    /// sorted by family.
+    pub fn faces(&self) -> Vec<font::Face> {
+        let mut unique = FxHashSet::default();
+
+        let mut faces: Vec<_> = self
+            .raw
+            .db()
+            .faces()
+            .filter_map(|face| {
+                let (family, _language) = face.families.first()?;
+
+                Some(font::Face {
+                    family: family.clone(),
+                    weight: from_weight(face.weight),
+                    stretch: from_stretch(face.stretch),
+                    style: from_style(face.style),
+                    monospaced: face.monospaced,
+                })
+            })
+            .filter(|face| unique.insert(face.clone()))
+            .collect();
+
+        faces.sort_by(|a, b| {
+            a.family
+                .cmp(&b.family)
+                .then_with(|| (a.weight as u8).cmp(&(b.weight as u8)))
+        });
+
+        faces
+    }
+
+    /// Returns whether the [`font::Family`] of the given [`Font`] is
+    /// available in the [`FontSystem`].
+    ///
+    /// Generic families, like [`font::Family::SansSerif`], are always
+    /// available.
+    pub fn is_available(&self, font: Font) -> bool {
+        match font.family {
+            font::Family::Name(name) => self.raw.db().faces().any(|face| {
+                face.families
+                    .iter()
+                    .any(|(family, _language)| family == name)
+            }),
+            _ => true,
+        }
+    }
+
+    /// Sets the fallback chain of font families.
+    ///
+    /// See [`FontSystem::with_fallbacks`].
+    pub fn set_fallbacks(&mut self, families: Vec<font::Family>) {
+        self.fallbacks = families;
+        self.version = Version(self.version.0 + 1);
+    }
+
+    /// Splits the given spans of text so every character missing in the
+    /// family of its span uses the first family of the fallback chain that
+    /// contains it.
+    ///
+    /// If the family of a span is not available at all, the characters that
+    /// no family of the chain contains use the first available family of the
+    /// chain. The rest of the fonts of the system are only looked up during
+    /// shaping, once the chain is exhausted.
+    ///
+    /// See [`FontSystem::set_fallbacks`].
+    pub fn with_fallbacks<'a, 'b>(
+        &mut self,
+        spans: impl IntoIterator<Item = (&'a str, cosmic_text::Attrs<'b>)>,
+    ) -> Vec<(&'a str, cosmic_text::Attrs<'b>)> {
+        let mut result = Vec::new();
+
+        for (text, attrs) in spans {
+            let mut last = 0;
+
+            self.find_fallbacks(text, attrs, |range, family| {
+                if last < range.start {
+                    result.push((&text[last..range.start], attrs));
+                }
+
+                result.push((&text[range.clone()], attrs.family(family)));
+                last = range.end;
+            });
+
+            if last < text.len() || text.is_empty() {
+                result.push((&text[last..], attrs));
+            }
+        }
+
+        result
+    }
+
+    /// Adds a span to the given [`cosmic_text::AttrsList`] for every
+    /// character of the text that needs a family of the fallback chain.
+    ///
+    /// See [`FontSystem::with_fallbacks`].
+    pub fn apply_fallbacks(
+        &mut self,
+        text: &str,
+        attributes: &mut cosmic_text::AttrsList,
+    ) {
+        if self.fallbacks.is_empty() {
+            return;
+        }
+
+        let mut runs = Vec::new();
+        let mut start = 0;
+
+        for (i, _) in text.char_indices().skip(1) {
+            if attributes.get_span(i) != attributes.get_span(start) {
+                runs.push(start..i);
+                start = i;
+            }
+        }
+
+        if start < text.len() {
+            runs.push(start..text.len());
+        }
+
+        let mut spans = Vec::new();
+
+        for run in runs {
+            let attrs =
+                cosmic_text::AttrsOwned::new(attributes.get_span(run.start));
+
+            self.find_fallbacks(
+                &text[run.clone()],
+                attrs.as_attrs(),
+                |range, family| {
+                    spans.push((
+                        run.start + range.start..run.start + range.end,
+                        cosmic_text::AttrsOwned::new(
+                            attrs.as_attrs().family(family),
+                        ),
+                    ));
+                },
+            );
+        }
+
+        for (range, attrs) in spans {
+            attributes.add_span(range, attrs.as_attrs());
+        }
+    }
+
+    /// Calls the given function with every range of characters of the text
+    /// that should use a family of the fallback chain, along with the
+    /// family.
+    fn find_fallbacks(
+        &mut self,
+        text: &str,
+        attrs: cosmic_text::Attrs<'_>,
+        mut f: impl FnMut(Range<usize>, cosmic_text::Family<'static>),
+    ) {
+        if self.fallbacks.is_empty() {
+            return;
+        }
+
+        let primary = self.font(attrs.family, attrs);
+
+        let chain: Vec<_> = self
+            .fallbacks
+            .clone()
+            .into_iter()
+            .filter_map(|family| {
+                let family = to_family(family);
+
+                Some((family, self.font(family, attrs)?))
+            })
+            .collect();
+
+        let Some(first) = chain.first().map(|(family, _)| *family) else {
+            return;
+        };
+
+        let mut current: Option<(Range<usize>, cosmic_text::Family<'static>)> =
+            None;
+
+        for (i, c) in text.char_indices() {
+            let contains = |font: &cosmic_text::Font| {
+                font.rustybuzz().glyph_index(c).is_some()
+            };
+
+            let family =
+                if c.is_control() || primary.as_deref().is_some_and(contains) {
+                    None
+                } else {
+                    chain
+                        .iter()
+                        .find(|(_, font)| contains(font))
+                        .map(|(family, _)| *family)
+                        .or(primary.is_none().then_some(first))
+                };
+
+            let range = i..i + c.len_utf8();
+
+            match (&mut current, family) {
+                (Some((current, last)), Some(family))
+                    if *last == family && current.end == range.start =>
+                {
+                    current.end = range.end;
+                }
+                (_, family) => {
+                    if let Some((range, family)) = current.take() {
+                        f(range, family);
+                    }
+
+                    current = family.map(|family| (range, family));
+                }
+            }
+        }
+
+        if let Some((range, family)) = current {
+            f(range, family);
+        }
+    }
+
+    /// Returns the font of the given family that best matches the given
+    /// attributes, if any.
+    fn font(
+        &mut self,
+        family: cosmic_text::Family<'_>,
+        attrs: cosmic_text::Attrs<'_>,
+    ) -> Option<Arc<cosmic_text::Font>> {
+        let key = (
+            cosmic_text::FamilyOwned::new(family),
+            attrs.weight,
+            attrs.stretch,
+            attrs.style,
+        );
+
+        let raw = &self.raw;
+
+        let id = *self.faces.entry(key).or_insert_with(|| {
+            raw.db().query(&cosmic_text::fontdb::Query {
+                families: &[family],
+                weight: attrs.weight,
+                stretch: attrs.stretch,
+                style: attrs.style,
+            })
+        });
+
+        self.raw.get_font(id?)
+    }
+
     /// Returns the current [`Version`] of the [`FontSystem`].
     ///
     /// Loading a font will increase the version of a [`FontSystem`].
@@ -298,6 +559,42 @@ fn to_style(style: font::Style) -> cosmic_text::Style {
     }
 }
 
+fn from_weight(weight: cosmic_text::Weight) -> font::Weight {
+    match weight.0 {
+        0..=149 => font::Weight::Thin,
+        150..=249 => font::Weight::ExtraLight,
+        250..=349 => font::Weight::Light,
+        350..=449 => font::Weight::Normal,
+        450..=549 => font::Weight::Medium,
+        550..=649 => font::Weight::Semibold,
+        650..=749 => font::Weight::Bold,
+        750..=849 => font::Weight::ExtraBold,
+        _ => font::Weight::Black,
+    }
+}
+
+fn from_stretch(stretch: cosmic_text::Stretch) -> font::Stretch {
+    match stretch {
+        cosmic_text::Stretch::UltraCondensed => font::Stretch::UltraCondensed,
+        cosmic_text::Stretch::ExtraCondensed => font::Stretch::ExtraCondensed,
+        cosmic_text::Stretch::Condensed => font::Stretch::Condensed,
+        cosmic_text::Stretch::SemiCondensed => font::Stretch::SemiCondensed,
+        cosmic_text::Stretch::Normal => font::Stretch::Normal,
+        cosmic_text::Stretch::SemiExpanded => font::Stretch::SemiExpanded,
+        cosmic_text::Stretch::Expanded => font::Stretch::Expanded,
+        cosmic_text::Stretch::ExtraExpanded => font::Stretch::ExtraExpanded,
+        cosmic_text::Stretch::UltraExpanded => font::Stretch::UltraExpanded,
+    }
+}
+
+fn from_style(style: cosmic_text::Style) -> font::Style {
+    match style {
+        cosmic_text::Style::Normal => font::Style::Normal,
+        cosmic_text::Style::Italic => font::Style::Italic,
+        cosmic_text::Style::Oblique => font::Style::Oblique,
+    }
+}
+
 /// Converts some [`Shaping`] strategy to a [`cosmic_text::Shaping`] strategy.
 pub fn to_shaping(shaping: Shaping) -> cosmic_text::Shaping {
     match shaping {
@@ -322,3 +619,101 @@ pub fn to_color(color: Color) -> cosmic_text::Color {
 
     cosmic_text::Color::rgba(r, g, b, a)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_spans_with_fallback_chain() {
+        let mut font_system = font_system();
+
+        let fira_sans = to_attributes(Font::with_name("Fira Sans"));
+        let missing = to_attributes(Font::with_name("Missing"));
+
+        let text = "a\u{f00c}b";
+
+        let fira_sans_family = cosmic_text::Family::Name("Fira Sans");
+        let icons_family = cosmic_text::Family::Name("Iced-Icons");
+
+        assert_eq!(
+            families(font_system.with_fallbacks([(text, fira_sans)])),
+            [(text, fira_sans_family)]
+        );
+
+        font_system.set_fallbacks(vec![
+            font::Family::Name("Inter"),
+            font::Family::Name("Iced-Icons"),
+            font::Family::Name("Fira Sans"),
+        ]);
+
+        let split = [
+            ("a", fira_sans_family),
+            ("\u{f00c}", icons_family),
+            ("b", fira_sans_family),
+        ];
+
+        assert_eq!(
+            families(font_system.with_fallbacks([(text, fira_sans)])),
+            split
+        );
+        assert_eq!(
+            families(font_system.with_fallbacks([(text, missing)])),
+            split
+        );
+
+        font_system.set_fallbacks(vec![font::Family::Name("Iced-Icons")]);
+
+        assert_eq!(
+            families(font_system.with_fallbacks([(text, missing)])),
+            [(text, icons_family)]
+        );
+
+        let mut attributes = cosmic_text::AttrsList::new(fira_sans);
+        font_system.apply_fallbacks(text, &mut attributes);
+
+        assert_eq!(attributes.get_span(1).family, icons_family);
+        assert_eq!(attributes.get_span(4), fira_sans);
+    }
+
+    #[test]
+    fn list_every_face_once() {
+        let mut font_system = font_system();
+
+        font_system.load_font(Cow::Borrowed(FIRA_SANS));
+
+        let faces = font_system.faces();
+
+        assert_eq!(faces.len(), 2);
+        assert_eq!(faces[0].family, "Fira Sans");
+        assert_eq!(faces[1].family, "Iced-Icons");
+    }
+
+    const ICED_ICONS: &[u8] = include_bytes!("../fonts/Iced-Icons.ttf");
+    const FIRA_SANS: &[u8] = include_bytes!("../fonts/FiraSans-Regular.ttf");
+
+    fn font_system() -> FontSystem {
+        let mut database = cosmic_text::fontdb::Database::new();
+        database.load_font_data(ICED_ICONS.to_vec());
+        database.load_font_data(FIRA_SANS.to_vec());
+
+        FontSystem {
+            raw: cosmic_text::FontSystem::new_with_locale_and_db(
+                String::from("en-US"),
+                database,
+            ),
+            fallbacks: Vec::new(),
+            faces: FxHashMap::default(),
+            version: Version::default(),
+        }
+    }
+
+    fn families<'a, 'b>(
+        spans: Vec<(&'a str, cosmic_text::Attrs<'b>)>,
+    ) -> Vec<(&'a str, cosmic_text::Family<'b>)> {
+        spans
+            .into_iter()
+            .map(|(text, attrs)| (text, attrs.family))
+            .collect()
+    }
+}
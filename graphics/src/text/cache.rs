@@ -28,7 +28,7 @@ impl Cache {
     /// Allocates a text [`Entry`] if it is not already present in the [`Cache`].
     pub fn allocate(
         &mut self,
-        font_system: &mut cosmic_text::FontSystem,
+        font_system: &mut text::FontSystem,
         key: Key<'_>,
     ) -> (KeyHash, &mut Entry) {
         let hash = key.hash(FxHasher::default());
@@ -44,17 +44,22 @@ impl Cache {
                 key.size,
                 key.line_height.max(f32::MIN_POSITIVE),
             );
-            let mut buffer = cosmic_text::Buffer::new(font_system, metrics);
+            let mut buffer =
+                cosmic_text::Buffer::new(font_system.raw(), metrics);
 
             buffer.set_size(
-                font_system,
+                font_system.raw(),
                 Some(key.bounds.width),
                 Some(key.bounds.height.max(key.line_height)),
             );
-            buffer.set_text(
-                font_system,
-                key.content,
-                text::to_attributes(key.font),
+
+            let attributes = text::to_attributes(key.font);
+            let spans = font_system.with_fallbacks([(key.content, attributes)]);
+
+            buffer.set_rich_text(
+                font_system.raw(),
+                spans,
+                attributes,
                 text::to_shaping(key.shaping),
             );
 
@@ -1,6 +1,6 @@
 //! Draw and edit text.
 use crate::core::text::editor::{
-    self, Action, Cursor, Direction, Edit, Motion,
+    self, Action, Cursor, Direction, Edit, Motion, Position,
 };
 use crate::core::text::highlighter::{self, Highlighter};
 use crate::core::text::{LineHeight, Wrapping};
@@ -18,6 +18,8 @@ pub struct Editor(Option<Arc<Internal>>);
 
 struct Internal {
     editor: cosmic_text::Editor<'static>,
+    carets: Vec<Caret>,
+    box_carets: Option<Vec<Caret>>,
     font: Font,
     bounds: Size,
     topmost_line_changed: Option<usize>,
@@ -101,136 +103,66 @@ impl editor::Editor for Editor {
     }
 
     fn selection(&self) -> Option<String> {
-        self.internal().editor.copy_selection()
-    }
-
-    fn cursor(&self) -> editor::Cursor {
         let internal = self.internal();
 
-        let cursor = internal.editor.cursor();
-        let buffer = buffer_from_editor(&internal.editor);
-
-        match internal.editor.selection_bounds() {
-            Some((start, end)) => {
-                let line_height = buffer.metrics().line_height;
-                let selected_lines = end.line - start.line + 1;
-
-                let visual_lines_offset =
-                    visual_lines_offset(start.line, buffer);
+        if internal.carets.is_empty() {
+            return internal.editor.copy_selection();
+        }
 
-                let regions = buffer
-                    .lines
-                    .iter()
-                    .skip(start.line)
-                    .take(selected_lines)
-                    .enumerate()
-                    .flat_map(|(i, line)| {
-                        highlight_line(
-                            line,
-                            if i == 0 { start.index } else { 0 },
-                            if i == selected_lines - 1 {
-                                end.index
-                            } else {
-                                line.text().len()
-                            },
-                        )
-                    })
-                    .enumerate()
-                    .filter_map(|(visual_line, (x, width))| {
-                        if width > 0.0 {
-                            Some(Rectangle {
-                                x,
-                                width,
-                                y: (visual_line as i32 + visual_lines_offset)
-                                    as f32
-                                    * line_height
-                                    - buffer.scroll().vertical,
-                                height: line_height,
-                            })
-                        } else {
-                            None
-                        }
-                    })
-                    .collect();
+        let buffer = buffer_from_editor(&internal.editor);
 
-                Cursor::Selection(regions)
-            }
-            _ => {
-                let line_height = buffer.metrics().line_height;
+        let selections: Vec<_> = carets(internal)
+            .iter()
+            .filter_map(Caret::bounds)
+            .map(|(start, end)| text_between(buffer, start, end))
+            .collect();
 
-                let visual_lines_offset =
-                    visual_lines_offset(cursor.line, buffer);
+        (!selections.is_empty()).then(|| selections.join("\n"))
+    }
 
-                let line = buffer
-                    .lines
-                    .get(cursor.line)
-                    .expect("Cursor line should be present");
+    fn selection_range(&self) -> Option<(Position, Position)> {
+        let (start, end) = self.internal().editor.selection_bounds()?;
 
-                let layout = line
-                    .layout_opt()
-                    .as_ref()
-                    .expect("Line layout should be cached");
+        Some((
+            Position::new(start.line, start.index),
+            Position::new(end.line, end.index),
+        ))
+    }
 
-                let mut lines = layout.iter().enumerate();
+    fn selection_ranges(&self) -> Vec<(Position, Position)> {
+        carets(self.internal())
+            .iter()
+            .map(|caret| {
+                let (start, end) =
+                    caret.bounds().unwrap_or((caret.cursor, caret.cursor));
+
+                (
+                    Position::new(start.line, start.index),
+                    Position::new(end.line, end.index),
+                )
+            })
+            .collect()
+    }
 
-                let (visual_line, offset) = lines
-                    .find_map(|(i, line)| {
-                        let start = line
-                            .glyphs
-                            .first()
-                            .map(|glyph| glyph.start)
-                            .unwrap_or(0);
-                        let end = line
-                            .glyphs
-                            .last()
-                            .map(|glyph| glyph.end)
-                            .unwrap_or(0);
+    fn cursor(&self) -> editor::Cursor {
+        let internal = self.internal();
 
-                        let is_cursor_before_start = start > cursor.index;
+        visual_cursor(
+            buffer_from_editor(&internal.editor),
+            internal.editor.cursor(),
+            internal.editor.selection_bounds(),
+        )
+    }
 
-                        let is_cursor_before_end = match cursor.affinity {
-                            cosmic_text::Affinity::Before => {
-                                cursor.index <= end
-                            }
-                            cosmic_text::Affinity::After => cursor.index < end,
-                        };
-
-                        if is_cursor_before_start {
-                            // Sometimes, the glyph we are looking for is right
-                            // between lines. This can happen when a line wraps
-                            // on a space.
-                            // In that case, we can assume the cursor is at the
-                            // end of the previous line.
-                            // i is guaranteed to be > 0 because `start` is always
-                            // 0 for the first line, so there is no way for the
-                            // cursor to be before it.
-                            Some((i - 1, layout[i - 1].w))
-                        } else if is_cursor_before_end {
-                            let offset = line
-                                .glyphs
-                                .iter()
-                                .take_while(|glyph| cursor.index > glyph.start)
-                                .map(|glyph| glyph.w)
-                                .sum();
-
-                            Some((i, offset))
-                        } else {
-                            None
-                        }
-                    })
-                    .unwrap_or((
-                        layout.len().saturating_sub(1),
-                        layout.last().map(|line| line.w).unwrap_or(0.0),
-                    ));
+    fn cursors(&self) -> Vec<editor::Cursor> {
+        let internal = self.internal();
+        let buffer = buffer_from_editor(&internal.editor);
 
-                Cursor::Caret(Point::new(
-                    offset,
-                    (visual_lines_offset + visual_line as i32) as f32
-                        * line_height
-                        - buffer.scroll().vertical,
-                ))
-            }
-        }
+        std::iter::once(self.cursor())
+            .chain(internal.carets.iter().map(|caret| {
+                visual_cursor(buffer, caret.cursor, caret.bounds())
+            }))
+            .collect()
     }
 
     fn cursor_position(&self) -> (usize, usize) {
@@ -250,72 +182,48 @@ impl editor::Editor for Editor {
         let mut internal = Arc::try_unwrap(editor)
             .expect("Editor cannot have multiple strong references");
 
-        let editor = &mut internal.editor;
+        // A rectangular selection ends with any other interaction
+        if !matches!(
+            action,
+            Action::SelectBox { .. }
+                | Action::Scroll { .. }
+                | Action::ScrollHorizontally { .. }
+        ) {
+            internal.box_carets = None;
+        }
 
         match action {
             // Motion events
             Action::Move(motion) => {
-                if let Some((start, end)) = editor.selection_bounds() {
-                    editor.set_selection(cosmic_text::Selection::None);
-
-                    match motion {
-                        // These motions are performed as-is even when a selection
-                        // is present
-                        Motion::Home
-                        | Motion::End
-                        | Motion::DocumentStart
-                        | Motion::DocumentEnd => {
-                            editor.action(
-                                font_system.raw(),
-                                cosmic_text::Action::Motion(to_motion(motion)),
-                            );
-                        }
-                        // Other motions simply move the cursor to one end of the selection
-                        _ => editor.set_cursor(match motion.direction() {
-                            Direction::Left => start,
-                            Direction::Right => end,
-                        }),
-                    }
-                } else {
-                    editor.action(
-                        font_system.raw(),
-                        cosmic_text::Action::Motion(to_motion(motion)),
-                    );
-                }
+                for_each_caret(&mut internal, |editor, _| {
+                    move_(editor, font_system.raw(), motion);
+                });
             }
 
             // Selection events
             Action::Select(motion) => {
-                let cursor = editor.cursor();
-
-                if editor.selection_bounds().is_none() {
-                    editor
-                        .set_selection(cosmic_text::Selection::Normal(cursor));
-                }
-
-                editor.action(
-                    font_system.raw(),
-                    cosmic_text::Action::Motion(to_motion(motion)),
-                );
-
-                // Deselect if selection matches cursor position
-                if let Some((start, end)) = editor.selection_bounds() {
-                    if start.line == end.line && start.index == end.index {
-                        editor.set_selection(cosmic_text::Selection::None);
-                    }
-                }
+                for_each_caret(&mut internal, |editor, _| {
+                    select(editor, font_system.raw(), motion);
+                });
             }
             Action::SelectWord => {
-                let cursor = editor.cursor();
+                for_each_caret(&mut internal, |editor, _| {
+                    let cursor = editor.cursor();
 
-                editor.set_selection(cosmic_text::Selection::Word(cursor));
+                    editor.set_selection(cosmic_text::Selection::Word(cursor));
+                });
             }
             Action::SelectLine => {
-                let cursor = editor.cursor();
+                for_each_caret(&mut internal, |editor, _| {
+                    let cursor = editor.cursor();
 
-                editor.set_selection(cosmic_text::Selection::Line(cursor));
+                    editor.set_selection(cosmic_text::Selection::Line(cursor));
+                });
             }
             Action::SelectAll => {
+                internal.carets.clear();
+
+                let editor = &mut internal.editor;
                 let buffer = buffer_from_editor(editor);
 
                 if buffer.lines.len() > 1
@@ -345,48 +253,42 @@ impl editor::Editor for Editor {
 
             // Editing events
             Action::Edit(edit) => {
-                match edit {
-                    Edit::Insert(c) => {
-                        editor.action(
-                            font_system.raw(),
-                            cosmic_text::Action::Insert(c),
-                        );
-                    }
-                    Edit::Paste(text) => {
-                        editor.insert_string(&text, None);
-                    }
-                    Edit::Enter => {
-                        editor.action(
-                            font_system.raw(),
-                            cosmic_text::Action::Enter,
-                        );
-                    }
-                    Edit::Backspace => {
-                        editor.action(
-                            font_system.raw(),
-                            cosmic_text::Action::Backspace,
-                        );
-                    }
-                    Edit::Delete => {
-                        editor.action(
-                            font_system.raw(),
-                            cosmic_text::Action::Delete,
-                        );
+                let topmost = topmost_line(&internal);
+
+                // Distribute pasted lines among carets, if they match
+                let pasted_lines: Option<Vec<String>> = match &edit {
+                    Edit::Paste(text) if !internal.carets.is_empty() => {
+                        let lines: Vec<_> =
+                            text.lines().map(str::to_owned).collect();
+
+                        (lines.len() == internal.carets.len() + 1)
+                            .then_some(lines)
                     }
-                }
+                    _ => None,
+                };
 
-                let cursor = editor.cursor();
-                let selection_start = editor
-                    .selection_bounds()
-                    .map(|(start, _)| start)
-                    .unwrap_or(cursor);
+                for_each_caret(&mut internal, |editor, index| {
+                    match (&edit, &pasted_lines) {
+                        (Edit::Paste(_), Some(lines)) => {
+                            insert(editor, &lines[index]);
+                        }
+                        _ => {
+                            self::edit(editor, font_system.raw(), edit.clone());
+                        }
+                    }
+                });
 
-                internal.topmost_line_changed = Some(selection_start.line);
+                internal.topmost_line_changed =
+                    Some(topmost_line(&internal).min(topmost));
             }
 
             // Mouse events
             Action::Click(position) => {
-                editor.action(
+                internal.carets.clear();
+
+                let position = scrolled(&internal.editor, position);
+
+                internal.editor.action(
                     font_system.raw(),
                     cosmic_text::Action::Click {
                         x: position.x as i32,
@@ -395,6 +297,9 @@ impl editor::Editor for Editor {
                 );
             }
             Action::Drag(position) => {
+                let editor = &mut internal.editor;
+                let position = scrolled(editor, position);
+
                 editor.action(
                     font_system.raw(),
                     cosmic_text::Action::Drag {
@@ -410,12 +315,101 @@ impl editor::Editor for Editor {
                     }
                 }
             }
+            Action::AddCursor(position) => {
+                let editor = &mut internal.editor;
+                let position = scrolled(editor, position);
+
+                if let Some(cursor) =
+                    buffer_from_editor(editor).hit(position.x, position.y)
+                {
+                    let caret = Caret::of(editor);
+
+                    internal.carets.retain(|caret| {
+                        !is_same_position(caret.cursor, cursor)
+                    });
+
+                    if !is_same_position(caret.cursor, cursor) {
+                        internal.carets.push(caret);
+                    }
+
+                    editor.set_selection(cosmic_text::Selection::None);
+                    editor.set_cursor(cursor);
+                }
+            }
+            Action::SelectBox { start, end } => {
+                // The carets that existed before the rectangular selection
+                // started are kept
+                let existing = internal
+                    .box_carets
+                    .get_or_insert_with(|| internal.carets.clone())
+                    .clone();
+
+                let mut carets = box_carets(
+                    buffer_from_editor(&internal.editor),
+                    scrolled(&internal.editor, start),
+                    scrolled(&internal.editor, end),
+                );
+
+                if !carets.is_empty() {
+                    let primary = if end.y < start.y {
+                        carets.remove(0)
+                    } else {
+                        carets.pop().expect("Carets should not be empty")
+                    };
+
+                    let mut carets: Vec<_> = std::iter::once((primary, true))
+                        .chain(
+                            existing
+                                .into_iter()
+                                .chain(carets)
+                                .map(|caret| (caret, false)),
+                        )
+                        .collect();
+
+                    carets.sort_by_key(|(caret, _)| position(caret.start()));
+
+                    restore_carets(&mut internal, carets);
+                }
+            }
             Action::Scroll { lines } => {
-                editor.action(
+                internal.editor.action(
                     font_system.raw(),
                     cosmic_text::Action::Scroll { lines },
                 );
             }
+            Action::ScrollHorizontally { pixels } => {
+                let width = internal.bounds.width;
+                let buffer = buffer_mut_from_editor(&mut internal.editor);
+
+                let max = (text::measure(buffer).width - width).max(0.0);
+                let mut scroll = buffer.scroll();
+
+                scroll.horizontal =
+                    (scroll.horizontal + pixels).clamp(0.0, max);
+                buffer.set_scroll(scroll);
+            }
+            Action::MoveTo(position) => {
+                internal.carets.clear();
+
+                let editor = &mut internal.editor;
+                let cursor = to_cursor(position, buffer_from_editor(editor));
+
+                editor.set_selection(cosmic_text::Selection::None);
+                editor.set_cursor(cursor);
+            }
+            Action::SelectRange { start, end } => {
+                internal.carets.clear();
+
+                let editor = &mut internal.editor;
+                let buffer = buffer_from_editor(editor);
+                let start = to_cursor(start, buffer);
+                let end = to_cursor(end, buffer);
+
+                editor.set_selection(cosmic_text::Selection::Normal(start));
+                editor.set_cursor(end);
+            }
+            // History is kept by the owner of the editor
+            Action::Undo | Action::Redo => {}
         }
 
         self.0 = Some(Arc::new(internal));
@@ -431,6 +425,38 @@ impl editor::Editor for Editor {
         text::measure(buffer_from_editor(&internal.editor))
     }
 
+    fn horizontal_scroll(&self) -> f32 {
+        buffer_from_editor(&self.internal().editor)
+            .scroll()
+            .horizontal
+    }
+
+    fn visible_lines(&self) -> Vec<(usize, Rectangle)> {
+        let internal = self.internal();
+        let buffer = buffer_from_editor(&internal.editor);
+
+        let mut lines: Vec<(usize, Rectangle)> = Vec::new();
+
+        for run in buffer.layout_runs() {
+            match lines.last_mut() {
+                Some((line, bounds)) if *line == run.line_i => {
+                    bounds.height = run.line_top + run.line_height - bounds.y;
+                }
+                _ => lines.push((
+                    run.line_i,
+                    Rectangle {
+                        x: 0.0,
+                        y: run.line_top,
+                        width: internal.bounds.width,
+                        height: run.line_height,
+                    },
+                )),
+            }
+        }
+
+        lines
+    }
+
     fn update(
         &mut self,
         new_bounds: Size,
@@ -520,6 +546,18 @@ impl editor::Editor for Editor {
 
         internal.editor.shape_as_needed(font_system.raw(), false);
 
+        // Edits and resizes may leave the contents scrolled past their end
+        let width = internal.bounds.width;
+        let buffer = buffer_mut_from_editor(&mut internal.editor);
+
+        let max = (text::measure(buffer).width - width).max(0.0);
+        let mut scroll = buffer.scroll();
+
+        if scroll.horizontal > max {
+            scroll.horizontal = max;
+            buffer.set_scroll(scroll);
+        }
+
         self.0 = Some(Arc::new(internal));
     }
 
@@ -595,6 +633,8 @@ impl editor::Editor for Editor {
                 }
             }
 
+            font_system.apply_fallbacks(line.text(), &mut list);
+
             let _ = line.set_attrs_list(list);
         }
 
@@ -628,6 +668,8 @@ impl Default for Internal {
                     line_height: 1.0,
                 },
             )),
+            carets: Vec::new(),
+            box_carets: None,
             font: Font::default(),
             bounds: Size::ZERO,
             topmost_line_changed: None,
@@ -669,6 +711,851 @@ impl PartialEq for Weak {
     }
 }
 
+/// Computes the visual [`Cursor`] of a caret of a buffer.
+fn visual_cursor(
+    buffer: &cosmic_text::Buffer,
+    cursor: cosmic_text::Cursor,
+    selection: Option<(cosmic_text::Cursor, cosmic_text::Cursor)>,
+) -> Cursor {
+    match selection {
+        Some((start, end)) => {
+            let line_height = buffer.metrics().line_height;
+            let selected_lines = end.line - start.line + 1;
+
+            let visual_lines_offset = visual_lines_offset(start.line, buffer);
+
+            let regions = buffer
+                .lines
+                .iter()
+                .skip(start.line)
+                .take(selected_lines)
+                .enumerate()
+                .flat_map(|(i, line)| {
+                    highlight_line(
+                        line,
+                        if i == 0 { start.index } else { 0 },
+                        if i == selected_lines - 1 {
+                            end.index
+                        } else {
+                            line.text().len()
+                        },
+                    )
+                })
+                .enumerate()
+                .filter_map(|(visual_line, (x, width))| {
+                    if width > 0.0 {
+                        Some(Rectangle {
+                            x: x - buffer.scroll().horizontal,
+                            width,
+                            y: (visual_line as i32 + visual_lines_offset)
+                                as f32
+                                * line_height
+                                - buffer.scroll().vertical,
+                            height: line_height,
+                        })
+                    } else {
+                        None
+                    }
+                })
+                .collect();
+
+            Cursor::Selection(regions)
+        }
+        _ => {
+            let line_height = buffer.metrics().line_height;
+
+            let visual_lines_offset = visual_lines_offset(cursor.line, buffer);
+
+            let line = buffer
+                .lines
+                .get(cursor.line)
+                .expect("Cursor line should be present");
+
+            let layout = line
+                .layout_opt()
+                .as_ref()
+                .expect("Line layout should be cached");
+
+            let mut lines = layout.iter().enumerate();
+
+            let (visual_line, offset) = lines
+                .find_map(|(i, line)| {
+                    let start = line
+                        .glyphs
+                        .first()
+                        .map(|glyph| glyph.start)
+                        .unwrap_or(0);
+                    let end =
+                        line.glyphs.last().map(|glyph| glyph.end).unwrap_or(0);
+
+                    let is_cursor_before_start = start > cursor.index;
+
+                    let is_cursor_before_end = match cursor.affinity {
+                        cosmic_text::Affinity::Before => cursor.index <= end,
+                        cosmic_text::Affinity::After => cursor.index < end,
+                    };
+
+                    if is_cursor_before_start {
+                        // Sometimes, the glyph we are looking for is right
+                        // between lines. This can happen when a line wraps
+                        // on a space.
+                        // In that case, we can assume the cursor is at the
+                        // end of the previous line.
+                        // i is guaranteed to be > 0 because `start` is always
+                        // 0 for the first line, so there is no way for the
+                        // cursor to be before it.
+                        Some((i - 1, layout[i - 1].w))
+                    } else if is_cursor_before_end {
+                        let offset = line
+                            .glyphs
+                            .iter()
+                            .take_while(|glyph| cursor.index > glyph.start)
+                            .map(|glyph| glyph.w)
+                            .sum();
+
+                        Some((i, offset))
+                    } else {
+                        None
+                    }
+                })
+                .unwrap_or((
+                    layout.len().saturating_sub(1),
+                    layout.last().map(|line| line.w).unwrap_or(0.0),
+                ));
+
+            Cursor::Caret(Point::new(
+                offset - buffer.scroll().horizontal,
+                (visual_lines_offset + visual_line as i32) as f32 * line_height
+                    - buffer.scroll().vertical,
+            ))
+        }
+    }
+}
+
+fn move_(
+    editor: &mut cosmic_text::Editor<'static>,
+    font_system: &mut cosmic_text::FontSystem,
+    motion: Motion,
+) {
+    if let Some((start, end)) = editor.selection_bounds() {
+        editor.set_selection(cosmic_text::Selection::None);
+
+        match motion {
+            // These motions are performed as-is even when a selection
+            // is present
+            Motion::Home
+            | Motion::End
+            | Motion::DocumentStart
+            | Motion::DocumentEnd => {
+                editor.action(
+                    font_system,
+                    cosmic_text::Action::Motion(to_motion(motion)),
+                );
+            }
+            // Other motions simply move the cursor to one end of the selection
+            _ => editor.set_cursor(match motion.direction() {
+                Direction::Left => start,
+                Direction::Right => end,
+            }),
+        }
+    } else {
+        editor.action(
+            font_system,
+            cosmic_text::Action::Motion(to_motion(motion)),
+        );
+    }
+}
+
+fn select(
+    editor: &mut cosmic_text::Editor<'static>,
+    font_system: &mut cosmic_text::FontSystem,
+    motion: Motion,
+) {
+    let cursor = editor.cursor();
+
+    if editor.selection_bounds().is_none() {
+        editor.set_selection(cosmic_text::Selection::Normal(cursor));
+    }
+
+    editor.action(font_system, cosmic_text::Action::Motion(to_motion(motion)));
+
+    // Deselect if selection matches cursor position
+    if let Some((start, end)) = editor.selection_bounds() {
+        if start.line == end.line && start.index == end.index {
+            editor.set_selection(cosmic_text::Selection::None);
+        }
+    }
+}
+
+fn edit(
+    editor: &mut cosmic_text::Editor<'static>,
+    font_system: &mut cosmic_text::FontSystem,
+    edit: Edit,
+) {
+    match edit {
+        Edit::Insert('\t') => {
+            insert(editor, "\t");
+        }
+        Edit::Insert(c) => {
+            editor.action(font_system, cosmic_text::Action::Insert(c));
+        }
+        Edit::Paste(text) => {
+            insert(editor, &text);
+        }
+        Edit::Enter => {
+            editor.action(font_system, cosmic_text::Action::Enter);
+        }
+        Edit::Backspace => {
+            editor.action(font_system, cosmic_text::Action::Backspace);
+        }
+        Edit::Delete => {
+            editor.action(font_system, cosmic_text::Action::Delete);
+        }
+        Edit::EnterIndented => {
+            let start = selection_start(editor);
+            let text = buffer_from_editor(editor).lines[start.line].text();
+
+            let indentation: String = text[..start.index]
+                .chars()
+                .take_while(|c| c.is_whitespace())
+                .collect();
+
+            insert(editor, &format!("\n{indentation}"));
+        }
+        Edit::Indent(indentation) => {
+            let width = indentation.width.max(1);
+
+            if has_selection(editor) && is_multiline(editor) {
+                let level = if indentation.soft_tabs {
+                    " ".repeat(width)
+                } else {
+                    String::from("\t")
+                };
+
+                for line in selected_lines(editor) {
+                    if !line_text(editor, line).is_empty() {
+                        splice(editor, line, 0..0, &level);
+                    }
+                }
+            } else {
+                let start = selection_start(editor);
+                let text = buffer_from_editor(editor).lines[start.line].text();
+
+                let column =
+                    text[..start.index].chars().fold(0, |column, c| {
+                        if c == '\t' {
+                            (column / width + 1) * width
+                        } else {
+                            column + 1
+                        }
+                    });
+
+                let level = if indentation.soft_tabs {
+                    " ".repeat(width - column % width)
+                } else {
+                    String::from("\t")
+                };
+
+                insert(editor, &level);
+            }
+        }
+        Edit::Unindent(indentation) => {
+            let width = indentation.width.max(1);
+
+            for line in selected_lines(editor) {
+                let text = line_text(editor, line);
+
+                let amount = if text.starts_with('\t') {
+                    1
+                } else {
+                    let spaces = text.chars().take_while(|c| *c == ' ').count();
+
+                    match spaces % width {
+                        0 => spaces.min(width),
+                        rest => rest,
+                    }
+                };
+
+                if amount > 0 {
+                    splice(editor, line, 0..amount, "");
+                }
+            }
+        }
+        Edit::InsertPair(open, close) => {
+            if has_selection(editor) {
+                let caret = Caret::of(editor);
+                let (start, end) =
+                    caret.bounds().unwrap_or((caret.cursor, caret.cursor));
+
+                let _ = editor.insert_at(end, &close.to_string(), None);
+                let _ = editor.insert_at(start, &open.to_string(), None);
+
+                let shift = |cursor: cosmic_text::Cursor| {
+                    if cursor.line == start.line && cursor.index >= start.index
+                    {
+                        cosmic_text::Cursor {
+                            index: cursor.index + open.len_utf8(),
+                            ..cursor
+                        }
+                    } else {
+                        cursor
+                    }
+                };
+
+                Caret {
+                    cursor: shift(caret.cursor),
+                    anchor: caret.anchor.map(shift),
+                }
+                .apply(editor);
+
+                return;
+            }
+
+            let cursor = editor.cursor();
+            let text = line_text(editor, cursor.line);
+
+            let next = text[cursor.index..].chars().next();
+            let previous = text[..cursor.index].chars().next_back();
+
+            if open == close && next == Some(close) {
+                editor.set_cursor(cosmic_text::Cursor {
+                    index: cursor.index + close.len_utf8(),
+                    ..cursor
+                });
+            } else if open == close
+                && previous.is_some_and(char::is_alphanumeric)
+            {
+                editor.action(font_system, cosmic_text::Action::Insert(open));
+            } else {
+                insert(editor, &format!("{open}{close}"));
+
+                let cursor = editor.cursor();
+
+                editor.set_cursor(cosmic_text::Cursor {
+                    index: cursor.index - close.len_utf8(),
+                    ..cursor
+                });
+            }
+        }
+        Edit::InsertClosing(close) => {
+            let cursor = editor.cursor();
+            let text = line_text(editor, cursor.line);
+
+            if !has_selection(editor) && text[cursor.index..].starts_with(close)
+            {
+                editor.set_cursor(cosmic_text::Cursor {
+                    index: cursor.index + close.len_utf8(),
+                    ..cursor
+                });
+            } else {
+                editor.action(font_system, cosmic_text::Action::Insert(close));
+            }
+        }
+        Edit::ToggleComment(prefix) => {
+            let prefix = prefix.trim_end();
+
+            let lines: Vec<_> = selected_lines(editor)
+                .map(|line| (line, line_text(editor, line)))
+                .filter(|(_, text)| !text.trim().is_empty())
+                .collect();
+
+            if prefix.is_empty() || lines.is_empty() {
+                return;
+            }
+
+            let is_commented = lines
+                .iter()
+                .all(|(_, text)| text.trim_start().starts_with(prefix));
+
+            if is_commented {
+                for (line, text) in lines {
+                    let start = text.len() - text.trim_start().len();
+                    let mut end = start + prefix.len();
+
+                    if text[end..].starts_with(' ') {
+                        end += 1;
+                    }
+
+                    splice(editor, line, start..end, "");
+                }
+            } else {
+                // Comments are aligned with the least indented line
+                let column = lines
+                    .iter()
+                    .map(|(_, text)| {
+                        text.chars().take_while(|c| c.is_whitespace()).count()
+                    })
+                    .min()
+                    .unwrap_or_default();
+
+                let comment = format!("{prefix} ");
+
+                for (line, text) in lines {
+                    let index = text
+                        .char_indices()
+                        .nth(column)
+                        .map_or(text.len(), |(index, _)| index);
+
+                    splice(editor, line, index..index, &comment);
+                }
+            }
+        }
+    }
+}
+
+/// Translates a [`Point`] relative to the visible part of a
+/// [`cosmic_text::Editor`] into its horizontally scrolled contents.
+fn scrolled(editor: &cosmic_text::Editor<'static>, position: Point) -> Point {
+    Point::new(
+        position.x + buffer_from_editor(editor).scroll().horizontal,
+        position.y,
+    )
+}
+
+/// Returns true if the [`cosmic_text::Editor`] has some text selected.
+fn has_selection(editor: &cosmic_text::Editor<'static>) -> bool {
+    editor
+        .selection_bounds()
+        .is_some_and(|(start, end)| !is_same_position(start, end))
+}
+
+/// Returns true if the selection of the [`cosmic_text::Editor`] spans
+/// multiple lines.
+fn is_multiline(editor: &cosmic_text::Editor<'static>) -> bool {
+    editor
+        .selection_bounds()
+        .is_some_and(|(start, end)| start.line != end.line)
+}
+
+/// Returns the start of the selection of the [`cosmic_text::Editor`], or
+/// its cursor if nothing is selected.
+fn selection_start(
+    editor: &cosmic_text::Editor<'static>,
+) -> cosmic_text::Cursor {
+    editor
+        .selection_bounds()
+        .map_or(editor.cursor(), |(start, _)| start)
+}
+
+/// Returns the lines spanned by the selection of the [`cosmic_text::Editor`],
+/// or the current line if nothing is selected.
+///
+/// A last line that is selected up to its start is not included.
+fn selected_lines(
+    editor: &cosmic_text::Editor<'static>,
+) -> std::ops::RangeInclusive<usize> {
+    match editor.selection_bounds() {
+        Some((start, end)) if end.line > start.line && end.index == 0 => {
+            start.line..=end.line - 1
+        }
+        Some((start, end)) => start.line..=end.line,
+        None => {
+            let line = editor.cursor().line;
+
+            line..=line
+        }
+    }
+}
+
+fn line_text(editor: &cosmic_text::Editor<'static>, line: usize) -> String {
+    buffer_from_editor(editor)
+        .lines
+        .get(line)
+        .map(|line| line.text().to_owned())
+        .unwrap_or_default()
+}
+
+/// Inserts the given text at the cursor, replacing the selection.
+fn insert(editor: &mut cosmic_text::Editor<'static>, text: &str) {
+    let _ = editor.delete_selection();
+
+    let cursor = insert_at(editor, editor.cursor(), text);
+    editor.set_cursor(cursor);
+}
+
+/// Inserts the given text at the given cursor, returning the cursor at the
+/// end of the inserted text.
+///
+/// `cosmic_text` drops the control character ending the inserted text, like
+/// a tab; so a placeholder is inserted after it and removed afterwards.
+fn insert_at(
+    editor: &mut cosmic_text::Editor<'static>,
+    cursor: cosmic_text::Cursor,
+    text: &str,
+) -> cosmic_text::Cursor {
+    if !text.ends_with(|c: char| c.is_control() && c != '\n') {
+        return editor.insert_at(cursor, text, None);
+    }
+
+    let end = editor.insert_at(cursor, &format!("{text} "), None);
+    let placeholder = cosmic_text::Cursor {
+        index: end.index - 1,
+        ..end
+    };
+
+    editor.delete_range(placeholder, end);
+
+    placeholder
+}
+
+/// Replaces the given byte range of a line with some text, keeping the
+/// cursor and the selection of the [`cosmic_text::Editor`] in place.
+fn splice(
+    editor: &mut cosmic_text::Editor<'static>,
+    line: usize,
+    range: std::ops::Range<usize>,
+    text: &str,
+) {
+    let caret = Caret::of(editor);
+
+    if !range.is_empty() {
+        editor.delete_range(
+            cosmic_text::Cursor::new(line, range.start),
+            cosmic_text::Cursor::new(line, range.end),
+        );
+    }
+
+    if !text.is_empty() {
+        let _ = insert_at(
+            editor,
+            cosmic_text::Cursor::new(line, range.start),
+            text,
+        );
+    }
+
+    let shift = |cursor: cosmic_text::Cursor| {
+        if cursor.line != line {
+            cursor
+        } else if cursor.index >= range.end {
+            cosmic_text::Cursor {
+                index: cursor.index - range.len() + text.len(),
+                ..cursor
+            }
+        } else if cursor.index > range.start {
+            cosmic_text::Cursor {
+                index: range.start + text.len(),
+                ..cursor
+            }
+        } else {
+            cursor
+        }
+    };
+
+    Caret {
+        cursor: shift(caret.cursor),
+        anchor: caret.anchor.map(shift),
+    }
+    .apply(editor);
+}
+
+/// A secondary caret of an [`Editor`].
+#[derive(Debug, Clone, Copy)]
+struct Caret {
+    cursor: cosmic_text::Cursor,
+    anchor: Option<cosmic_text::Cursor>,
+}
+
+impl Caret {
+    /// Returns the current caret of a [`cosmic_text::Editor`].
+    fn of(editor: &cosmic_text::Editor<'static>) -> Self {
+        let cursor = editor.cursor();
+
+        match editor.selection_bounds() {
+            Some((start, end)) if is_same_position(start, end) => Self {
+                cursor,
+                anchor: None,
+            },
+            Some((start, end)) if is_same_position(cursor, start) => Self {
+                cursor: start,
+                anchor: Some(end),
+            },
+            Some((start, end)) => Self {
+                cursor: end,
+                anchor: Some(start),
+            },
+            None => Self {
+                cursor,
+                anchor: None,
+            },
+        }
+    }
+
+    /// Makes the [`Caret`] the current one of a [`cosmic_text::Editor`].
+    fn apply(self, editor: &mut cosmic_text::Editor<'static>) {
+        editor.set_cursor(self.cursor);
+        editor.set_selection(self.anchor.map_or(
+            cosmic_text::Selection::None,
+            cosmic_text::Selection::Normal,
+        ));
+    }
+
+    fn start(&self) -> cosmic_text::Cursor {
+        self.bounds().map_or(self.cursor, |(start, _)| start)
+    }
+
+    fn bounds(&self) -> Option<(cosmic_text::Cursor, cosmic_text::Cursor)> {
+        let anchor = self.anchor?;
+
+        if position(anchor) < position(self.cursor) {
+            Some((anchor, self.cursor))
+        } else {
+            Some((self.cursor, anchor))
+        }
+    }
+}
+
+/// Returns every caret of the [`Editor`], in order.
+fn carets(internal: &Internal) -> Vec<Caret> {
+    let mut carets: Vec<_> = std::iter::once(Caret::of(&internal.editor))
+        .chain(internal.carets.iter().copied())
+        .collect();
+
+    carets.sort_by_key(|caret| position(caret.start()));
+    carets
+}
+
+fn topmost_line(internal: &Internal) -> usize {
+    std::iter::once(Caret::of(&internal.editor))
+        .chain(internal.carets.iter().copied())
+        .map(|caret| caret.start().line)
+        .min()
+        .unwrap_or_default()
+}
+
+/// Runs the given function for every caret of the [`Editor`], along with
+/// its index in order.
+///
+/// Carets are processed from last to first, keeping track of their distance
+/// to the end of the text; which does not change when editing text before
+/// them.
+fn for_each_caret(
+    internal: &mut Internal,
+    mut f: impl FnMut(&mut cosmic_text::Editor<'static>, usize),
+) {
+    if internal.carets.is_empty() {
+        f(&mut internal.editor, 0);
+        return;
+    }
+
+    let current = Caret::of(&internal.editor);
+
+    let mut carets: Vec<_> = std::iter::once((current, true))
+        .chain(internal.carets.drain(..).map(|caret| (caret, false)))
+        .collect();
+
+    carets.sort_by_key(|(caret, _)| position(caret.start()));
+
+    let mut processed = Vec::with_capacity(carets.len());
+
+    for (index, (caret, is_current)) in carets.into_iter().enumerate().rev() {
+        caret.apply(&mut internal.editor);
+        f(&mut internal.editor, index);
+
+        let caret = Caret::of(&internal.editor);
+        let buffer = buffer_from_editor(&internal.editor);
+
+        processed.push((
+            offset_from_end(caret.cursor, buffer),
+            caret.anchor.map(|anchor| offset_from_end(anchor, buffer)),
+            is_current,
+        ));
+    }
+
+    let buffer = buffer_from_editor(&internal.editor);
+
+    let carets: Vec<_> = processed
+        .into_iter()
+        .rev()
+        .map(|(cursor, anchor, is_current)| {
+            (
+                Caret {
+                    cursor: cursor_from_end(cursor, buffer),
+                    anchor: anchor
+                        .map(|anchor| cursor_from_end(anchor, buffer)),
+                },
+                is_current,
+            )
+        })
+        .collect();
+
+    restore_carets(internal, carets);
+}
+
+/// Sets the carets of the [`Editor`], sorted by position and flagging the
+/// current one.
+///
+/// Carets with overlapping selections, or in the same position, are merged.
+fn restore_carets(internal: &mut Internal, mut carets: Vec<(Caret, bool)>) {
+    carets.dedup_by(|(caret, is_current), (previous, is_previous_current)| {
+        let (start, end) =
+            caret.bounds().unwrap_or((caret.cursor, caret.cursor));
+        let (previous_start, previous_end) = previous
+            .bounds()
+            .unwrap_or((previous.cursor, previous.cursor));
+
+        let overlaps = position(start) < position(previous_end)
+            || is_same_position(start, previous_start);
+
+        if overlaps {
+            let end = if position(end) > position(previous_end) {
+                end
+            } else {
+                previous_end
+            };
+
+            let is_backward = previous.anchor.is_some_and(|anchor| {
+                position(anchor) > position(previous.cursor)
+            });
+
+            *previous = if is_same_position(previous_start, end) {
+                Caret {
+                    cursor: end,
+                    anchor: None,
+                }
+            } else if is_backward {
+                Caret {
+                    cursor: previous_start,
+                    anchor: Some(end),
+                }
+            } else {
+                Caret {
+                    cursor: end,
+                    anchor: Some(previous_start),
+                }
+            };
+
+            *is_previous_current |= *is_current;
+        }
+
+        overlaps
+    });
+
+    let current = carets
+        .iter()
+        .position(|(_, is_current)| *is_current)
+        .unwrap_or_default();
+
+    let (caret, _) = carets.remove(current);
+    caret.apply(&mut internal.editor);
+
+    internal.carets = carets.into_iter().map(|(caret, _)| caret).collect();
+}
+
+/// Returns the carets of a rectangular selection of a buffer, one per line.
+fn box_carets(
+    buffer: &cosmic_text::Buffer,
+    start: Point,
+    end: Point,
+) -> Vec<Caret> {
+    let top = start.y.min(end.y);
+    let bottom = start.y.max(end.y);
+
+    let column = |run: &cosmic_text::LayoutRun<'_>, x: f32| {
+        run.glyphs
+            .iter()
+            .find(|glyph| x < glyph.x + glyph.w / 2.0)
+            .map(|glyph| glyph.start)
+            .or_else(|| run.glyphs.last().map(|glyph| glyph.end))
+            .unwrap_or_default()
+    };
+
+    let mut carets: Vec<Caret> = Vec::new();
+
+    for run in buffer.layout_runs() {
+        if run.line_top > bottom || run.line_top + run.line_height <= top {
+            continue;
+        }
+
+        if carets
+            .last()
+            .is_some_and(|caret| caret.cursor.line == run.line_i)
+        {
+            continue;
+        }
+
+        let anchor =
+            cosmic_text::Cursor::new(run.line_i, column(&run, start.x));
+        let cursor = cosmic_text::Cursor::new(run.line_i, column(&run, end.x));
+
+        carets.push(Caret {
+            cursor,
+            anchor: (anchor.index != cursor.index).then_some(anchor),
+        });
+    }
+
+    carets
+}
+
+fn offset_from_end(
+    cursor: cosmic_text::Cursor,
+    buffer: &cosmic_text::Buffer,
+) -> usize {
+    let line = buffer.lines[cursor.line].text();
+
+    let rest: usize = buffer.lines[cursor.line + 1..]
+        .iter()
+        .map(|line| line.text().len() + 1)
+        .sum();
+
+    rest + line.len() - cursor.index.min(line.len())
+}
+
+fn cursor_from_end(
+    offset: usize,
+    buffer: &cosmic_text::Buffer,
+) -> cosmic_text::Cursor {
+    let mut offset = offset;
+
+    for (i, line) in buffer.lines.iter().enumerate().rev() {
+        let text = line.text();
+
+        if offset <= text.len() {
+            let mut index = text.len() - offset;
+
+            while !text.is_char_boundary(index) {
+                index -= 1;
+            }
+
+            return cosmic_text::Cursor::new(i, index);
+        }
+
+        offset -= text.len() + 1;
+    }
+
+    cosmic_text::Cursor::new(0, 0)
+}
+
+fn text_between(
+    buffer: &cosmic_text::Buffer,
+    start: cosmic_text::Cursor,
+    end: cosmic_text::Cursor,
+) -> String {
+    if start.line == end.line {
+        return buffer.lines[start.line].text()[start.index..end.index]
+            .to_owned();
+    }
+
+    let mut text = buffer.lines[start.line].text()[start.index..].to_owned();
+
+    for line in &buffer.lines[start.line + 1..end.line] {
+        text.push('\n');
+        text.push_str(line.text());
+    }
+
+    text.push('\n');
+    text.push_str(&buffer.lines[end.line].text()[..end.index]);
+
+    text
+}
+
+fn position(cursor: cosmic_text::Cursor) -> (usize, usize) {
+    (cursor.line, cursor.index)
+}
+
+fn is_same_position(a: cosmic_text::Cursor, b: cosmic_text::Cursor) -> bool {
+    position(a) == position(b)
+}
+
 fn highlight_line(
     line: &cosmic_text::BufferLine,
     from: usize,
@@ -737,6 +1624,26 @@ fn visual_lines_offset(line: usize, buffer: &cosmic_text::Buffer) -> i32 {
     visual_lines_offset as i32 * if scroll.line < line { 1 } else { -1 }
 }
 
+fn to_cursor(
+    position: Position,
+    buffer: &cosmic_text::Buffer,
+) -> cosmic_text::Cursor {
+    let Some(last_line) = buffer.lines.len().checked_sub(1) else {
+        return cosmic_text::Cursor::new(0, 0);
+    };
+
+    let line = position.line.min(last_line);
+    let text = buffer.lines[line].text();
+
+    let mut index = position.column.min(text.len());
+
+    while !text.is_char_boundary(index) {
+        index -= 1;
+    }
+
+    cosmic_text::Cursor::new(line, index)
+}
+
 fn to_motion(motion: Motion) -> cosmic_text::Motion {
     match motion {
         Motion::Left => cosmic_text::Motion::Left,
@@ -779,3 +1686,185 @@ where
         cosmic_text::BufferRef::Arc(_buffer) => unreachable!(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::text::editor::{Editor as _, Indentation};
+    use crate::core::text::highlighter::PlainText;
+
+    fn editor(text: &str) -> Editor {
+        let mut editor = Editor::with_text(text);
+
+        editor.update(
+            Size::new(1000.0, 1000.0),
+            Font::default(),
+            Pixels(10.0),
+            LineHeight::Absolute(Pixels(10.0)),
+            Wrapping::None,
+            &mut PlainText,
+        );
+
+        editor
+    }
+
+    fn text(editor: &Editor) -> String {
+        (0..editor.line_count())
+            .filter_map(|line| editor.line(line))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn box_selection_keeps_existing_carets() {
+        let mut editor = editor("one\ntwo\nthree\nfour");
+
+        editor.perform(Action::MoveTo(Position::new(3, 2)));
+        editor.perform(Action::AddCursor(Point::new(0.0, 1.0)));
+
+        for end in [Point::new(0.0, 15.0), Point::new(0.0, 25.0)] {
+            editor.perform(Action::SelectBox {
+                start: Point::new(0.0, 1.0),
+                end,
+            });
+        }
+
+        assert_eq!(
+            editor.selection_ranges(),
+            vec![
+                (Position::new(0, 0), Position::new(0, 0)),
+                (Position::new(1, 0), Position::new(1, 0)),
+                (Position::new(2, 0), Position::new(2, 0)),
+                (Position::new(3, 2), Position::new(3, 2)),
+            ]
+        );
+
+        editor.perform(Action::Click(Point::new(0.0, 1.0)));
+
+        assert_eq!(editor.selection_ranges().len(), 1);
+    }
+
+    #[test]
+    fn overlapping_selections_are_merged() {
+        let mut editor = editor("one\ntwo\nthree\nfour");
+
+        editor.perform(Action::SelectBox {
+            start: Point::new(0.0, 1.0),
+            end: Point::new(0.0, 15.0),
+        });
+
+        assert_eq!(editor.selection_ranges().len(), 2);
+
+        editor.perform(Action::Select(Motion::Down));
+        editor.perform(Action::Select(Motion::Down));
+
+        assert_eq!(
+            editor.selection_ranges(),
+            vec![(Position::new(0, 0), Position::new(3, 0))]
+        );
+        assert_eq!(editor.selection().as_deref(), Some("one\ntwo\nthree\n"));
+    }
+
+    #[test]
+    fn indent_and_comment_selected_lines() {
+        let mut editor = editor("fn main() {\nprint();\n\nexit();\n}");
+        let comment = Arc::new(String::from("//"));
+
+        editor.perform(Action::SelectRange {
+            start: Position::new(1, 2),
+            end: Position::new(3, 0),
+        });
+
+        editor.perform(Action::Edit(Edit::Indent(Indentation::spaces(4))));
+        assert_eq!(text(&editor), "fn main() {\n    print();\n\nexit();\n}");
+        assert_eq!(editor.selection().as_deref(), Some("int();\n\n"));
+
+        editor.perform(Action::Edit(Edit::ToggleComment(comment.clone())));
+        assert_eq!(text(&editor), "fn main() {\n    // print();\n\nexit();\n}");
+
+        editor.perform(Action::Edit(Edit::ToggleComment(comment)));
+        editor.perform(Action::Edit(Edit::Unindent(Indentation::spaces(4))));
+        assert_eq!(text(&editor), "fn main() {\nprint();\n\nexit();\n}");
+    }
+
+    #[test]
+    fn indent_with_tabs_by_default() {
+        let mut editor = editor("call");
+
+        editor.perform(Action::Edit(Edit::Indent(Indentation::default())));
+        assert_eq!(text(&editor), "\tcall");
+
+        editor.perform(Action::Edit(Edit::Unindent(Indentation::default())));
+        assert_eq!(text(&editor), "call");
+
+        editor.perform(Action::Move(Motion::End));
+        editor.perform(Action::Edit(Edit::Insert('\t')));
+        editor.perform(Action::Edit(Edit::EnterIndented));
+        assert_eq!(text(&editor), "call\t\n");
+
+        editor.perform(Action::SelectAll);
+        editor.perform(Action::Edit(Edit::Indent(Indentation::default())));
+        assert_eq!(text(&editor), "\tcall\t\n");
+    }
+
+    #[test]
+    fn auto_indent_and_close_pairs() {
+        let mut editor = editor("    call");
+
+        editor.perform(Action::Move(Motion::End));
+
+        for edit in [
+            Edit::InsertPair('(', ')'),
+            Edit::InsertPair('"', '"'),
+            Edit::Insert('a'),
+            Edit::InsertPair('"', '"'),
+            Edit::InsertClosing(')'),
+        ] {
+            editor.perform(Action::Edit(edit));
+        }
+
+        assert_eq!(text(&editor), "    call(\"a\")");
+        assert_eq!(editor.cursor_position(), (0, 13));
+
+        editor.perform(Action::Edit(Edit::EnterIndented));
+        editor.perform(Action::Edit(Edit::Indent(Indentation::spaces(4))));
+        assert_eq!(text(&editor), "    call(\"a\")\n        ");
+
+        editor.perform(Action::SelectRange {
+            start: Position::new(0, 4),
+            end: Position::new(0, 8),
+        });
+
+        editor.perform(Action::Edit(Edit::InsertPair('[', ']')));
+        assert_eq!(text(&editor), "    [call](\"a\")\n        ");
+        assert_eq!(editor.selection().as_deref(), Some("call"));
+    }
+
+    #[test]
+    fn horizontal_scroll_is_clamped_after_resizing() {
+        let mut editor = editor("a rather long line of text");
+
+        let resize = |editor: &mut Editor, width: f32| {
+            editor.update(
+                Size::new(width, 1000.0),
+                Font::default(),
+                Pixels(10.0),
+                LineHeight::Absolute(Pixels(10.0)),
+                Wrapping::None,
+                &mut PlainText,
+            );
+        };
+
+        resize(&mut editor, 20.0);
+        editor.perform(Action::ScrollHorizontally { pixels: 1000.0 });
+
+        let scroll = editor.horizontal_scroll();
+        assert!(scroll > 0.0);
+
+        resize(&mut editor, 40.0);
+        assert_eq!(editor.horizontal_scroll(), scroll - 20.0);
+
+        resize(&mut editor, 1000.0);
+        assert_eq!(editor.horizontal_scroll(), 0.0);
+    }
+}
@@ -1,11 +1,12 @@
 //! Draw paragraphs.
 use crate::core;
 use crate::core::alignment;
-use crate::core::text::{Hit, Shaping, Span, Text, Wrapping};
-use crate::core::{Font, Point, Rectangle, Size};
+use crate::core::text::{Ellipsis, Hit, Shaping, Span, Text, Wrapping};
+use crate::core::{Font, Pixels, Point, Rectangle, Size};
 use crate::text;
 
 use std::fmt;
+use std::ops::Range;
 use std::sync::{self, Arc};
 
 /// A bunch of text.
@@ -18,11 +19,17 @@ struct Internal {
     font: Font,
     shaping: Shaping,
     wrapping: Wrapping,
+    ellipsis: Ellipsis,
+    max_lines: Option<usize>,
+    paragraph_spacing: Pixels,
     horizontal_alignment: alignment::Horizontal,
     vertical_alignment: alignment::Vertical,
     bounds: Size,
     min_bounds: Size,
     version: text::Version,
+    source: Vec<(String, cosmic_text::Attrs<'static>)>,
+    truncation: Option<Truncation>,
+    paragraphs: Vec<(f32, cosmic_text::Buffer)>,
 }
 
 impl Paragraph {
@@ -36,6 +43,23 @@ impl Paragraph {
         &self.internal().buffer
     }
 
+    /// Returns the spacing between the paragraphs of the [`Paragraph`].
+    ///
+    /// Every line of the [`buffer`](Self::buffer) must be shifted down by
+    /// the spacing times its index when drawn.
+    pub fn paragraph_spacing(&self) -> f32 {
+        self.internal().paragraph_spacing.0
+    }
+
+    /// Returns the lines of the [`buffer`](Self::buffer) split into a
+    /// buffer per paragraph, together with the vertical position where each
+    /// one must be drawn.
+    ///
+    /// It is empty if the [`Paragraph`] has no spacing between paragraphs.
+    pub fn paragraphs(&self) -> &[(f32, cosmic_text::Buffer)] {
+        &self.internal().paragraphs
+    }
+
     /// Creates a [`Weak`] reference to the [`Paragraph`].
     ///
     /// This is useful to avoid cloning the [`Paragraph`] when
@@ -80,26 +104,30 @@ impl core::text::Paragraph for Paragraph {
             Some(text.bounds.height),
         );
 
-        buffer.set_text(
+        buffer.set_wrap(font_system.raw(), text::to_wrap(text.wrapping));
+
+        let attributes = text::to_attributes(text.font);
+        let spans = font_system.with_fallbacks([(text.content, attributes)]);
+
+        buffer.set_rich_text(
             font_system.raw(),
-            text.content,
-            text::to_attributes(text.font),
+            spans,
+            attributes,
             text::to_shaping(text.shaping),
         );
 
-        let min_bounds = text::measure(&buffer);
+        let source = if is_clamped(&text) {
+            vec![(text.content.to_owned(), attributes)]
+        } else {
+            Vec::new()
+        };
 
-        Self(Arc::new(Internal {
+        Self(Arc::new(Internal::new(
             buffer,
-            font: text.font,
-            horizontal_alignment: text.horizontal_alignment,
-            vertical_alignment: text.vertical_alignment,
-            shaping: text.shaping,
-            wrapping: text.wrapping,
-            bounds: text.bounds,
-            min_bounds,
-            version: font_system.version(),
-        }))
+            text,
+            source,
+            &mut font_system,
+        )))
     }
 
     fn with_spans<Link>(text: Text<&[Span<'_, Link>]>) -> Self {
@@ -122,9 +150,13 @@ impl core::text::Paragraph for Paragraph {
             Some(text.bounds.height),
         );
 
-        buffer.set_rich_text(
-            font_system.raw(),
-            text.content.iter().enumerate().map(|(i, span)| {
+        buffer.set_wrap(font_system.raw(), text::to_wrap(text.wrapping));
+
+        let spans: Vec<_> = text
+            .content
+            .iter()
+            .enumerate()
+            .map(|(i, span)| {
                 let attrs = text::to_attributes(span.font.unwrap_or(text.font));
 
                 let attrs = match (span.size, span.line_height) {
@@ -149,24 +181,33 @@ impl core::text::Paragraph for Paragraph {
                 };
 
                 (span.text.as_ref(), attrs.metadata(i))
-            }),
+            })
+            .collect();
+
+        let fallbacks = font_system.with_fallbacks(spans.iter().copied());
+
+        buffer.set_rich_text(
+            font_system.raw(),
+            fallbacks,
             text::to_attributes(text.font),
             text::to_shaping(text.shaping),
         );
 
-        let min_bounds = text::measure(&buffer);
+        let source = if is_clamped(&text) {
+            spans
+                .iter()
+                .map(|(text, attrs)| ((*text).to_owned(), *attrs))
+                .collect()
+        } else {
+            Vec::new()
+        };
 
-        Self(Arc::new(Internal {
+        Self(Arc::new(Internal::new(
             buffer,
-            font: text.font,
-            horizontal_alignment: text.horizontal_alignment,
-            vertical_alignment: text.vertical_alignment,
-            shaping: text.shaping,
-            wrapping: text.wrapping,
-            bounds: text.bounds,
-            min_bounds,
-            version: font_system.version(),
-        }))
+            text,
+            source,
+            &mut font_system,
+        )))
     }
 
     fn resize(&mut self, new_bounds: Size) {
@@ -181,8 +222,14 @@ impl core::text::Paragraph for Paragraph {
             Some(new_bounds.height),
         );
 
+        if paragraph.truncation.is_some() {
+            paragraph.set_text(&mut font_system, None);
+        }
+
         paragraph.bounds = new_bounds;
-        paragraph.min_bounds = text::measure(&paragraph.buffer);
+        paragraph.clamp(&mut font_system);
+        paragraph.min_bounds = paragraph.measure();
+        paragraph.split(&mut font_system);
     }
 
     fn compare(&self, text: Text<()>) -> core::text::Difference {
@@ -196,6 +243,9 @@ impl core::text::Paragraph for Paragraph {
             || paragraph.font != text.font
             || paragraph.shaping != text.shaping
             || paragraph.wrapping != text.wrapping
+            || paragraph.ellipsis != text.ellipsis
+            || paragraph.max_lines != text.max_lines
+            || paragraph.paragraph_spacing != text.paragraph_spacing
             || paragraph.horizontal_alignment != text.horizontal_alignment
             || paragraph.vertical_alignment != text.vertical_alignment
         {
@@ -220,15 +270,26 @@ impl core::text::Paragraph for Paragraph {
     }
 
     fn hit_test(&self, point: Point) -> Option<Hit> {
-        let cursor = self.internal().buffer.hit(point.x, point.y)?;
+        let internal = self.internal();
+        let buffer = &internal.buffer;
+        let cursor = internal.hit(point)?;
 
-        Some(Hit::CharOffset(cursor.index))
+        let offset: usize = buffer.lines[..cursor.line]
+            .iter()
+            .map(line_length)
+            .sum::<usize>()
+            + cursor.index;
+
+        Some(Hit::CharOffset(match internal.truncation {
+            Some(truncation) => truncation.to_original(offset),
+            None => offset,
+        }))
     }
 
     fn hit_span(&self, point: Point) -> Option<usize> {
         let internal = self.internal();
 
-        let cursor = internal.buffer.hit(point.x, point.y)?;
+        let cursor = internal.hit(point)?;
         let line = internal.buffer.lines.get(cursor.line)?;
 
         let mut last_glyph = None;
@@ -265,7 +326,7 @@ impl core::text::Paragraph for Paragraph {
             .buffer
             .layout_runs()
             .flat_map(|run| {
-                let line_top = run.line_top;
+                let line_top = run.line_top + internal.line_offset(run.line_i);
                 let line_height = run.line_height;
 
                 run.glyphs
@@ -306,6 +367,55 @@ impl core::text::Paragraph for Paragraph {
         bounds
     }
 
+    fn range_bounds(&self, range: Range<usize>) -> Vec<Rectangle> {
+        let internal = self.internal();
+        let buffer = &internal.buffer;
+
+        let range = match internal.truncation {
+            Some(truncation) => truncation.to_truncated(range),
+            None => range,
+        };
+
+        let offsets: Vec<usize> = buffer
+            .lines
+            .iter()
+            .scan(0, |offset, line| {
+                let start = *offset;
+                *offset += line_length(line);
+
+                Some(start)
+            })
+            .collect();
+
+        buffer
+            .layout_runs()
+            .filter_map(|run| {
+                let offset = offsets[run.line_i];
+                let start = range.start.saturating_sub(offset);
+                let end = range.end.saturating_sub(offset);
+
+                let (left, right) = run
+                    .glyphs
+                    .iter()
+                    .filter(|glyph| glyph.start < end && start < glyph.end)
+                    .fold(None, |bounds, glyph| {
+                        let (left, right) =
+                            bounds.unwrap_or((glyph.x, glyph.x + glyph.w));
+
+                        Some((left.min(glyph.x), right.max(glyph.x + glyph.w)))
+                    })?;
+
+                Some(Rectangle::new(
+                    Point::new(
+                        left,
+                        run.line_top + internal.line_offset(run.line_i),
+                    ),
+                    Size::new(right - left, run.line_height),
+                ))
+            })
+            .collect()
+    }
+
     fn grapheme_position(&self, line: usize, index: usize) -> Option<Point> {
         use unicode_segmentation::UnicodeSegmentation;
 
@@ -349,6 +459,400 @@ impl core::text::Paragraph for Paragraph {
     }
 }
 
+impl Internal {
+    fn new<Content>(
+        buffer: cosmic_text::Buffer,
+        text: Text<Content>,
+        source: Vec<(String, cosmic_text::Attrs<'static>)>,
+        font_system: &mut text::FontSystem,
+    ) -> Self {
+        let mut internal = Self {
+            buffer,
+            font: text.font,
+            horizontal_alignment: text.horizontal_alignment,
+            vertical_alignment: text.vertical_alignment,
+            shaping: text.shaping,
+            wrapping: text.wrapping,
+            ellipsis: text.ellipsis,
+            max_lines: text.max_lines,
+            paragraph_spacing: text.paragraph_spacing,
+            bounds: text.bounds,
+            min_bounds: Size::ZERO,
+            version: font_system.version(),
+            source,
+            truncation: None,
+            paragraphs: Vec::new(),
+        };
+
+        format(
+            &mut internal.buffer,
+            font_system.raw(),
+            internal.horizontal_alignment,
+        );
+
+        internal.clamp(font_system);
+        internal.min_bounds = internal.measure();
+        internal.split(font_system);
+        internal
+    }
+
+    /// Truncates the text of the buffer until it fits in the bounds and the
+    /// maximum amount of lines of the paragraph, replacing the removed text
+    /// with an ellipsis.
+    ///
+    /// The buffer must contain the whole text of the paragraph.
+    fn clamp(&mut self, font_system: &mut text::FontSystem) {
+        use unicode_segmentation::UnicodeSegmentation;
+
+        self.truncation = None;
+
+        if self.source.is_empty() {
+            return;
+        }
+
+        self.buffer
+            .set_size(font_system.raw(), Some(self.bounds.width), None);
+
+        if !self.fits() {
+            let content: String =
+                self.source.iter().map(|(text, _)| text.as_str()).collect();
+
+            let boundaries: Vec<usize> = content
+                .grapheme_indices(true)
+                .map(|(i, _)| i)
+                .chain([content.len()])
+                .collect();
+
+            // The whole text does not fit, so at least one grapheme is removed
+            let mut fitting = 0;
+            let mut overflowing = boundaries.len() - 1;
+
+            while fitting + 1 < overflowing {
+                let kept = (fitting + overflowing) / 2;
+
+                self.set_text(
+                    font_system,
+                    Some(Truncation::new(
+                        self.ellipsis,
+                        &content,
+                        &boundaries,
+                        kept,
+                    )),
+                );
+
+                if self.fits() {
+                    fitting = kept;
+                } else {
+                    overflowing = kept;
+                }
+            }
+
+            let truncation =
+                Truncation::new(self.ellipsis, &content, &boundaries, fitting);
+
+            self.set_text(font_system, Some(truncation));
+            self.truncation = Some(truncation);
+        }
+
+        self.buffer.set_size(
+            font_system.raw(),
+            Some(self.bounds.width),
+            Some(self.bounds.height),
+        );
+    }
+
+    /// Returns true if the lines of the buffer fit in the bounds and the
+    /// maximum amount of lines of the paragraph.
+    ///
+    /// The bounds are only enforced when the paragraph has an [`Ellipsis`],
+    /// since any overflowing text is clipped otherwise. The first line
+    /// always fits vertically.
+    fn fits(&self) -> bool {
+        let max_lines = self.max_lines.unwrap_or(usize::MAX).max(1);
+        let is_ellipsized = self.ellipsis != Ellipsis::None;
+
+        let mut height = 0.0;
+
+        self.buffer.layout_runs().enumerate().all(|(i, run)| {
+            height += run.line_height;
+
+            i < max_lines
+                && !(is_ellipsized
+                    && (run.line_w > self.bounds.width
+                        || i > 0
+                            && height + self.line_offset(run.line_i)
+                                > self.bounds.height))
+        })
+    }
+
+    /// Returns the vertical offset of the given line of the buffer, caused by
+    /// the spacing between paragraphs.
+    fn line_offset(&self, line: usize) -> f32 {
+        line as f32 * self.paragraph_spacing.0
+    }
+
+    /// Splits the lines of the buffer into a buffer per paragraph, so they
+    /// can be drawn apart from each other without being laid out again.
+    fn split(&mut self, font_system: &mut text::FontSystem) {
+        self.paragraphs.clear();
+
+        if self.paragraph_spacing.0 == 0.0 {
+            return;
+        }
+
+        let mut tops = vec![None; self.buffer.lines.len()];
+
+        for run in self.buffer.layout_runs() {
+            let _ = tops[run.line_i].get_or_insert(run.line_top);
+        }
+
+        let (width, _) = self.buffer.size();
+
+        for (i, (line, top)) in self.buffer.lines.iter().zip(tops).enumerate() {
+            let Some(top) = top else {
+                continue;
+            };
+
+            let mut buffer =
+                cosmic_text::Buffer::new_empty(self.buffer.metrics());
+
+            buffer.set_wrap(font_system.raw(), self.buffer.wrap());
+            buffer.set_size(font_system.raw(), width, None);
+
+            // The line keeps its layout, since the width and wrapping match
+            buffer.lines.push(line.clone());
+
+            self.paragraphs.push((top + self.line_offset(i), buffer));
+        }
+    }
+
+    /// Returns the bounds of the text of the buffer, including the spacing
+    /// between paragraphs.
+    fn measure(&self) -> Size {
+        let size = text::measure(&self.buffer);
+
+        Size {
+            height: size.height
+                + self.line_offset(self.buffer.lines.len().saturating_sub(1)),
+            ..size
+        }
+    }
+
+    /// Returns the [`cosmic_text::Cursor`] of the buffer at the given point,
+    /// taking the spacing between paragraphs into account.
+    ///
+    /// Points in the space below a paragraph hit its last line.
+    fn hit(&self, point: Point) -> Option<cosmic_text::Cursor> {
+        if self.paragraph_spacing.0 == 0.0 {
+            return self.buffer.hit(point.x, point.y);
+        }
+
+        let mut y = point.y;
+
+        for run in self.buffer.layout_runs() {
+            let offset = self.line_offset(run.line_i);
+
+            if run.line_top + offset > point.y {
+                break;
+            }
+
+            y = (point.y - offset).min(run.line_top + run.line_height / 2.0);
+        }
+
+        self.buffer.hit(point.x, y)
+    }
+
+    /// Sets the text of the buffer to the source of the paragraph, removing
+    /// the text of the given [`Truncation`], if any.
+    fn set_text(
+        &mut self,
+        font_system: &mut text::FontSystem,
+        truncation: Option<Truncation>,
+    ) {
+        let source = &self.source;
+        let length = source.iter().map(|(text, _)| text.len()).sum();
+
+        let pieces = |range: Range<usize>| {
+            source
+                .iter()
+                .scan(0, |offset, (text, attrs)| {
+                    let start = *offset;
+                    *offset += text.len();
+
+                    Some((start, text, *attrs))
+                })
+                .filter_map(move |(offset, text, attrs)| {
+                    let start = range.start.max(offset);
+                    let end = range.end.min(offset + text.len());
+
+                    (start < end)
+                        .then(|| (&text[start - offset..end - offset], attrs))
+                })
+        };
+
+        let attributes = text::to_attributes(self.font);
+        let shaping = text::to_shaping(self.shaping);
+
+        let Some(truncation) = truncation else {
+            let spans = font_system.with_fallbacks(pieces(0..length));
+
+            self.buffer.set_rich_text(
+                font_system.raw(),
+                spans,
+                attributes,
+                shaping,
+            );
+
+            format(
+                &mut self.buffer,
+                font_system.raw(),
+                self.horizontal_alignment,
+            );
+
+            return;
+        };
+
+        let head: Vec<_> = pieces(0..truncation.start).collect();
+        let tail: Vec<_> = pieces(truncation.end..length).collect();
+
+        // The ellipsis inherits the attributes of the text it replaces
+        let ellipsis = (truncation.ellipsis > 0).then(|| {
+            let attrs = head
+                .last()
+                .or(tail.first())
+                .map_or(attributes, |(_, attrs)| *attrs);
+
+            (ELLIPSIS, attrs)
+        });
+
+        let spans = font_system
+            .with_fallbacks(head.into_iter().chain(ellipsis).chain(tail));
+
+        self.buffer.set_rich_text(
+            font_system.raw(),
+            spans,
+            attributes,
+            shaping,
+        );
+
+        format(
+            &mut self.buffer,
+            font_system.raw(),
+            self.horizontal_alignment,
+        );
+    }
+}
+
+/// The ellipsis that replaces truncated text.
+const ELLIPSIS: &str = "…";
+
+/// The text removed from a [`Paragraph`] to fit its bounds or its maximum
+/// amount of lines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Truncation {
+    /// The start of the removed text, in bytes.
+    start: usize,
+    /// The end of the removed text, in bytes.
+    end: usize,
+    /// The length of the ellipsis that replaces the removed text, in bytes.
+    ellipsis: usize,
+}
+
+impl Truncation {
+    /// Creates the [`Truncation`] of some text that keeps the given amount
+    /// of graphemes, given the byte offsets of its grapheme boundaries.
+    ///
+    /// Any whitespace around the removed text is removed as well.
+    fn new(
+        ellipsis: Ellipsis,
+        content: &str,
+        boundaries: &[usize],
+        kept: usize,
+    ) -> Self {
+        let graphemes = boundaries.len() - 1;
+
+        let (head, tail) = match ellipsis {
+            Ellipsis::None | Ellipsis::End => (kept, 0),
+            Ellipsis::Middle => (kept - kept / 2, kept / 2),
+            Ellipsis::Start => (0, kept),
+        };
+
+        let start = content[..boundaries[head]].trim_end().len();
+        let end = content.len()
+            - content[boundaries[graphemes - tail]..].trim_start().len();
+
+        Self {
+            start,
+            end,
+            ellipsis: if ellipsis == Ellipsis::None {
+                0
+            } else {
+                ELLIPSIS.len()
+            },
+        }
+    }
+
+    /// Converts a byte offset into the truncated text into a byte offset
+    /// into the original text.
+    ///
+    /// Offsets inside the ellipsis are moved to the start of the removed
+    /// text.
+    fn to_original(self, offset: usize) -> usize {
+        if offset <= self.start {
+            offset
+        } else if offset < self.start + self.ellipsis {
+            self.start
+        } else {
+            offset - self.start - self.ellipsis + self.end
+        }
+    }
+
+    /// Converts a byte range of the original text into a byte range of the
+    /// truncated text.
+    ///
+    /// If the range contains any removed text, it contains the ellipsis.
+    fn to_truncated(self, range: Range<usize>) -> Range<usize> {
+        let convert = |offset: usize, ellipsis: usize| {
+            if offset <= self.start {
+                offset
+            } else if offset < self.end {
+                self.start + ellipsis
+            } else {
+                offset - self.end + self.start + self.ellipsis
+            }
+        };
+
+        convert(range.start, 0)..convert(range.end, self.ellipsis)
+    }
+}
+
+/// Justifies the lines of the buffer, if needed.
+fn format(
+    buffer: &mut cosmic_text::Buffer,
+    font_system: &mut cosmic_text::FontSystem,
+    horizontal_alignment: alignment::Horizontal,
+) {
+    if horizontal_alignment != alignment::Horizontal::Justified {
+        return;
+    }
+
+    for line in &mut buffer.lines {
+        let _ = line.set_align(Some(cosmic_text::Align::Justified));
+    }
+
+    buffer.shape_until_scroll(font_system, false);
+}
+
+/// Returns true if the given [`Text`] may need to be truncated.
+fn is_clamped<Content>(text: &Text<Content>) -> bool {
+    text.ellipsis != Ellipsis::None || text.max_lines.is_some()
+}
+
+/// Returns the length in bytes of a line of a buffer, including its ending.
+fn line_length(line: &cosmic_text::BufferLine) -> usize {
+    line.text().len() + line.ending().as_str().len()
+}
+
 impl Default for Paragraph {
     fn default() -> Self {
         Self(Arc::new(Internal::default()))
@@ -392,11 +896,17 @@ impl Default for Internal {
             font: Font::default(),
             shaping: Shaping::default(),
             wrapping: Wrapping::default(),
+            ellipsis: Ellipsis::None,
+            max_lines: None,
+            paragraph_spacing: Pixels::ZERO,
             horizontal_alignment: alignment::Horizontal::Left,
             vertical_alignment: alignment::Vertical::Top,
             bounds: Size::ZERO,
             min_bounds: Size::ZERO,
             version: text::Version::default(),
+            source: Vec::new(),
+            truncation: None,
+            paragraphs: Vec::new(),
         }
     }
 }
@@ -428,3 +938,92 @@ impl PartialEq for Weak {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn truncate_and_map_offsets() {
+        let content = "Hello, wonderful world";
+        let boundaries: Vec<usize> = (0..=content.len()).collect();
+
+        let end = Truncation::new(Ellipsis::End, content, &boundaries, 7);
+        let middle =
+            Truncation::new(Ellipsis::Middle, content, &boundaries, 10);
+        let start = Truncation::new(Ellipsis::Start, content, &boundaries, 6);
+
+        // "Hello,…", "Hello…world", and "…world"
+        assert_eq!((end.start, end.end), (6, 22));
+        assert_eq!((middle.start, middle.end), (5, 17));
+        assert_eq!((start.start, start.end), (0, 17));
+
+        assert_eq!(end.to_original(3), 3);
+        assert_eq!(end.to_original(7), 6);
+        assert_eq!(end.to_original(6 + ELLIPSIS.len()), 22);
+        assert_eq!(middle.to_original(5 + ELLIPSIS.len() + 2), 19);
+
+        assert_eq!(middle.to_truncated(0..2), 0..2);
+        assert_eq!(middle.to_truncated(3..20), 3..5 + ELLIPSIS.len() + 3);
+        assert_eq!(middle.to_truncated(8..12), 5..5 + ELLIPSIS.len());
+
+        let clamp = Truncation::new(Ellipsis::None, content, &boundaries, 17);
+
+        assert_eq!((clamp.start, clamp.end, clamp.ellipsis), (16, 22, 0));
+    }
+
+    #[test]
+    fn space_paragraphs() {
+        use crate::core::text::{LineHeight, Paragraph as _};
+
+        let paragraph = Paragraph::with_text(Text {
+            content: "a\nb",
+            bounds: Size::INFINITY,
+            size: Pixels(10.0),
+            line_height: LineHeight::Absolute(Pixels(10.0)),
+            font: Font::DEFAULT,
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Top,
+            shaping: Shaping::Basic,
+            wrapping: Wrapping::None,
+            ellipsis: Ellipsis::None,
+            max_lines: None,
+            paragraph_spacing: Pixels(5.0),
+        });
+
+        assert_eq!(paragraph.buffer().lines.len(), 2);
+        assert_eq!(paragraph.min_bounds().height, 25.0);
+
+        let hit = |y| match paragraph.hit_test(Point::new(0.0, y)) {
+            Some(Hit::CharOffset(offset)) => offset,
+            None => panic!("no hit at {y}"),
+        };
+
+        assert_eq!(hit(5.0), 0);
+        assert_eq!(hit(12.0), 0);
+        assert_eq!(hit(20.0), 2);
+
+        assert_eq!(
+            paragraph.range_bounds(2..3).first().map(|bounds| bounds.y),
+            Some(15.0)
+        );
+
+        let paragraphs: Vec<_> = paragraph
+            .paragraphs()
+            .iter()
+            .map(|(top, buffer)| {
+                let runs: Vec<_> = buffer
+                    .layout_runs()
+                    .map(|run| (run.text, run.line_top))
+                    .collect();
+
+                (*top, runs)
+            })
+            .collect();
+
+        assert_eq!(
+            paragraphs,
+            vec![(0.0, vec![("a", 0.0)]), (15.0, vec![("b", 0.0)])]
+        );
+    }
+}
@@ -3,26 +3,119 @@ use iced_core as core;
 
 use crate::core::font::{self, Font};
 use crate::core::text::highlighter::{self, Format};
+use crate::core::theme::palette;
 use crate::core::Color;
 
 use once_cell::sync::Lazy;
+use std::io;
 use std::ops::Range;
+use std::path::Path;
+use std::sync::{Arc, RwLock};
 use syntect::highlighting;
 use syntect::parsing;
 
-static SYNTAXES: Lazy<parsing::SyntaxSet> =
-    Lazy::new(parsing::SyntaxSet::load_defaults_nonewlines);
+#[cfg(feature = "tree-sitter")]
+pub mod tree_sitter;
+
+static SYNTAXES: Lazy<RwLock<Arc<parsing::SyntaxSet>>> = Lazy::new(|| {
+    RwLock::new(Arc::new(parsing::SyntaxSet::load_defaults_nonewlines()))
+});
 
 static THEMES: Lazy<highlighting::ThemeSet> =
     Lazy::new(highlighting::ThemeSet::load_defaults);
 
+static EMPTY_THEME: Lazy<highlighting::Theme> =
+    Lazy::new(highlighting::Theme::default);
+
+static CLASSES: Lazy<Vec<(parsing::Scope, Class)>> = Lazy::new(|| {
+    [
+        ("comment", Class::Comment),
+        ("string", Class::String),
+        ("constant", Class::Constant),
+        ("entity.name.function", Class::Function),
+        ("support.function", Class::Function),
+        ("variable.function", Class::Function),
+        ("entity.name.type", Class::Type),
+        ("entity.name.class", Class::Type),
+        ("entity.name.struct", Class::Type),
+        ("entity.name.enum", Class::Type),
+        ("support.type", Class::Type),
+        ("support.class", Class::Type),
+        ("entity.name.tag", Class::Keyword),
+        ("keyword", Class::Keyword),
+        ("storage", Class::Keyword),
+    ]
+    .into_iter()
+    .map(|(scope, class)| {
+        (parsing::Scope::new(scope).expect("Valid scope"), class)
+    })
+    .collect()
+});
+
 const LINES_PER_SNAPSHOT: usize = 50;
 
+/// Registers the grammar of a `.sublime-syntax` file, so it can be
+/// selected with the [`Settings::token`] of a [`Highlighter`].
+///
+/// Highlighters that already exist keep using the grammars that were
+/// available when their [`Settings`] last changed.
+pub fn load_syntax(source: &str) -> Result<(), Error> {
+    add_syntax(source, None)
+}
+
+/// Registers the grammar of the `.sublime-syntax` file at the given path.
+///
+/// The name of the file is used as the name of the grammar if it does not
+/// have any.
+///
+/// See [`load_syntax`] for more details.
+pub fn load_syntax_file(path: impl AsRef<Path>) -> Result<(), Error> {
+    let path = path.as_ref();
+    let source = std::fs::read_to_string(path).map_err(Arc::new)?;
+
+    add_syntax(&source, path.file_stem().and_then(|name| name.to_str()))
+}
+
+fn add_syntax(source: &str, fallback_name: Option<&str>) -> Result<(), Error> {
+    let syntax =
+        parsing::SyntaxDefinition::load_from_str(source, false, fallback_name)
+            .map_err(|error| Error::InvalidSyntax(error.to_string()))?;
+
+    let mut syntaxes = SYNTAXES.write().expect("Write syntaxes");
+
+    let mut builder = parsing::SyntaxSet::clone(&syntaxes).into_builder();
+    builder.add(syntax);
+
+    *syntaxes = Arc::new(builder.build());
+
+    Ok(())
+}
+
+/// An error that occurred while loading a grammar or a [`Theme`].
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum Error {
+    /// The file could not be read.
+    #[error("the file could not be read: {0}")]
+    Io(#[from] Arc<io::Error>),
+    /// The grammar is invalid.
+    #[error("the grammar is invalid: {0}")]
+    InvalidSyntax(String),
+    /// The theme is invalid.
+    #[error("the theme is invalid: {0}")]
+    InvalidTheme(String),
+    /// The highlights query of a tree-sitter grammar is invalid.
+    #[cfg(feature = "tree-sitter")]
+    #[error("the highlights query is invalid: {0}")]
+    InvalidQuery(String),
+}
+
 /// A syntax highlighter.
 #[derive(Debug)]
 pub struct Highlighter {
-    syntax: &'static parsing::SyntaxReference,
-    highlighter: highlighting::Highlighter<'static>,
+    syntaxes: Arc<parsing::SyntaxSet>,
+    syntax: usize,
+    line_comment: Option<String>,
+    theme: Theme,
     caches: Vec<(parsing::ParseState, parsing::ScopeStack)>,
     current_line: usize,
 }
@@ -35,33 +128,27 @@ impl highlighter::Highlighter for Highlighter {
         Box<dyn Iterator<Item = (Range<usize>, Self::Highlight)> + 'a>;
 
     fn new(settings: &Self::Settings) -> Self {
-        let syntax = SYNTAXES
-            .find_syntax_by_token(&settings.token)
-            .unwrap_or_else(|| SYNTAXES.find_syntax_plain_text());
-
-        let highlighter = highlighting::Highlighter::new(
-            &THEMES.themes[settings.theme.key()],
-        );
+        let syntaxes = Arc::clone(&SYNTAXES.read().expect("Read syntaxes"));
+        let syntax = find_syntax(&syntaxes, &settings.token);
 
-        let parser = parsing::ParseState::new(syntax);
+        let parser = parsing::ParseState::new(&syntaxes.syntaxes()[syntax]);
         let stack = parsing::ScopeStack::new();
 
         Highlighter {
+            line_comment: line_comment(&syntaxes, syntax),
+            syntaxes,
             syntax,
-            highlighter,
+            theme: settings.theme.clone(),
             caches: vec![(parser, stack)],
             current_line: 0,
         }
     }
 
     fn update(&mut self, new_settings: &Self::Settings) {
-        self.syntax = SYNTAXES
-            .find_syntax_by_token(&new_settings.token)
-            .unwrap_or_else(|| SYNTAXES.find_syntax_plain_text());
-
-        self.highlighter = highlighting::Highlighter::new(
-            &THEMES.themes[new_settings.theme.key()],
-        );
+        self.syntaxes = Arc::clone(&SYNTAXES.read().expect("Read syntaxes"));
+        self.syntax = find_syntax(&self.syntaxes, &new_settings.token);
+        self.line_comment = line_comment(&self.syntaxes, self.syntax);
+        self.theme = new_settings.theme.clone();
 
         // Restart the highlighter
         self.change_line(0);
@@ -81,7 +168,7 @@ impl highlighter::Highlighter for Highlighter {
         let (parser, stack) =
             self.caches.last().cloned().unwrap_or_else(|| {
                 (
-                    parsing::ParseState::new(self.syntax),
+                    parsing::ParseState::new(self.syntax()),
                     parsing::ScopeStack::new(),
                 )
             });
@@ -102,9 +189,10 @@ impl highlighter::Highlighter for Highlighter {
         let (parser, stack) =
             self.caches.last_mut().expect("Caches must not be empty");
 
-        let ops = parser.parse_line(line, &SYNTAXES).unwrap_or_default();
+        let ops = parser.parse_line(line, &self.syntaxes).unwrap_or_default();
 
-        let highlighter = &self.highlighter;
+        let theme = &self.theme;
+        let highlighter = highlighting::Highlighter::new(theme.to_syntect());
 
         Box::new(
             ScopeRangeIterator {
@@ -121,9 +209,7 @@ impl highlighter::Highlighter for Highlighter {
                 } else {
                     Some((
                         range,
-                        Highlight(
-                            highlighter.style_mod_for_stack(&stack.scopes),
-                        ),
+                        Highlight::new(theme, &highlighter, &stack.scopes),
                     ))
                 }
             }),
@@ -133,6 +219,45 @@ impl highlighter::Highlighter for Highlighter {
     fn current_line(&self) -> usize {
         self.current_line
     }
+
+    fn line_comment(&self) -> Option<&str> {
+        self.line_comment.as_deref()
+    }
+}
+
+impl Highlighter {
+    fn syntax(&self) -> &parsing::SyntaxReference {
+        &self.syntaxes.syntaxes()[self.syntax]
+    }
+}
+
+/// Returns the index of the grammar matching the given token, falling back
+/// to plain text.
+fn find_syntax(syntaxes: &parsing::SyntaxSet, token: &str) -> usize {
+    let syntax = syntaxes
+        .find_syntax_by_token(token)
+        .unwrap_or_else(|| syntaxes.find_syntax_plain_text());
+
+    syntaxes
+        .syntaxes()
+        .iter()
+        .position(|candidate| std::ptr::eq(candidate, syntax))
+        .unwrap_or_default()
+}
+
+/// Returns the line comment prefix of the grammar with the given index, as
+/// defined by the `TM_COMMENT_START` variable of its metadata.
+fn line_comment(
+    syntaxes: &parsing::SyntaxSet,
+    syntax: usize,
+) -> Option<String> {
+    let scope = syntaxes.syntaxes()[syntax].scope;
+
+    syntaxes
+        .metadata()
+        .metadata_for_scope(&[scope])
+        .line_comment()
+        .map(|prefix| prefix.trim_end().to_owned())
 }
 
 /// The settings of a [`Highlighter`].
@@ -150,15 +275,41 @@ pub struct Settings {
 }
 
 /// A highlight produced by a [`Highlighter`].
-#[derive(Debug)]
-pub struct Highlight(highlighting::StyleModifier);
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Highlight(Kind);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Kind {
+    Style(highlighting::StyleModifier),
+    Class(Option<Class>),
+}
 
 impl Highlight {
+    /// Creates the [`Highlight`] of the given stack of scopes with a
+    /// [`Theme`].
+    ///
+    /// With [`Theme::Palette`], only the [`Class`] of the scopes is kept.
+    fn new(
+        theme: &Theme,
+        highlighter: &highlighting::Highlighter<'_>,
+        scopes: &[parsing::Scope],
+    ) -> Self {
+        Self(if *theme == Theme::Palette {
+            Kind::Class(Class::of(scopes))
+        } else {
+            Kind::Style(highlighter.style_mod_for_stack(scopes))
+        })
+    }
+
     /// Returns the color of this [`Highlight`].
     ///
     /// If `None`, the original text color should be unchanged.
     pub fn color(&self) -> Option<Color> {
-        self.0.foreground.map(|color| {
+        let Kind::Style(style) = self.0 else {
+            return None;
+        };
+
+        style.foreground.map(|color| {
             Color::from_rgba8(color.r, color.g, color.b, color.a as f32 / 255.0)
         })
     }
@@ -167,7 +318,11 @@ impl Highlight {
     ///
     /// If `None`, the original font should be unchanged.
     pub fn font(&self) -> Option<Font> {
-        self.0.font_style.and_then(|style| {
+        let Kind::Style(style) = self.0 else {
+            return None;
+        };
+
+        style.font_style.and_then(|style| {
             let bold = style.contains(highlighting::FontStyle::BOLD);
             let italic = style.contains(highlighting::FontStyle::ITALIC);
 
@@ -203,17 +358,90 @@ impl Highlight {
             font: self.font(),
         }
     }
+
+    /// Returns the [`Class`] of the [`Highlight`], if it was produced
+    /// with [`Theme::Palette`].
+    pub fn class(&self) -> Option<Class> {
+        match self.0 {
+            Kind::Style(_) => None,
+            Kind::Class(class) => class,
+        }
+    }
+
+    /// Returns the [`Format`] of the [`Highlight`], coloring its [`Class`]
+    /// with the given [`palette::Extended`].
+    ///
+    /// This is the same as [`to_format`] for any [`Theme`] other than
+    /// [`Theme::Palette`].
+    ///
+    /// [`to_format`]: Self::to_format
+    pub fn to_format_with(&self, palette: &palette::Extended) -> Format<Font> {
+        match self.0 {
+            Kind::Style(_) => self.to_format(),
+            Kind::Class(class) => Format {
+                color: class.map(|class| class.color(palette)),
+                font: None,
+            },
+        }
+    }
+}
+
+/// The syntactic class of some text highlighted with [`Theme::Palette`].
+#[allow(missing_docs)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Class {
+    Comment,
+    Keyword,
+    String,
+    Constant,
+    Function,
+    Type,
+}
+
+impl Class {
+    /// Returns the [`Class`] of the innermost scope that has one.
+    fn of(scopes: &[parsing::Scope]) -> Option<Self> {
+        scopes.iter().rev().find_map(|scope| {
+            CLASSES
+                .iter()
+                .find(|(prefix, _)| prefix.is_prefix_of(*scope))
+                .map(|(_, class)| *class)
+        })
+    }
+
+    /// Returns the [`Color`] of the [`Class`] in the given
+    /// [`palette::Extended`].
+    pub fn color(self, palette: &palette::Extended) -> Color {
+        match self {
+            Self::Comment => Color {
+                a: 0.6,
+                ..palette.background.base.text
+            },
+            Self::Keyword => palette.primary.base.color,
+            Self::String => palette.success.base.color,
+            Self::Constant => palette.danger.base.color,
+            Self::Function => palette.primary.strong.color,
+            Self::Type => palette.success.strong.color,
+        }
+    }
 }
 
 /// A highlighting theme.
 #[allow(missing_docs)]
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Theme {
     SolarizedDark,
     Base16Mocha,
     Base16Ocean,
     Base16Eighties,
     InspiredGitHub,
+    /// A theme that colors the [`Class`] of each [`Highlight`] with the
+    /// palette of the application theme.
+    ///
+    /// See [`Highlight::to_format_with`].
+    Palette,
+    /// A theme loaded with [`Theme::load`] or [`Theme::load_file`].
+    Custom(Custom),
 }
 
 impl Theme {
@@ -224,27 +452,69 @@ impl Theme {
         Self::Base16Ocean,
         Self::Base16Eighties,
         Self::InspiredGitHub,
+        Self::Palette,
     ];
 
+    /// Loads a [`Theme::Custom`] from the contents of a `.tmTheme` file.
+    ///
+    /// Parsing a theme is expensive. Therefore, themes should be loaded once
+    /// and stored instead of being loaded on every `view`. Cloning a loaded
+    /// [`Theme`] is cheap.
+    pub fn load(bytes: &[u8]) -> Result<Self, Error> {
+        let theme = highlighting::ThemeSet::load_from_reader(
+            &mut io::Cursor::new(bytes),
+        )
+        .map_err(|error| Error::InvalidTheme(error.to_string()))?;
+
+        Ok(Self::Custom(Custom {
+            theme: Arc::new(theme),
+        }))
+    }
+
+    /// Loads a [`Theme::Custom`] from the `.tmTheme` file at the given path.
+    ///
+    /// See [`Theme::load`] for more details.
+    pub fn load_file(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let bytes = std::fs::read(path).map_err(Arc::new)?;
+
+        Self::load(&bytes)
+    }
+
     /// Returns `true` if the [`Theme`] is dark, and false otherwise.
-    pub fn is_dark(self) -> bool {
+    ///
+    /// [`Theme::Palette`] is never considered dark, since its colors
+    /// depend on the application theme.
+    pub fn is_dark(&self) -> bool {
         match self {
             Self::SolarizedDark
             | Self::Base16Mocha
             | Self::Base16Ocean
             | Self::Base16Eighties => true,
-            Self::InspiredGitHub => false,
+            Self::InspiredGitHub | Self::Palette => false,
+            Self::Custom(custom) => {
+                custom.theme.settings.background.is_some_and(|color| {
+                    let luminance = 0.299 * f32::from(color.r)
+                        + 0.587 * f32::from(color.g)
+                        + 0.114 * f32::from(color.b);
+
+                    luminance < 128.0
+                })
+            }
         }
     }
 
-    fn key(self) -> &'static str {
-        match self {
+    fn to_syntect(&self) -> &highlighting::Theme {
+        let key = match self {
             Theme::SolarizedDark => "Solarized (dark)",
             Theme::Base16Mocha => "base16-mocha.dark",
             Theme::Base16Ocean => "base16-ocean.dark",
             Theme::Base16Eighties => "base16-eighties.dark",
             Theme::InspiredGitHub => "InspiredGitHub",
-        }
+            Theme::Palette => return &EMPTY_THEME,
+            Theme::Custom(custom) => return &custom.theme,
+        };
+
+        &THEMES.themes[key]
     }
 }
 
@@ -256,10 +526,33 @@ impl std::fmt::Display for Theme {
             Theme::Base16Ocean => write!(f, "Ocean"),
             Theme::Base16Eighties => write!(f, "Eighties"),
             Theme::InspiredGitHub => write!(f, "Inspired GitHub"),
+            Theme::Palette => write!(f, "Palette"),
+            Theme::Custom(custom) => write!(f, "{}", custom.name()),
         }
     }
 }
 
+/// A custom [`Theme`].
+#[derive(Debug, Clone)]
+pub struct Custom {
+    theme: Arc<highlighting::Theme>,
+}
+
+impl Custom {
+    /// Returns the name of the [`Custom`] theme.
+    pub fn name(&self) -> &str {
+        self.theme.name.as_deref().unwrap_or("Custom")
+    }
+}
+
+impl PartialEq for Custom {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.theme, &other.theme)
+    }
+}
+
+impl Eq for Custom {}
+
 struct ScopeRangeIterator {
     ops: Vec<(usize, parsing::ScopeStackOp)>,
     line_length: usize,
@@ -294,3 +587,123 @@ impl Iterator for ScopeRangeIterator {
         Some((range, op))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::text::highlighter::Highlighter as _;
+
+    const SYNTAX: &str = r"%YAML 1.2
+---
+name: Greeting
+file_extensions: [greeting]
+scope: source.greeting
+contexts:
+  main:
+    - match: '\bhello\b'
+      scope: keyword.greeting
+";
+
+    const THEME: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<plist version="1.0">
+<dict>
+    <key>name</key>
+    <string>Company</string>
+    <key>settings</key>
+    <array>
+        <dict>
+            <key>settings</key>
+            <dict>
+                <key>background</key>
+                <string>#101010</string>
+                <key>foreground</key>
+                <string>#F0F0F0</string>
+            </dict>
+        </dict>
+        <dict>
+            <key>scope</key>
+            <string>keyword</string>
+            <key>settings</key>
+            <dict>
+                <key>foreground</key>
+                <string>#FF0000</string>
+            </dict>
+        </dict>
+    </array>
+</dict>
+</plist>"#;
+
+    #[test]
+    fn highlight_with_custom_syntax_and_theme() {
+        load_syntax(SYNTAX).expect("Load syntax");
+        assert!(load_syntax("contexts: [").is_err());
+
+        let theme = Theme::load(THEME.as_bytes()).expect("Load theme");
+
+        assert!(theme.is_dark());
+        assert_eq!(theme.to_string(), "Company");
+
+        let mut highlighter = Highlighter::new(&Settings {
+            theme,
+            token: String::from("greeting"),
+        });
+
+        let colors: Vec<_> = highlighter
+            .highlight_line("hello world")
+            .map(|(range, highlight)| (range, highlight.color()))
+            .collect();
+
+        assert!(colors.contains(&(0..5, Some(Color::from_rgb8(255, 0, 0)))));
+    }
+
+    #[test]
+    fn highlight_classes_with_palette() {
+        let mut highlighter = Highlighter::new(&Settings {
+            theme: Theme::Palette,
+            token: String::from("rs"),
+        });
+
+        let highlights: Vec<_> = highlighter
+            .highlight_line("fn main() {} // Hello")
+            .collect();
+
+        let class_at = |offset: usize| {
+            highlights
+                .iter()
+                .rev()
+                .find(|(range, _)| range.contains(&offset))
+                .and_then(|(_, highlight)| highlight.class())
+        };
+
+        assert_eq!(class_at(0), Some(Class::Keyword));
+        assert_eq!(class_at(3), Some(Class::Function));
+        assert_eq!(class_at(16), Some(Class::Comment));
+
+        let palette = &*core::theme::palette::EXTENDED_LIGHT;
+        let (_, keyword) = &highlights[0];
+
+        assert_eq!(keyword.color(), None);
+        assert_eq!(
+            keyword.to_format_with(palette).color,
+            Some(palette.primary.base.color)
+        );
+    }
+
+    #[test]
+    fn read_line_comment_from_syntax_metadata() {
+        let line_comment = |token: &str| {
+            Highlighter::new(&Settings {
+                theme: Theme::SolarizedDark,
+                token: String::from(token),
+            })
+            .line_comment()
+            .map(str::to_owned)
+        };
+
+        assert_eq!(line_comment("rs").as_deref(), Some("//"));
+        assert_eq!(line_comment("py").as_deref(), Some("#"));
+        assert_eq!(line_comment("lua").as_deref(), Some("--"));
+        assert_eq!(line_comment("txt"), None);
+    }
+}
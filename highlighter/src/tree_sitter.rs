@@ -0,0 +1,408 @@
+//! Highlight syntax with [tree-sitter] queries.
+//!
+//! Unlike the default [`Highlighter`](crate::Highlighter), the tree-sitter
+//! [`Highlighter`] keeps a syntax tree of the lines it has highlighted and
+//! reparses it incrementally when they change. Constructs that span multiple
+//! lines are highlighted accurately, and the tree can be queried for
+//! structural information, like [`Highlighter::matching_bracket`].
+//!
+//! [tree-sitter]: https://tree-sitter.github.io
+use crate::core::text::editor::Position;
+use crate::core::text::highlighter;
+use crate::{Error, Highlight, Theme};
+
+use std::ops::Range;
+use std::sync::Arc;
+use streaming_iterator::StreamingIterator;
+use syntect::highlighting;
+use syntect::parsing::Scope;
+
+/// A tree-sitter grammar along with the query that highlights it.
+#[derive(Debug, Clone)]
+pub struct Language {
+    name: &'static str,
+    grammar: ::tree_sitter::Language,
+    query: Arc<::tree_sitter::Query>,
+    scopes: Arc<[Option<Scope>]>,
+    line_comment: Option<&'static str>,
+}
+
+impl Language {
+    /// Creates a new [`Language`] with the given name, grammar, and source
+    /// of its highlights query.
+    ///
+    /// The names of the captures of the query, like `@keyword` or
+    /// `@function.method`, are matched against the scopes of the [`Theme`].
+    pub fn new(
+        name: &'static str,
+        grammar: impl Into<::tree_sitter::Language>,
+        highlights: &str,
+    ) -> Result<Self, Error> {
+        let grammar = grammar.into();
+
+        ::tree_sitter::Parser::new()
+            .set_language(&grammar)
+            .map_err(|error| Error::InvalidSyntax(error.to_string()))?;
+
+        let query = ::tree_sitter::Query::new(&grammar, highlights)
+            .map_err(|error| Error::InvalidQuery(error.to_string()))?;
+
+        let scopes = query
+            .capture_names()
+            .iter()
+            .map(|capture| scope(capture))
+            .collect();
+
+        Ok(Self {
+            name,
+            grammar,
+            query: Arc::new(query),
+            scopes,
+            line_comment: None,
+        })
+    }
+
+    /// Sets the prefix of a line comment in the [`Language`].
+    pub fn line_comment(mut self, prefix: &'static str) -> Self {
+        self.line_comment = Some(prefix);
+        self
+    }
+
+    /// Returns the name of the [`Language`].
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+impl PartialEq for Language {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && Arc::ptr_eq(&self.query, &other.query)
+    }
+}
+
+/// The settings of a tree-sitter [`Highlighter`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Settings {
+    /// The [`Theme`] of the [`Highlighter`].
+    pub theme: Theme,
+    /// The [`Language`] to highlight.
+    pub language: Language,
+}
+
+/// A syntax highlighter backed by tree-sitter.
+pub struct Highlighter {
+    language: Language,
+    theme: Theme,
+    parser: ::tree_sitter::Parser,
+    cursor: ::tree_sitter::QueryCursor,
+    tree: Option<::tree_sitter::Tree>,
+    source: String,
+    lines: Vec<usize>,
+    current_line: usize,
+}
+
+impl highlighter::Highlighter for Highlighter {
+    type Settings = Settings;
+    type Highlight = Highlight;
+
+    type Iterator<'a> = std::vec::IntoIter<(Range<usize>, Self::Highlight)>;
+
+    fn new(settings: &Self::Settings) -> Self {
+        let mut parser = ::tree_sitter::Parser::new();
+
+        parser
+            .set_language(&settings.language.grammar)
+            .expect("Language grammar must be valid");
+
+        Self {
+            language: settings.language.clone(),
+            theme: settings.theme.clone(),
+            parser,
+            cursor: ::tree_sitter::QueryCursor::new(),
+            tree: None,
+            source: String::new(),
+            lines: Vec::new(),
+            current_line: 0,
+        }
+    }
+
+    fn update(&mut self, new_settings: &Self::Settings) {
+        if self.language != new_settings.language {
+            *self = Self::new(new_settings);
+            return;
+        }
+
+        self.theme = new_settings.theme.clone();
+
+        // Restart the highlighter, keeping the syntax tree
+        self.change_line(0);
+    }
+
+    fn change_line(&mut self, line: usize) {
+        self.current_line = line.min(self.lines.len());
+    }
+
+    fn highlight_line(&mut self, line: &str) -> Self::Iterator<'_> {
+        let index = self.current_line;
+        self.current_line += 1;
+
+        let start = self.lines.get(index).copied();
+
+        let is_unchanged = start.is_some_and(|start| {
+            let end = self
+                .lines
+                .get(index + 1)
+                .copied()
+                .unwrap_or(self.source.len());
+
+            self.source[start..end].strip_suffix('\n') == Some(line)
+        });
+
+        let start = start.unwrap_or(self.source.len());
+
+        if !is_unchanged {
+            self.replace_lines(index, start, line);
+        }
+
+        let Some(tree) = &self.tree else {
+            return Vec::new().into_iter();
+        };
+
+        let end = start + line.len();
+
+        let mut captures = self.cursor.set_byte_range(start..end).captures(
+            &self.language.query,
+            tree.root_node(),
+            self.source.as_bytes(),
+        );
+
+        let highlighter =
+            highlighting::Highlighter::new(self.theme.to_syntect());
+
+        let mut highlights = Vec::new();
+        let mut last_node = None;
+
+        while let Some((match_, index)) = captures.next() {
+            let capture = match_.captures[*index];
+
+            let Some(scope) = self.language.scopes[capture.index as usize]
+            else {
+                continue;
+            };
+
+            // The first pattern that captures a node takes precedence
+            if last_node == Some(capture.node.byte_range()) {
+                continue;
+            }
+
+            let range = capture.node.start_byte().max(start) - start
+                ..capture.node.end_byte().min(end) - start;
+
+            if !range.is_empty() {
+                highlights.push((
+                    range,
+                    Highlight::new(&self.theme, &highlighter, &[scope]),
+                ));
+            }
+
+            last_node = Some(capture.node.byte_range());
+        }
+
+        highlights.into_iter()
+    }
+
+    fn current_line(&self) -> usize {
+        self.current_line
+    }
+
+    fn line_comment(&self) -> Option<&str> {
+        self.language.line_comment
+    }
+}
+
+impl Highlighter {
+    /// Returns the syntax tree of the lines highlighted so far, if any.
+    pub fn tree(&self) -> Option<&::tree_sitter::Tree> {
+        self.tree.as_ref()
+    }
+
+    /// Returns the [`Position`] of the bracket that matches the one at the
+    /// given [`Position`], if any.
+    ///
+    /// Only the lines highlighted so far are taken into account.
+    pub fn matching_bracket(&self, position: Position) -> Option<Position> {
+        let point = ::tree_sitter::Point::new(position.line, position.column);
+
+        let node = self
+            .tree
+            .as_ref()?
+            .root_node()
+            .descendant_for_point_range(point, point)?;
+
+        if node.is_named() {
+            return None;
+        }
+
+        let (pair, is_opening) = match node.kind() {
+            "(" => (")", true),
+            "[" => ("]", true),
+            "{" => ("}", true),
+            ")" => ("(", false),
+            "]" => ("[", false),
+            "}" => ("{", false),
+            _ => return None,
+        };
+
+        let mut sibling = node;
+
+        loop {
+            sibling = if is_opening {
+                sibling.next_sibling()?
+            } else {
+                sibling.prev_sibling()?
+            };
+
+            if !sibling.is_named() && sibling.kind() == pair {
+                let start = sibling.start_position();
+
+                return Some(Position::new(start.row, start.column));
+            }
+        }
+    }
+
+    /// Replaces the lines from the given index onwards with the given line,
+    /// reparsing the syntax tree incrementally.
+    ///
+    /// The lines after a changed one are always fed again, so they can be
+    /// forgotten.
+    fn replace_lines(&mut self, index: usize, start: usize, line: &str) {
+        let old_end_byte = self.source.len();
+        let old_end_row = self.lines.len();
+
+        self.source.truncate(start);
+        self.source.push_str(line);
+        self.source.push('\n');
+
+        self.lines.truncate(index);
+        self.lines.push(start);
+
+        if let Some(tree) = &mut self.tree {
+            tree.edit(&::tree_sitter::InputEdit {
+                start_byte: start,
+                old_end_byte,
+                new_end_byte: self.source.len(),
+                start_position: ::tree_sitter::Point::new(index, 0),
+                old_end_position: ::tree_sitter::Point::new(old_end_row, 0),
+                new_end_position: ::tree_sitter::Point::new(index + 1, 0),
+            });
+        }
+
+        self.tree = self.parser.parse(&self.source, self.tree.as_ref());
+    }
+}
+
+impl std::fmt::Debug for Highlighter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Highlighter")
+            .field("language", &self.language.name)
+            .field("current_line", &self.current_line)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Returns the theme scope of the given capture name.
+///
+/// The most common tree-sitter captures are mapped to their TextMate
+/// counterparts; any other capture is used as a scope directly.
+fn scope(capture: &str) -> Option<Scope> {
+    if capture.starts_with('_') {
+        return None;
+    }
+
+    let scope = match capture {
+        "attribute" => "entity.other.attribute-name",
+        "constant.builtin" => "constant.language",
+        "constructor" | "type" => "entity.name.type",
+        "escape" => "constant.character.escape",
+        "function" | "function.method" => "entity.name.function",
+        "function.builtin" | "function.macro" => "support.function",
+        "label" => "entity.name.label",
+        "number" => "constant.numeric",
+        "operator" => "keyword.operator",
+        "property" => "variable.other.member",
+        "punctuation.bracket" | "punctuation.delimiter" => "punctuation",
+        "tag" => "entity.name.tag",
+        "type.builtin" => "storage.type",
+        "variable.builtin" => "variable.language",
+        _ => capture,
+    };
+
+    Scope::new(scope).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::text::highlighter::Highlighter as _;
+
+    fn rust() -> Language {
+        Language::new(
+            "Rust",
+            tree_sitter_rust::LANGUAGE,
+            tree_sitter_rust::HIGHLIGHTS_QUERY,
+        )
+        .expect("Create Rust language")
+        .line_comment("//")
+    }
+
+    #[test]
+    fn highlight_multiline_comments_incrementally() {
+        let mut highlighter = Highlighter::new(&Settings {
+            theme: Theme::SolarizedDark,
+            language: rust(),
+        });
+
+        let colors = |highlighter: &mut Highlighter, line| {
+            highlighter
+                .highlight_line(line)
+                .map(|(range, highlight)| (range, highlight.color()))
+                .collect::<Vec<_>>()
+        };
+
+        let code = colors(&mut highlighter, "let x = 1;");
+        let _ = colors(&mut highlighter, "/* let x = 1;");
+        let closed = colors(&mut highlighter, "let x = 1; */");
+
+        highlighter.change_line(1);
+
+        let comment = colors(&mut highlighter, "// let x = 1;");
+
+        assert_ne!(code, comment);
+        assert_eq!(closed, comment);
+        assert_eq!(colors(&mut highlighter, "let x = 1;"), code);
+        assert_eq!(highlighter.current_line(), 3);
+        assert_eq!(highlighter.line_comment(), Some("//"));
+    }
+
+    #[test]
+    fn find_matching_brackets() {
+        let mut highlighter = Highlighter::new(&Settings {
+            theme: Theme::SolarizedDark,
+            language: rust(),
+        });
+
+        for line in ["fn main() {", "    let x = [1, (2)];", "}"] {
+            let _ = highlighter.highlight_line(line).count();
+        }
+
+        assert_eq!(
+            highlighter.matching_bracket(Position::new(0, 10)),
+            Some(Position::new(2, 0))
+        );
+        assert_eq!(
+            highlighter.matching_bracket(Position::new(1, 19)),
+            Some(Position::new(1, 12))
+        );
+        assert_eq!(highlighter.matching_bracket(Position::new(1, 4)), None);
+    }
+}
@@ -615,3 +615,34 @@ where
 {
     type Compositor = Compositor<A::Compositor, B::Compositor>;
 }
+
+impl<A, B> renderer::Headless for Renderer<A, B>
+where
+    B: renderer::Headless,
+{
+    /// Creates a new headless [`Renderer`] using the fallback strategy,
+    /// since it is always expected to be available offscreen.
+    fn new(default_font: core::Font, default_text_size: core::Pixels) -> Self {
+        Self::Secondary(B::new(default_font, default_text_size))
+    }
+
+    fn screenshot(
+        &mut self,
+        size: Size<u32>,
+        scale_factor: f32,
+        background_color: Color,
+    ) -> Vec<u8> {
+        match self {
+            Self::Primary(_) => {
+                log::warn!(
+                    "Screenshots are only supported by the fallback renderer"
+                );
+
+                Vec::new()
+            }
+            Self::Secondary(renderer) => {
+                renderer.screenshot(size, scale_factor, background_color)
+            }
+        }
+    }
+}
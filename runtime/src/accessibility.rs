@@ -0,0 +1,861 @@
+//! Expose user interfaces to assistive technologies.
+//!
+//! An accessibility [`Tree`] is built by running the [`build`] operation on
+//! a user interface. The resulting [`Tree`] can then be turned into an
+//! [`accesskit::TreeUpdate`] for a platform adapter, and any
+//! [`accesskit::ActionRequest`] coming back from assistive technologies can
+//! be translated into a list of [`Action`] to be performed on the user
+//! interface.
+pub use accesskit;
+
+use crate::core::keyboard;
+use crate::core::mouse;
+use crate::core::widget::operation::scrollable::AbsoluteOffset;
+use crate::core::widget::operation::{
+    Focusable, Operation, Outcome, Scrollable, TextInput,
+};
+use crate::core::widget::semantics::{self, Semantics};
+use crate::core::widget::Id;
+use crate::core::{Event, Point, Rectangle, Vector};
+
+use accesskit::{NodeBuilder, NodeId, Role};
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+/// The accessibility tree of a user interface.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tree {
+    nodes: Vec<Node>,
+}
+
+/// The [`NodeId`] of the window, which is the root of every [`Tree`].
+const ROOT: NodeId = NodeId(0);
+
+#[derive(Debug, Clone, PartialEq)]
+struct Node {
+    node_id: NodeId,
+    kind: Option<&'static str>,
+    id: Option<Id>,
+    layout: Rectangle,
+    bounds: Rectangle,
+    text: Option<String>,
+    semantics: Option<Semantics>,
+    is_focusable: bool,
+    is_focused: bool,
+    is_text_input: bool,
+    scroll: Option<Vector>,
+    children: Vec<usize>,
+}
+
+impl Node {
+    fn new(
+        node_id: NodeId,
+        id: Option<&Id>,
+        kind: Option<&'static str>,
+        layout: Rectangle,
+        bounds: Rectangle,
+    ) -> Self {
+        Self {
+            node_id,
+            kind,
+            id: id.cloned(),
+            layout,
+            bounds,
+            text: None,
+            semantics: None,
+            is_focusable: false,
+            is_focused: false,
+            is_text_input: false,
+            scroll: None,
+            children: Vec::new(),
+        }
+    }
+
+    fn role(&self) -> Role {
+        if let Some(semantics) = &self.semantics {
+            return match semantics.role {
+                semantics::Role::Button => Role::Button,
+                semantics::Role::CheckBox => Role::CheckBox,
+                semantics::Role::Switch => Role::Switch,
+                semantics::Role::RadioButton => Role::RadioButton,
+                semantics::Role::Slider => Role::Slider,
+                semantics::Role::TextInput => Role::TextInput,
+                semantics::Role::MultilineTextInput => Role::MultilineTextInput,
+                semantics::Role::ComboBox => Role::ComboBox,
+                semantics::Role::Image => Role::Image,
+                semantics::Role::Label => Role::Label,
+                semantics::Role::ProgressIndicator => Role::ProgressIndicator,
+                semantics::Role::Group => Role::Group,
+            };
+        }
+
+        match self.kind {
+            Some("text" | "rich_text") => Role::Label,
+            Some("scrollable") => Role::ScrollView,
+            Some("container" | "column" | "row" | "keyed_column" | "stack") => {
+                Role::GenericContainer
+            }
+            Some(_) => Role::Unknown,
+            None if self.text.is_some() => Role::Label,
+            None if self.is_text_input => Role::TextInput,
+            None if self.scroll.is_some() => Role::ScrollView,
+            None => Role::Unknown,
+        }
+    }
+
+    fn is_transparent(&self) -> bool {
+        self.role() == Role::Unknown && !self.is_focusable
+    }
+}
+
+impl Tree {
+    /// Returns the [`accesskit::TreeUpdate`] that fully describes the
+    /// [`Tree`], using the given scale factor to convert its logical
+    /// coordinates into physical pixels.
+    pub fn update(&self, scale_factor: f64) -> accesskit::TreeUpdate {
+        let mut tree = accesskit::Tree::new(ROOT);
+        tree.toolkit_name = Some(String::from("iced"));
+        tree.toolkit_version = Some(String::from(env!("CARGO_PKG_VERSION")));
+
+        accesskit::TreeUpdate {
+            nodes: self.nodes(scale_factor),
+            tree: Some(tree),
+            focus: self.focus(),
+        }
+    }
+
+    /// Returns the [`accesskit::TreeUpdate`] that turns the `previous`
+    /// [`Tree`] into this one.
+    ///
+    /// Only the nodes that changed are included in the update.
+    pub fn diff(
+        &self,
+        previous: &Tree,
+        scale_factor: f64,
+    ) -> accesskit::TreeUpdate {
+        let previous: HashMap<_, _> =
+            previous.nodes(scale_factor).into_iter().collect();
+
+        let nodes = self
+            .nodes(scale_factor)
+            .into_iter()
+            .filter(|(id, node)| previous.get(id) != Some(node))
+            .collect();
+
+        accesskit::TreeUpdate {
+            nodes,
+            tree: None,
+            focus: self.focus(),
+        }
+    }
+
+    fn nodes(&self, scale_factor: f64) -> Vec<(NodeId, accesskit::Node)> {
+        let mut nodes = Vec::with_capacity(self.nodes.len());
+
+        let mut root = NodeBuilder::new(Role::Window);
+        root.set_transform(accesskit::Affine::scale(scale_factor));
+        root.set_children(self.children(0));
+
+        nodes.push((ROOT, root.build()));
+
+        for (i, node) in self.nodes.iter().enumerate().skip(1) {
+            if node.is_transparent() {
+                continue;
+            }
+
+            let role = node.role();
+            let mut builder = NodeBuilder::new(role);
+
+            builder.set_bounds(accesskit::Rect {
+                x0: f64::from(node.bounds.x),
+                y0: f64::from(node.bounds.y),
+                x1: f64::from(node.bounds.x + node.bounds.width),
+                y1: f64::from(node.bounds.y + node.bounds.height),
+            });
+
+            builder.set_children(self.children(i));
+
+            let label = node
+                .semantics
+                .as_ref()
+                .and_then(|semantics| semantics.label.clone());
+
+            match role {
+                Role::TextInput | Role::MultilineTextInput | Role::ComboBox => {
+                    if let Some(text) = &node.text {
+                        builder.set_value(text.as_str());
+                    }
+
+                    if let Some(label) = label {
+                        builder.set_name(label);
+                    }
+                }
+                Role::Button => {
+                    if let Some(name) = label.or_else(|| self.name(i)) {
+                        builder.set_name(name);
+                    }
+                }
+                _ => {
+                    if let Some(name) = label.or_else(|| node.text.clone()) {
+                        builder.set_name(name);
+                    }
+                }
+            }
+
+            if let Some(description) = node
+                .semantics
+                .as_ref()
+                .and_then(|semantics| semantics.description.as_deref())
+            {
+                builder.set_description(description);
+            }
+
+            if let Some(semantics) = &node.semantics {
+                if let Some(toggled) = semantics.toggled {
+                    builder.set_toggled(if toggled {
+                        accesskit::Toggled::True
+                    } else {
+                        accesskit::Toggled::False
+                    });
+                }
+
+                if let Some(value) = semantics.value {
+                    builder.set_numeric_value(value.current);
+                    builder.set_min_numeric_value(value.min);
+                    builder.set_max_numeric_value(value.max);
+
+                    if let Some(step) = value.step {
+                        builder.set_numeric_value_step(step);
+                    }
+                }
+
+                if semantics.is_disabled {
+                    builder.set_disabled();
+                }
+            }
+
+            if node.is_focusable {
+                builder.add_action(accesskit::Action::Focus);
+                builder.add_action(accesskit::Action::Blur);
+            }
+
+            if matches!(
+                role,
+                Role::Button
+                    | Role::CheckBox
+                    | Role::Switch
+                    | Role::RadioButton
+                    | Role::ComboBox
+            ) {
+                builder.add_action(accesskit::Action::Default);
+            }
+
+            if node.is_text_input && role == Role::TextInput {
+                builder.add_action(accesskit::Action::SetValue);
+            }
+
+            if let Some(scroll) = node.scroll {
+                builder.set_scroll_x(f64::from(scroll.x));
+                builder.set_scroll_y(f64::from(scroll.y));
+
+                builder.add_action(accesskit::Action::ScrollUp);
+                builder.add_action(accesskit::Action::ScrollDown);
+                builder.add_action(accesskit::Action::ScrollLeft);
+                builder.add_action(accesskit::Action::ScrollRight);
+                builder.add_action(accesskit::Action::SetScrollOffset);
+            }
+
+            nodes.push((node.node_id, builder.build()));
+        }
+
+        nodes
+    }
+
+    fn focus(&self) -> NodeId {
+        self.nodes
+            .iter()
+            .find(|node| node.is_focused)
+            .map_or(ROOT, |node| node.node_id)
+    }
+
+    /// Translates the given [`accesskit::ActionRequest`] into the list of
+    /// [`Action`] that must be performed on the user interface to satisfy it.
+    ///
+    /// Unsupported requests produce no actions.
+    pub fn perform(&self, request: &accesskit::ActionRequest) -> Vec<Action> {
+        let Some(node) = self
+            .nodes
+            .iter()
+            .skip(1)
+            .find(|node| node.node_id == request.target)
+        else {
+            return Vec::new();
+        };
+
+        let target = node.node_id;
+        let viewport = node.layout;
+
+        match request.action {
+            accesskit::Action::Focus if node.is_focusable => {
+                vec![Action::operate(Target::focus(target))]
+            }
+            accesskit::Action::Blur if node.is_focusable => {
+                vec![Action::operate(Target::blur(target))]
+            }
+            accesskit::Action::Default => {
+                let position = node.bounds.center();
+
+                vec![Action::Simulate {
+                    cursor: Some(position),
+                    events: vec![
+                        Event::Mouse(mouse::Event::CursorMoved { position }),
+                        Event::Mouse(mouse::Event::ButtonPressed(
+                            mouse::Button::Left,
+                        )),
+                        Event::Mouse(mouse::Event::ButtonReleased(
+                            mouse::Button::Left,
+                        )),
+                    ],
+                }]
+            }
+            accesskit::Action::SetValue if node.is_text_input => {
+                let Some(accesskit::ActionData::Value(value)) = &request.data
+                else {
+                    return Vec::new();
+                };
+
+                let keys = if value.is_empty() {
+                    vec![(
+                        keyboard::Key::Named(keyboard::key::Named::Backspace),
+                        None,
+                    )]
+                } else {
+                    value
+                        .chars()
+                        .map(|c| {
+                            let text = crate::core::SmolStr::new(
+                                c.encode_utf8(&mut [0; 4]),
+                            );
+
+                            (keyboard::Key::Character(text.clone()), Some(text))
+                        })
+                        .collect()
+                };
+
+                let events = keys
+                    .into_iter()
+                    .flat_map(|(key, text)| {
+                        [
+                            Event::Keyboard(keyboard::Event::KeyPressed {
+                                key: key.clone(),
+                                location: keyboard::Location::Standard,
+                                modifiers: keyboard::Modifiers::default(),
+                                text,
+                            }),
+                            Event::Keyboard(keyboard::Event::KeyReleased {
+                                key,
+                                location: keyboard::Location::Standard,
+                                modifiers: keyboard::Modifiers::default(),
+                            }),
+                        ]
+                    })
+                    .collect();
+
+                vec![
+                    Action::operate(Target::select_all(target)),
+                    Action::Simulate {
+                        cursor: None,
+                        events,
+                    },
+                ]
+            }
+            accesskit::Action::ScrollUp
+            | accesskit::Action::ScrollDown
+            | accesskit::Action::ScrollLeft
+            | accesskit::Action::ScrollRight
+            | accesskit::Action::SetScrollOffset => {
+                let Some(scroll) = node.scroll else {
+                    return Vec::new();
+                };
+
+                let offset = match (request.action, &request.data) {
+                    (accesskit::Action::ScrollUp, _) => {
+                        Vector::new(scroll.x, scroll.y - viewport.height)
+                    }
+                    (accesskit::Action::ScrollDown, _) => {
+                        Vector::new(scroll.x, scroll.y + viewport.height)
+                    }
+                    (accesskit::Action::ScrollLeft, _) => {
+                        Vector::new(scroll.x - viewport.width, scroll.y)
+                    }
+                    (accesskit::Action::ScrollRight, _) => {
+                        Vector::new(scroll.x + viewport.width, scroll.y)
+                    }
+                    (
+                        _,
+                        Some(accesskit::ActionData::SetScrollOffset(point)),
+                    ) => Vector::new(point.x as f32, point.y as f32),
+                    _ => return Vec::new(),
+                };
+
+                vec![Action::operate(Target::scroll_to(
+                    target,
+                    AbsoluteOffset {
+                        x: offset.x.max(0.0),
+                        y: offset.y.max(0.0),
+                    },
+                ))]
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    fn children(&self, i: usize) -> Vec<NodeId> {
+        let mut children = Vec::new();
+
+        for &child in &self.nodes[i].children {
+            if self.nodes[child].is_transparent() {
+                children.extend(self.children(child));
+            } else {
+                children.push(self.nodes[child].node_id);
+            }
+        }
+
+        children
+    }
+
+    fn name(&self, i: usize) -> Option<String> {
+        let node = &self.nodes[i];
+
+        if let Some(label) = node
+            .semantics
+            .as_ref()
+            .and_then(|semantics| semantics.label.clone())
+        {
+            return Some(label);
+        }
+
+        if node.role() == Role::Label {
+            return node.text.clone();
+        }
+
+        let names: Vec<String> = node
+            .children
+            .iter()
+            .filter_map(|&child| self.name(child))
+            .collect();
+
+        (!names.is_empty()).then(|| names.join(" "))
+    }
+}
+
+/// An action that must be performed on a user interface to satisfy an
+/// [`accesskit::ActionRequest`].
+pub enum Action {
+    /// Run a widget operation.
+    Operate(Box<dyn Operation>),
+
+    /// Process the given events, with the mouse cursor placed at the given
+    /// position, if any.
+    Simulate {
+        /// The position of the mouse cursor.
+        cursor: Option<Point>,
+
+        /// The events to process.
+        events: Vec<Event>,
+    },
+}
+
+impl Action {
+    fn operate(operation: impl Operation + 'static) -> Self {
+        Self::Operate(Box::new(operation))
+    }
+}
+
+impl fmt::Debug for Action {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Operate(_) => write!(f, "Action::Operate"),
+            Self::Simulate { cursor, events } => f
+                .debug_struct("Action::Simulate")
+                .field("cursor", cursor)
+                .field("events", events)
+                .finish(),
+        }
+    }
+}
+
+/// Produces an [`Operation`] that builds the accessibility [`Tree`] of a
+/// widget tree.
+pub fn build() -> impl Operation<Tree> {
+    Builder::new()
+}
+
+#[derive(Debug)]
+struct Builder {
+    nodes: Vec<Node>,
+    node_ids: HashSet<NodeId>,
+    parents: Vec<usize>,
+    translation: Vector,
+    scrollable: Option<(Rectangle, Vector)>,
+    last: usize,
+}
+
+impl Builder {
+    fn new() -> Self {
+        Self {
+            nodes: vec![Node::new(
+                ROOT,
+                None,
+                None,
+                Rectangle::default(),
+                Rectangle::default(),
+            )],
+            node_ids: HashSet::from([ROOT]),
+            parents: vec![0],
+            translation: Vector::ZERO,
+            scrollable: None,
+            last: 0,
+        }
+    }
+
+    fn visit(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        kind: Option<&'static str>,
+        has_text: bool,
+    ) -> usize {
+        let parent = self.parents.last().copied().unwrap_or(0);
+
+        // Widgets may report themselves through multiple hooks, so we merge
+        // consecutive reports of the same widget into a single node.
+        if let Some(&last) = self.nodes[parent].children.last() {
+            let node = &self.nodes[last];
+
+            let is_same_widget = node.layout == bounds
+                && (id.is_none()
+                    || node.id.is_none()
+                    || node.id.as_ref() == id)
+                && (kind.is_none() || node.kind.is_none())
+                && (!has_text || node.text.is_none());
+
+            if is_same_widget {
+                if node.id.is_none() && id.is_some() {
+                    let _ = self.node_ids.remove(&node.node_id);
+
+                    let node_id = self.node_id(id, parent);
+                    let node = &mut self.nodes[last];
+
+                    node.node_id = node_id;
+                    node.id = id.cloned();
+                }
+
+                let node = &mut self.nodes[last];
+
+                if node.kind.is_none() {
+                    node.kind = kind;
+                }
+
+                self.last = last;
+
+                return last;
+            }
+        }
+
+        let index = self.nodes.len();
+        let node_id = self.node_id(id, parent);
+
+        self.nodes.push(Node::new(
+            node_id,
+            id,
+            kind,
+            bounds,
+            bounds - self.translation,
+        ));
+        self.nodes[parent].children.push(index);
+        self.last = index;
+
+        index
+    }
+
+    /// Derives a [`NodeId`] that stays the same across rebuilds of the
+    /// widget tree.
+    ///
+    /// Widgets with an [`Id`] are identified by it. Otherwise, the path to
+    /// the widget from its closest parent is used.
+    fn node_id(&mut self, id: Option<&Id>, parent: usize) -> NodeId {
+        let mut hasher = DefaultHasher::new();
+
+        match id {
+            Some(id) => id.hash(&mut hasher),
+            None => {
+                self.nodes[parent].node_id.hash(&mut hasher);
+                self.nodes[parent].children.len().hash(&mut hasher);
+            }
+        }
+
+        loop {
+            let node_id = NodeId(hasher.finish());
+
+            if self.node_ids.insert(node_id) {
+                return node_id;
+            }
+
+            // Ids may be reused by multiple widgets
+            hasher.write_u8(0);
+        }
+    }
+
+    fn enter(&mut self, id: Option<&Id>, bounds: Rectangle) -> Vector {
+        let index = self.visit(id, bounds, None, false);
+
+        let translation = match self.scrollable.take() {
+            Some((scrollable, translation)) if scrollable == bounds => {
+                translation
+            }
+            _ => Vector::ZERO,
+        };
+
+        self.parents.push(index);
+        self.translation = self.translation + translation;
+
+        translation
+    }
+
+    fn leave(&mut self, translation: Vector) {
+        self.translation = self.translation - translation;
+        let _ = self.parents.pop();
+    }
+}
+
+impl Operation<Tree> for Builder {
+    fn container(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<Tree>),
+    ) {
+        let translation = self.enter(id, bounds);
+
+        operate_on_children(self);
+
+        self.leave(translation);
+    }
+
+    fn focusable(
+        &mut self,
+        state: &mut dyn Focusable,
+        id: Option<&Id>,
+        bounds: Rectangle,
+    ) {
+        let index = self.visit(id, bounds, None, false);
+        let node = &mut self.nodes[index];
+
+        node.is_focusable = true;
+        node.is_focused = state.is_focused();
+    }
+
+    fn scrollable(
+        &mut self,
+        _state: &mut dyn Scrollable,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        translation: Vector,
+    ) {
+        let index = self.visit(id, bounds, None, false);
+
+        self.nodes[index].scroll = Some(translation);
+        self.scrollable = Some((bounds, translation));
+    }
+
+    fn text_input(
+        &mut self,
+        _state: &mut dyn TextInput,
+        id: Option<&Id>,
+        bounds: Rectangle,
+    ) {
+        let index = self.visit(id, bounds, None, false);
+
+        self.nodes[index].is_text_input = true;
+    }
+
+    fn kind(&mut self, id: Option<&Id>, bounds: Rectangle, kind: &'static str) {
+        let _ = self.visit(id, bounds, Some(kind), false);
+    }
+
+    fn semantics(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        semantics: &Semantics,
+    ) {
+        let index = self.visit(id, bounds, None, false);
+
+        self.nodes[index].semantics = Some(semantics.clone());
+    }
+
+    fn text(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        text: &dyn fmt::Display,
+    ) {
+        let index = self.visit(id, bounds, None, true);
+
+        self.nodes[index].text = Some(text.to_string());
+    }
+
+    fn finish(&self) -> Outcome<Tree> {
+        Outcome::Some(Tree {
+            nodes: self.nodes.clone(),
+        })
+    }
+}
+
+/// An [`Operation`] targeting the widget with the given [`NodeId`].
+///
+/// The [`Tree`] is rebuilt alongside to find the [`NodeId`] of every widget.
+#[derive(Debug)]
+struct Target {
+    node: NodeId,
+    request: Request,
+    builder: Builder,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Request {
+    Focus,
+    Blur,
+    SelectAll,
+    ScrollTo(AbsoluteOffset),
+}
+
+impl Target {
+    fn new(node: NodeId, request: Request) -> Self {
+        Self {
+            node,
+            request,
+            builder: Builder::new(),
+        }
+    }
+
+    fn focus(node: NodeId) -> Self {
+        Self::new(node, Request::Focus)
+    }
+
+    fn blur(node: NodeId) -> Self {
+        Self::new(node, Request::Blur)
+    }
+
+    fn select_all(node: NodeId) -> Self {
+        Self::new(node, Request::SelectAll)
+    }
+
+    fn scroll_to(node: NodeId, offset: AbsoluteOffset) -> Self {
+        Self::new(node, Request::ScrollTo(offset))
+    }
+
+    fn is_target(&self) -> bool {
+        self.builder.nodes[self.builder.last].node_id == self.node
+    }
+}
+
+impl Operation for Target {
+    fn container(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation),
+    ) {
+        let translation = self.builder.enter(id, bounds);
+
+        operate_on_children(self);
+
+        self.builder.leave(translation);
+    }
+
+    fn focusable(
+        &mut self,
+        state: &mut dyn Focusable,
+        id: Option<&Id>,
+        bounds: Rectangle,
+    ) {
+        self.builder.focusable(state, id, bounds);
+
+        match self.request {
+            Request::Focus | Request::SelectAll => {
+                if self.is_target() {
+                    state.focus();
+                } else {
+                    state.unfocus();
+                }
+            }
+            Request::Blur => {
+                if self.is_target() {
+                    state.unfocus();
+                }
+            }
+            Request::ScrollTo(_) => {}
+        }
+    }
+
+    fn scrollable(
+        &mut self,
+        state: &mut dyn Scrollable,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        translation: Vector,
+    ) {
+        self.builder.scrollable(state, id, bounds, translation);
+
+        if let Request::ScrollTo(offset) = self.request {
+            if self.is_target() {
+                state.scroll_to(offset);
+            }
+        }
+    }
+
+    fn text_input(
+        &mut self,
+        state: &mut dyn TextInput,
+        id: Option<&Id>,
+        bounds: Rectangle,
+    ) {
+        self.builder.text_input(state, id, bounds);
+
+        if let Request::SelectAll = self.request {
+            if self.is_target() {
+                state.select_all();
+            }
+        }
+    }
+
+    fn kind(&mut self, id: Option<&Id>, bounds: Rectangle, kind: &'static str) {
+        self.builder.kind(id, bounds, kind);
+    }
+
+    fn semantics(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        semantics: &Semantics,
+    ) {
+        self.builder.semantics(id, bounds, semantics);
+    }
+
+    fn text(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        _text: &dyn fmt::Display,
+    ) {
+        let index = self.builder.visit(id, bounds, None, true);
+
+        // The text itself is irrelevant to find the target
+        self.builder.nodes[index].text = Some(String::new());
+    }
+}
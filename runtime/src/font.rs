@@ -1,7 +1,53 @@
 //! Load and use fonts.
+use crate::core::font::{Face, Family, Font};
+use crate::futures::futures::channel::oneshot;
 use crate::task::{self, Task};
-use crate::Action;
+
 use std::borrow::Cow;
+use std::fmt;
+
+/// A font action to be performed by some [`Task`].
+pub enum Action {
+    /// Load a font from its bytes.
+    Load {
+        /// The bytes of the font to load.
+        bytes: Cow<'static, [u8]>,
+        /// The channel to send back the load result.
+        channel: oneshot::Sender<Result<(), Error>>,
+    },
+
+    /// List the [`Face`] of every available font.
+    List {
+        /// The channel to send back the faces.
+        channel: oneshot::Sender<Vec<Face>>,
+    },
+
+    /// Check whether the [`Family`] of a [`Font`] is available.
+    IsAvailable {
+        /// The font to look for.
+        font: Font,
+        /// The channel to send back whether the font is available.
+        channel: oneshot::Sender<bool>,
+    },
+
+    /// Replace the fallback chain of font families.
+    SetFallbacks(Vec<Family>),
+}
+
+impl fmt::Debug for Action {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Action::Load { .. } => write!(f, "Load"),
+            Action::List { .. } => write!(f, "List"),
+            Action::IsAvailable { font, .. } => {
+                f.debug_tuple("IsAvailable").field(font).finish()
+            }
+            Action::SetFallbacks(families) => {
+                f.debug_tuple("SetFallbacks").field(families).finish()
+            }
+        }
+    }
+}
 
 /// An error while loading a font.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -9,8 +55,41 @@ pub enum Error {}
 
 /// Load a font from its bytes.
 pub fn load(bytes: impl Into<Cow<'static, [u8]>>) -> Task<Result<(), Error>> {
-    task::oneshot(|channel| Action::LoadFont {
-        bytes: bytes.into(),
-        channel,
+    task::oneshot(|channel| {
+        crate::Action::Font(Action::Load {
+            bytes: bytes.into(),
+            channel,
+        })
     })
 }
+
+/// Lists the [`Face`] of every available font, including both the fonts
+/// installed in the system and the ones loaded with [`load`].
+pub fn list() -> Task<Vec<Face>> {
+    task::oneshot(|channel| crate::Action::Font(Action::List { channel }))
+}
+
+/// Returns whether the [`Family`] of the given [`Font`] is available.
+///
+/// Generic families, like [`Family::SansSerif`], are always available.
+pub fn is_available(font: Font) -> Task<bool> {
+    task::oneshot(move |channel| {
+        crate::Action::Font(Action::IsAvailable { font, channel })
+    })
+}
+
+/// Sets the fallback chain of font families.
+///
+/// Every glyph missing in the [`Family`] of a [`Font`] is looked up in the
+/// families of the chain, in order; e.g. `Inter`, then `Noto Sans CJK`, and
+/// finally `Noto Color Emoji`. When the [`Family`] of a [`Font`] is not
+/// available at all, the first available family of the chain is used
+/// instead.
+///
+/// Glyphs missing in every family of the chain are still looked up in the
+/// rest of the fonts of the system.
+pub fn set_fallbacks<T>(families: impl IntoIterator<Item = Family>) -> Task<T> {
+    task::effect(crate::Action::Font(Action::SetFallbacks(
+        families.into_iter().collect(),
+    )))
+}
@@ -9,6 +9,8 @@
     html_logo_url = "https://raw.githubusercontent.com/iced-rs/iced/9ab6923e943f784985e9ef9ca28b10278297225d/docs/logo.svg"
 )]
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
+#[cfg(feature = "accessibility")]
+pub mod accessibility;
 pub mod clipboard;
 pub mod font;
 pub mod keyboard;
@@ -22,6 +24,9 @@ pub mod window;
 #[cfg(feature = "multi-window")]
 pub mod multi_window;
 
+#[cfg(feature = "recording")]
+pub mod recording;
+
 // We disable debug capabilities on release builds unless the `debug` feature
 // is explicitly enabled.
 #[cfg(feature = "debug")]
@@ -40,9 +45,7 @@ pub use task::Task;
 pub use user_interface::UserInterface;
 
 use crate::core::widget;
-use crate::futures::futures::channel::oneshot;
 
-use std::borrow::Cow;
 use std::fmt;
 
 /// An action that the iced runtime can perform.
@@ -50,13 +53,8 @@ pub enum Action<T> {
     /// Output some value.
     Output(T),
 
-    /// Load a font from its bytes.
-    LoadFont {
-        /// The bytes of the font to load.
-        bytes: Cow<'static, [u8]>,
-        /// The channel to send back the load result.
-        channel: oneshot::Sender<Result<(), font::Error>>,
-    },
+    /// Run a font action.
+    Font(font::Action),
 
     /// Run a widget operation.
     Widget(Box<dyn widget::Operation>),
@@ -86,9 +84,7 @@ impl<T> Action<T> {
     fn output<O>(self) -> Result<T, Action<O>> {
         match self {
             Action::Output(output) => Ok(output),
-            Action::LoadFont { bytes, channel } => {
-                Err(Action::LoadFont { bytes, channel })
-            }
+            Action::Font(action) => Err(Action::Font(action)),
             Action::Widget(operation) => Err(Action::Widget(operation)),
             Action::Clipboard(action) => Err(Action::Clipboard(action)),
             Action::Window(action) => Err(Action::Window(action)),
@@ -105,9 +101,7 @@ where
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Action::Output(output) => write!(f, "Action::Output({output:?})"),
-            Action::LoadFont { .. } => {
-                write!(f, "Action::LoadFont")
-            }
+            Action::Font(action) => write!(f, "Action::Font({action:?})"),
             Action::Widget { .. } => {
                 write!(f, "Action::Widget")
             }
@@ -0,0 +1,272 @@
+//! Record and replay the events of a user interface.
+//!
+//! A [`Recording`] is stored as [JSON Lines]; that is, one [`Entry`] per
+//! line. This makes recordings easy to inspect, edit, and stream to disk
+//! while an application is running.
+//!
+//! [JSON Lines]: https://jsonlines.org
+use crate::core::time::{Duration, Instant};
+use crate::core::window;
+use crate::core::Event;
+
+use serde::{Deserialize, Serialize};
+
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::path::Path;
+use std::sync::Arc;
+
+/// A sequence of recorded events, ordered by time.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Recording {
+    entries: Vec<Entry>,
+}
+
+/// An [`Event`] that happened in a window at some point of a [`Recording`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(crate = "serde")]
+pub struct Entry {
+    /// The time elapsed since the start of the [`Recording`].
+    pub time: Duration,
+
+    /// The [`window::Id`] of the window where the [`Event`] happened.
+    pub window: window::Id,
+
+    /// The recorded [`Event`].
+    pub event: Event,
+}
+
+impl Recording {
+    /// Creates a new empty [`Recording`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Loads the [`Recording`] stored at the given path.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Error> {
+        Self::read(io::BufReader::new(fs::File::open(path)?))
+    }
+
+    /// Reads a [`Recording`] from the given reader.
+    pub fn read(reader: impl BufRead) -> Result<Self, Error> {
+        let mut recording = Self::new();
+
+        for line in reader.lines() {
+            let line = line?;
+
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            recording.push(serde_json::from_str(&line)?);
+        }
+
+        Ok(recording)
+    }
+
+    /// Saves the [`Recording`] at the given path.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        let mut writer = io::BufWriter::new(fs::File::create(path)?);
+
+        self.write(&mut writer)?;
+        writer.flush()?;
+
+        Ok(())
+    }
+
+    /// Writes the [`Recording`] to the given writer.
+    pub fn write(&self, mut writer: impl Write) -> Result<(), Error> {
+        for entry in &self.entries {
+            serde_json::to_writer(&mut writer, entry)?;
+            writer.write_all(b"\n")?;
+        }
+
+        Ok(())
+    }
+
+    /// Adds an [`Entry`] to the [`Recording`].
+    ///
+    /// [`window::Event::RedrawRequested`] events are ignored, since they
+    /// are only meaningful for the current process.
+    pub fn push(&mut self, entry: Entry) {
+        if is_recordable(&entry.event) {
+            self.entries.push(entry);
+        }
+    }
+
+    /// Returns the entries of the [`Recording`].
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+
+    /// Returns the identifiers of the windows present in the [`Recording`],
+    /// in order of appearance.
+    pub fn windows(&self) -> impl Iterator<Item = window::Id> + '_ {
+        let mut seen = Vec::new();
+
+        self.entries.iter().filter_map(move |entry| {
+            if seen.contains(&entry.window) {
+                None
+            } else {
+                seen.push(entry.window);
+                Some(entry.window)
+            }
+        })
+    }
+
+    /// Returns the recorded events of the given window.
+    pub fn events(
+        &self,
+        window: window::Id,
+    ) -> impl Iterator<Item = &Event> + '_ {
+        self.entries
+            .iter()
+            .filter(move |entry| entry.window == window)
+            .map(|entry| &entry.event)
+    }
+
+    /// Returns true if the [`Recording`] has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl IntoIterator for Recording {
+    type Item = Entry;
+    type IntoIter = std::vec::IntoIter<Entry>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.into_iter()
+    }
+}
+
+/// A recorder that streams events to a writer as they happen.
+#[derive(Debug)]
+pub struct Recorder<W: Write = io::BufWriter<fs::File>> {
+    writer: W,
+    start: Instant,
+}
+
+impl Recorder {
+    /// Creates a new [`Recorder`] that writes to a new file at the given
+    /// path.
+    ///
+    /// If the file already exists, it will be truncated.
+    pub fn create(path: impl AsRef<Path>) -> Result<Self, Error> {
+        Ok(Self::new(io::BufWriter::new(fs::File::create(path)?)))
+    }
+}
+
+impl<W: Write> Recorder<W> {
+    /// Creates a new [`Recorder`] that writes to the given writer.
+    ///
+    /// The time of every recorded [`Entry`] will be relative to the
+    /// moment the [`Recorder`] is created.
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            start: Instant::now(),
+        }
+    }
+
+    /// Records an [`Event`] that happened in the given window.
+    ///
+    /// [`window::Event::RedrawRequested`] events are ignored.
+    pub fn record(
+        &mut self,
+        window: window::Id,
+        event: &Event,
+    ) -> Result<(), Error> {
+        if !is_recordable(event) {
+            return Ok(());
+        }
+
+        let entry = Entry {
+            time: self.start.elapsed(),
+            window,
+            event: event.clone(),
+        };
+
+        serde_json::to_writer(&mut self.writer, &entry)?;
+        self.writer.write_all(b"\n")?;
+
+        Ok(())
+    }
+
+    /// Flushes any buffered entries to the underlying writer.
+    pub fn flush(&mut self) -> Result<(), Error> {
+        self.writer.flush()?;
+
+        Ok(())
+    }
+}
+
+fn is_recordable(event: &Event) -> bool {
+    !matches!(event, Event::Window(window::Event::RedrawRequested(_)))
+}
+
+/// A recording error.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum Error {
+    /// The recording could not be read or written.
+    #[error("the recording could not be accessed: {0}")]
+    IOFailed(Arc<io::Error>),
+
+    /// An entry of the recording could not be serialized or deserialized.
+    #[error("the recording has an invalid entry: {0}")]
+    InvalidEntry(Arc<serde_json::Error>),
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Self::IOFailed(Arc::new(error))
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(error: serde_json::Error) -> Self {
+        Self::InvalidEntry(Arc::new(error))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::keyboard;
+    use crate::core::mouse;
+    use crate::core::Point;
+
+    #[test]
+    fn recorded_events_can_be_read_back() {
+        let window = window::Id::unique();
+
+        let events = [
+            Event::Mouse(mouse::Event::CursorMoved {
+                position: Point::new(10.0, 20.0),
+            }),
+            Event::Window(window::Event::RedrawRequested(Instant::now())),
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(keyboard::key::Named::Enter),
+                location: keyboard::Location::Standard,
+                modifiers: keyboard::Modifiers::SHIFT,
+                text: Some("\n".into()),
+            }),
+        ];
+
+        let mut recorder = Recorder::new(Vec::new());
+
+        for event in &events {
+            recorder.record(window, event).expect("Record event");
+        }
+
+        let recording =
+            Recording::read(recorder.writer.as_slice()).expect("Read");
+
+        assert_eq!(recording.windows().collect::<Vec<_>>(), vec![window]);
+        assert_eq!(
+            recording.events(window).collect::<Vec<_>>(),
+            vec![&events[0], &events[2]]
+        );
+    }
+}
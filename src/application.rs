@@ -376,6 +376,28 @@ impl<P: Program> Application<P> {
             window: self.window,
         }
     }
+
+    /// Sets the layout [`Direction`] of the [`Application`].
+    ///
+    /// A [`Direction::RightToLeft`] mirrors the whole user interface, which
+    /// is useful for right-to-left languages, like Arabic or Hebrew.
+    ///
+    /// [`Direction`]: crate::Direction
+    /// [`Direction::RightToLeft`]: crate::Direction::RightToLeft
+    pub fn direction(
+        self,
+        f: impl Fn(&P::State) -> crate::Direction,
+    ) -> Application<
+        impl Program<State = P::State, Message = P::Message, Theme = P::Theme>,
+    > {
+        Application {
+            raw: program::with_direction(self.raw, move |state, _window| {
+                f(state)
+            }),
+            settings: self.settings,
+            window: self.window,
+        }
+    }
 }
 
 /// The title logic of some [`Application`].
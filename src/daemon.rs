@@ -223,6 +223,21 @@ impl<P: Program> Daemon<P> {
             settings: self.settings,
         }
     }
+
+    /// Sets the layout [`Direction`] of the [`Daemon`].
+    ///
+    /// [`Direction`]: crate::Direction
+    pub fn direction(
+        self,
+        f: impl Fn(&P::State, window::Id) -> crate::Direction,
+    ) -> Daemon<
+        impl Program<State = P::State, Message = P::Message, Theme = P::Theme>,
+    > {
+        Daemon {
+            raw: program::with_direction(self.raw, f),
+            settings: self.settings,
+        }
+    }
 }
 
 /// The title logic of some [`Daemon`].
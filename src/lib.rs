@@ -477,6 +477,7 @@ pub use crate::core::alignment;
 pub use crate::core::border;
 pub use crate::core::color;
 pub use crate::core::gradient;
+pub use crate::core::layout::Direction;
 pub use crate::core::padding;
 pub use crate::core::theme;
 pub use crate::core::{
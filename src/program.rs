@@ -1,6 +1,8 @@
+use crate::core::layout::Direction;
 use crate::core::text;
 use crate::graphics::compositor;
 use crate::shell;
+use crate::widget::Directional;
 use crate::window;
 use crate::{Element, Executor, Result, Settings, Subscription, Task};
 
@@ -62,6 +64,14 @@ pub trait Program: Sized {
         1.0
     }
 
+    fn direction(
+        &self,
+        _state: &Self::State,
+        _window: window::Id,
+    ) -> Direction {
+        Direction::default()
+    }
+
     /// Runs the [`Program`].
     ///
     /// The state of the [`Program`] must implement [`Default`].
@@ -142,7 +152,14 @@ pub trait Program: Sized {
                 window: window::Id,
             ) -> crate::Element<'_, Self::Message, Self::Theme, Self::Renderer>
             {
-                self.program.view(&self.state, window)
+                let content = self.program.view(&self.state, window);
+                let direction = self.program.direction(&self.state, window);
+
+                if direction.is_right_to_left() {
+                    Directional::new(direction, content).into()
+                } else {
+                    content
+                }
             }
 
             fn subscription(&self) -> Subscription<Self::Message> {
@@ -259,6 +276,14 @@ pub fn with_title<P: Program>(
         fn scale_factor(&self, state: &Self::State, window: window::Id) -> f64 {
             self.program.scale_factor(state, window)
         }
+
+        fn direction(
+            &self,
+            state: &Self::State,
+            window: window::Id,
+        ) -> Direction {
+            self.program.direction(state, window)
+        }
     }
 
     WithTitle { program, title }
@@ -329,6 +354,14 @@ pub fn with_subscription<P: Program>(
         fn scale_factor(&self, state: &Self::State, window: window::Id) -> f64 {
             self.program.scale_factor(state, window)
         }
+
+        fn direction(
+            &self,
+            state: &Self::State,
+            window: window::Id,
+        ) -> Direction {
+            self.program.direction(state, window)
+        }
     }
 
     WithSubscription {
@@ -402,6 +435,14 @@ pub fn with_theme<P: Program>(
         fn scale_factor(&self, state: &Self::State, window: window::Id) -> f64 {
             self.program.scale_factor(state, window)
         }
+
+        fn direction(
+            &self,
+            state: &Self::State,
+            window: window::Id,
+        ) -> Direction {
+            self.program.direction(state, window)
+        }
     }
 
     WithTheme { program, theme: f }
@@ -472,6 +513,14 @@ pub fn with_style<P: Program>(
         fn scale_factor(&self, state: &Self::State, window: window::Id) -> f64 {
             self.program.scale_factor(state, window)
         }
+
+        fn direction(
+            &self,
+            state: &Self::State,
+            window: window::Id,
+        ) -> Direction {
+            self.program.direction(state, window)
+        }
     }
 
     WithStyle { program, style: f }
@@ -542,6 +591,14 @@ pub fn with_scale_factor<P: Program>(
         fn scale_factor(&self, state: &Self::State, window: window::Id) -> f64 {
             (self.scale_factor)(state, window)
         }
+
+        fn direction(
+            &self,
+            state: &Self::State,
+            window: window::Id,
+        ) -> Direction {
+            self.program.direction(state, window)
+        }
     }
 
     WithScaleFactor {
@@ -550,6 +607,87 @@ pub fn with_scale_factor<P: Program>(
     }
 }
 
+pub fn with_direction<P: Program>(
+    program: P,
+    f: impl Fn(&P::State, window::Id) -> Direction,
+) -> impl Program<State = P::State, Message = P::Message, Theme = P::Theme> {
+    struct WithDirection<P, F> {
+        program: P,
+        direction: F,
+    }
+
+    impl<P: Program, F> Program for WithDirection<P, F>
+    where
+        F: Fn(&P::State, window::Id) -> Direction,
+    {
+        type State = P::State;
+        type Message = P::Message;
+        type Theme = P::Theme;
+        type Renderer = P::Renderer;
+        type Executor = P::Executor;
+
+        fn title(&self, state: &Self::State, window: window::Id) -> String {
+            self.program.title(state, window)
+        }
+
+        fn update(
+            &self,
+            state: &mut Self::State,
+            message: Self::Message,
+        ) -> Task<Self::Message> {
+            self.program.update(state, message)
+        }
+
+        fn view<'a>(
+            &self,
+            state: &'a Self::State,
+            window: window::Id,
+        ) -> Element<'a, Self::Message, Self::Theme, Self::Renderer> {
+            self.program.view(state, window)
+        }
+
+        fn subscription(
+            &self,
+            state: &Self::State,
+        ) -> Subscription<Self::Message> {
+            self.program.subscription(state)
+        }
+
+        fn theme(
+            &self,
+            state: &Self::State,
+            window: window::Id,
+        ) -> Self::Theme {
+            self.program.theme(state, window)
+        }
+
+        fn style(
+            &self,
+            state: &Self::State,
+            theme: &Self::Theme,
+        ) -> Appearance {
+            self.program.style(state, theme)
+        }
+
+        fn scale_factor(&self, state: &Self::State, window: window::Id) -> f64 {
+            self.program.scale_factor(state, window)
+        }
+
+        fn direction(
+            &self,
+            state: &Self::State,
+            window: window::Id,
+        ) -> Direction {
+            (self.direction)(state, window)
+        }
+    }
+
+    WithDirection {
+        program,
+        direction: f,
+    }
+}
+
 /// The renderer of some [`Program`].
 pub trait Renderer: text::Renderer + compositor::Default {}
 
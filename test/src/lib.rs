@@ -0,0 +1,1133 @@
+//! Test your iced applications in headless mode.
+//!
+//! A [`Simulator`] builds a [`UserInterface`] for some [`Element`] without
+//! needing a window and lets you interact with it by simulating user input;
+//! like clicking widgets, typing text, or pressing keys.
+//!
+//! The messages produced by these interactions can then be obtained and fed
+//! to your __update logic__. Additionally, a [`Snapshot`] of the user
+//! interface can be rendered offscreen and compared against a stored image
+//! to catch visual regressions.
+//!
+//! # Example
+//! ```
+//! use iced_test::core::Element;
+//! use iced_test::simulator;
+//! use iced_widget::{button, column, text, Renderer, Theme};
+//!
+//! #[derive(Debug, Clone, PartialEq)]
+//! enum Message {
+//!     Increment,
+//! }
+//!
+//! fn view(value: i64) -> Element<'static, Message, Theme, Renderer> {
+//!     column![
+//!         text(value),
+//!         button("Increment").on_press(Message::Increment),
+//!     ]
+//!     .into()
+//! }
+//!
+//! let mut ui = simulator(view(0));
+//!
+//! let _ = ui.click("Increment").expect("Find increment button");
+//!
+//! assert_eq!(
+//!     ui.into_messages().collect::<Vec<_>>(),
+//!     vec![Message::Increment]
+//! );
+//! ```
+#![cfg_attr(docsrs, feature(doc_auto_cfg))]
+pub mod snapshot;
+
+pub use iced_renderer as renderer;
+pub use iced_runtime as runtime;
+pub use iced_runtime::core;
+
+pub use crate::core::widget::operation::query::{Match, Selector};
+pub use snapshot::{Snapshot, Tolerance};
+
+use crate::core::clipboard;
+use crate::core::event;
+use crate::core::keyboard;
+use crate::core::mouse;
+use crate::core::renderer::Headless;
+use crate::core::theme::DefaultStyle;
+use crate::core::touch;
+use crate::core::widget;
+use crate::core::widget::operation::query;
+use crate::core::widget::Operation;
+use crate::core::{Element, Event, Font, Pixels, Point, Size};
+use crate::runtime::accessibility::{self, accesskit};
+use crate::runtime::futures::futures::executor;
+use crate::runtime::futures::futures::StreamExt;
+use crate::runtime::user_interface;
+use crate::runtime::window;
+use crate::runtime::{Action, Task, UserInterface};
+
+use std::io;
+use std::sync::Arc;
+
+/// Creates a new [`Simulator`] for the given [`Element`] using the default
+/// [`Settings`].
+pub fn simulator<'a, Message, Theme, Renderer>(
+    element: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Simulator<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer + Headless,
+{
+    Simulator::new(element)
+}
+
+/// The settings of a [`Simulator`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Settings {
+    /// The size of the simulated window.
+    ///
+    /// By default, it is `1024x768`.
+    pub size: Size,
+
+    /// The scale factor used to render a [`Snapshot`].
+    ///
+    /// By default, it is `1.0`.
+    pub scale_factor: f32,
+
+    /// The default [`Font`] of the renderer.
+    pub default_font: Font,
+
+    /// The default text size of the renderer.
+    pub default_text_size: Pixels,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            size: Size::new(1024.0, 768.0),
+            scale_factor: 1.0,
+            default_font: Font::default(),
+            default_text_size: Pixels(16.0),
+        }
+    }
+}
+
+/// A user interface that can be interacted with without a window.
+#[allow(missing_debug_implementations)]
+pub struct Simulator<
+    'a,
+    Message,
+    Theme = core::Theme,
+    Renderer = renderer::Renderer,
+> {
+    raw: UserInterface<'a, Message, Theme, Renderer>,
+    renderer: Renderer,
+    size: Size,
+    scale_factor: f32,
+    cursor: mouse::Cursor,
+    clipboard: Clipboard,
+    messages: Vec<Message>,
+}
+
+impl<'a, Message, Theme, Renderer> Simulator<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer + Headless,
+{
+    /// Creates a new [`Simulator`] for the given [`Element`] using the
+    /// default [`Settings`].
+    pub fn new(
+        element: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self::with_settings(Settings::default(), element)
+    }
+
+    /// Creates a new [`Simulator`] for the given [`Element`] using the
+    /// provided [`Settings`].
+    pub fn with_settings(
+        settings: Settings,
+        element: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        let mut renderer =
+            Renderer::new(settings.default_font, settings.default_text_size);
+
+        let raw = UserInterface::build(
+            element,
+            settings.size,
+            user_interface::Cache::default(),
+            &mut renderer,
+        );
+
+        Self {
+            raw,
+            renderer,
+            size: settings.size,
+            scale_factor: settings.scale_factor,
+            cursor: mouse::Cursor::Unavailable,
+            clipboard: Clipboard::default(),
+            messages: Vec::new(),
+        }
+    }
+
+    /// Finds the first widget matching the given [`Selector`].
+    pub fn find(
+        &mut self,
+        selector: impl Into<Selector>,
+    ) -> Result<Match, Error> {
+        let selector = selector.into();
+
+        self.find_all(selector.clone())
+            .into_iter()
+            .next()
+            .ok_or(Error::NotFound(selector))
+    }
+
+    /// Finds all the widgets matching the given [`Selector`], in tree order.
+    pub fn find_all(&mut self, selector: impl Into<Selector>) -> Vec<Match> {
+        let mut operation = query::find_all(selector);
+
+        self.raw.operate(
+            &self.renderer,
+            &mut widget::operation::black_box(&mut operation),
+        );
+
+        match operation.finish() {
+            widget::operation::Outcome::Some(matches) => matches,
+            _ => Vec::new(),
+        }
+    }
+
+    /// Builds the accessibility [`Tree`] of the user interface.
+    ///
+    /// [`Tree`]: accessibility::Tree
+    pub fn accessibility(&mut self) -> accessibility::Tree {
+        let mut operation = accessibility::build();
+
+        self.raw.operate(
+            &self.renderer,
+            &mut widget::operation::black_box(&mut operation),
+        );
+
+        match operation.finish() {
+            widget::operation::Outcome::Some(tree) => tree,
+            _ => unreachable!("accessibility tree is always built"),
+        }
+    }
+
+    /// Performs the given [`accesskit::ActionRequest`] as if it came from
+    /// an assistive technology.
+    ///
+    /// The target of the request must be a node of the current
+    /// [`accessibility`](Self::accessibility) tree.
+    pub fn perform_action_request(
+        &mut self,
+        request: &accesskit::ActionRequest,
+    ) {
+        for action in self.accessibility().perform(request) {
+            match action {
+                accessibility::Action::Operate(mut operation) => {
+                    self.raw.operate(&self.renderer, operation.as_mut());
+                }
+                accessibility::Action::Simulate { cursor, events } => {
+                    if let Some(position) = cursor {
+                        self.point_at(position);
+                    }
+
+                    let _ = self.simulate(events);
+                }
+            }
+        }
+    }
+
+    /// Points the simulated mouse cursor at the given position.
+    pub fn point_at(&mut self, position: impl Into<Point>) {
+        self.cursor = mouse::Cursor::Available(position.into());
+    }
+
+    /// Clicks the center of the first widget matching the given
+    /// [`Selector`] with the left mouse button.
+    pub fn click(
+        &mut self,
+        selector: impl Into<Selector>,
+    ) -> Result<Match, Error> {
+        let target = self.find(selector)?;
+        let position = target.bounds.center();
+
+        self.point_at(position);
+
+        let _ = self.simulate([
+            Event::Mouse(mouse::Event::CursorMoved { position }),
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+        ]);
+
+        Ok(target)
+    }
+
+    /// Simulates a key press and release of the given [`keyboard::Key`].
+    ///
+    /// It returns the [`event::Status`] of the key press.
+    pub fn tap_key(&mut self, key: impl Into<keyboard::Key>) -> event::Status {
+        let key = key.into();
+
+        let text = match &key {
+            keyboard::Key::Character(c) => Some(c.clone()),
+            _ => None,
+        };
+
+        self.simulate([
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: key.clone(),
+                location: keyboard::Location::Standard,
+                modifiers: keyboard::Modifiers::default(),
+                text,
+            }),
+            Event::Keyboard(keyboard::Event::KeyReleased {
+                key,
+                location: keyboard::Location::Standard,
+                modifiers: keyboard::Modifiers::default(),
+            }),
+        ])
+        .first()
+        .copied()
+        .unwrap_or(event::Status::Ignored)
+    }
+
+    /// Simulates the user typing the given text, one character at a time.
+    ///
+    /// It returns [`event::Status::Captured`] if any of the key presses
+    /// was captured.
+    pub fn typewrite(&mut self, text: &str) -> event::Status {
+        text.chars()
+            .map(|c| {
+                self.tap_key(keyboard::Key::Character(
+                    c.to_string().as_str().into(),
+                ))
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    /// Simulates the given raw [`Event`]s and returns their
+    /// [`event::Status`].
+    pub fn simulate(
+        &mut self,
+        events: impl IntoIterator<Item = Event>,
+    ) -> Vec<event::Status> {
+        let events: Vec<Event> = events.into_iter().collect();
+
+        let (_state, statuses) = self.raw.update(
+            &events,
+            self.cursor,
+            &mut self.renderer,
+            &mut self.clipboard,
+            &mut self.messages,
+        );
+
+        statuses
+    }
+
+    /// Replays the given [`Event`]s one by one, keeping track of the
+    /// position of the mouse cursor, and returns their [`event::Status`].
+    ///
+    /// This is useful to replay a [`Recording`] of a real session
+    /// captured by a native application:
+    ///
+    /// ```no_run
+    /// # use iced_test::runtime::recording::Recording;
+    /// # fn replay(
+    /// #     mut ui: iced_test::Simulator<'_, ()>,
+    /// # ) -> Result<(), iced_test::runtime::recording::Error> {
+    /// let recording = Recording::load("session.jsonl")?;
+    ///
+    /// if let Some(window) = recording.windows().next() {
+    ///     let _ = ui.replay(recording.events(window).cloned());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Recording`]: crate::runtime::recording::Recording
+    pub fn replay(
+        &mut self,
+        events: impl IntoIterator<Item = Event>,
+    ) -> Vec<event::Status> {
+        events
+            .into_iter()
+            .flat_map(|event| {
+                match event {
+                    Event::Mouse(mouse::Event::CursorMoved { position })
+                    | Event::Touch(
+                        touch::Event::FingerPressed { position, .. }
+                        | touch::Event::FingerMoved { position, .. },
+                    ) => {
+                        self.point_at(position);
+                    }
+                    Event::Mouse(mouse::Event::CursorLeft) => {
+                        self.cursor = mouse::Cursor::Unavailable;
+                    }
+                    _ => {}
+                }
+
+                self.simulate([event])
+            })
+            .collect()
+    }
+
+    /// Runs the given [`Task`] to completion in the current thread.
+    ///
+    /// Any message produced by the [`Task`] will be queued and returned by
+    /// [`into_messages`](Self::into_messages). Widget operations and
+    /// clipboard actions are performed against the [`Simulator`]; any other
+    /// action is ignored.
+    pub fn run(&mut self, task: Task<Message>) {
+        let Some(mut stream) = runtime::task::into_stream(task) else {
+            return;
+        };
+
+        executor::block_on(async {
+            while let Some(action) = stream.next().await {
+                self.perform(action);
+            }
+        });
+    }
+
+    fn perform(&mut self, action: Action<Message>) {
+        match action {
+            Action::Output(message) => {
+                self.messages.push(message);
+            }
+            Action::Widget(operation) => {
+                let mut current_operation = Some(operation);
+
+                while let Some(mut operation) = current_operation.take() {
+                    self.raw.operate(&self.renderer, operation.as_mut());
+
+                    match operation.finish() {
+                        widget::operation::Outcome::None
+                        | widget::operation::Outcome::Some(()) => {}
+                        widget::operation::Outcome::Chain(next) => {
+                            current_operation = Some(next);
+                        }
+                    }
+                }
+            }
+            Action::Clipboard(action) => match action {
+                runtime::clipboard::Action::Read { target, channel } => {
+                    let _ = channel.send(clipboard::Clipboard::read(
+                        &self.clipboard,
+                        target,
+                    ));
+                }
+                runtime::clipboard::Action::Write { target, contents } => {
+                    clipboard::Clipboard::write(
+                        &mut self.clipboard,
+                        target,
+                        contents,
+                    );
+                }
+            },
+            Action::Font(action) => match action {
+                runtime::font::Action::Load { bytes, channel } => {
+                    renderer::graphics::text::font_system()
+                        .write()
+                        .expect("Write to font system")
+                        .load_font(bytes);
+
+                    let _ = channel.send(Ok(()));
+                }
+                runtime::font::Action::List { channel } => {
+                    let faces = renderer::graphics::text::font_system()
+                        .read()
+                        .expect("Read font system")
+                        .faces();
+
+                    let _ = channel.send(faces);
+                }
+                runtime::font::Action::IsAvailable { font, channel } => {
+                    let is_available = renderer::graphics::text::font_system()
+                        .read()
+                        .expect("Read font system")
+                        .is_available(font);
+
+                    let _ = channel.send(is_available);
+                }
+                runtime::font::Action::SetFallbacks(families) => {
+                    renderer::graphics::text::font_system()
+                        .write()
+                        .expect("Write to font system")
+                        .set_fallbacks(families);
+                }
+            },
+            Action::Window(_) | Action::System(_) | Action::Exit => {}
+        }
+    }
+
+    /// Draws the user interface with the given theme and renders it
+    /// offscreen into a [`Snapshot`].
+    pub fn snapshot(&mut self, theme: &Theme) -> Snapshot
+    where
+        Theme: DefaultStyle,
+    {
+        let appearance = theme.default_style();
+
+        let _ = self.raw.draw(
+            &mut self.renderer,
+            theme,
+            &core::renderer::Style {
+                text_color: appearance.text_color,
+            },
+            self.cursor,
+        );
+
+        let size = Size::new(
+            (self.size.width * self.scale_factor).round() as u32,
+            (self.size.height * self.scale_factor).round() as u32,
+        );
+
+        let bytes = self.renderer.screenshot(
+            size,
+            self.scale_factor,
+            appearance.background_color,
+        );
+
+        Snapshot::new(window::Screenshot::new(
+            bytes,
+            size,
+            f64::from(self.scale_factor),
+        ))
+    }
+
+    /// Returns the size of the simulated window.
+    pub fn size(&self) -> Size {
+        self.size
+    }
+
+    /// Consumes the [`Simulator`] and returns all the messages produced
+    /// by the simulated interactions.
+    pub fn into_messages(self) -> impl Iterator<Item = Message> {
+        self.messages.into_iter()
+    }
+}
+
+/// A simulation error.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum Error {
+    /// No widget matched the [`Selector`].
+    #[error("no widget matched the selector: {0:?}")]
+    NotFound(Selector),
+
+    /// An image file could not be read or written.
+    #[error("the image file could not be accessed: {0}")]
+    IOFailed(Arc<io::Error>),
+
+    /// An image could not be decoded as PNG.
+    #[error("the image could not be decoded: {0}")]
+    PngDecodingFailed(Arc<png::DecodingError>),
+
+    /// An image could not be encoded as PNG.
+    #[error("the image could not be encoded: {0}")]
+    PngEncodingFailed(Arc<png::EncodingError>),
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Self::IOFailed(Arc::new(error))
+    }
+}
+
+impl From<png::DecodingError> for Error {
+    fn from(error: png::DecodingError) -> Self {
+        Self::PngDecodingFailed(Arc::new(error))
+    }
+}
+
+impl From<png::EncodingError> for Error {
+    fn from(error: png::EncodingError) -> Self {
+        Self::PngEncodingFailed(Arc::new(error))
+    }
+}
+
+/// An in-memory clipboard.
+#[derive(Debug, Default)]
+struct Clipboard {
+    standard: Option<String>,
+    primary: Option<String>,
+}
+
+impl clipboard::Clipboard for Clipboard {
+    fn read(&self, kind: clipboard::Kind) -> Option<String> {
+        match kind {
+            clipboard::Kind::Standard => self.standard.clone(),
+            clipboard::Kind::Primary => self.primary.clone(),
+        }
+    }
+
+    fn write(&mut self, kind: clipboard::Kind, contents: String) {
+        match kind {
+            clipboard::Kind::Standard => self.standard = Some(contents),
+            clipboard::Kind::Primary => self.primary = Some(contents),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use iced_widget::{button, container, text_editor, text_input};
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        InputChanged(String),
+        Increment,
+        GutterPressed(usize),
+    }
+
+    #[test]
+    fn typewrite_into_focused_text_input() {
+        let id = text_input::Id::new("input");
+
+        let mut ui = simulator(
+            iced_widget::text_input::<_, core::Theme, renderer::Renderer>(
+                "Type something...",
+                "",
+            )
+            .id(id.clone())
+            .on_input(Message::InputChanged),
+        );
+
+        ui.run(text_input::focus(id));
+
+        let _ = ui.typewrite("a");
+
+        assert_eq!(
+            ui.into_messages().collect::<Vec<_>>(),
+            vec![Message::InputChanged(String::from("a"))]
+        );
+    }
+
+    #[test]
+    fn undo_and_redo_in_text_input() {
+        let id = text_input::Id::new("input");
+
+        let mut ui = simulator(
+            iced_widget::text_input::<_, core::Theme, renderer::Renderer>(
+                "Type something...",
+                "",
+            )
+            .id(id.clone())
+            .on_input(Message::InputChanged),
+        );
+
+        ui.run(text_input::focus(id));
+
+        let _ = ui.typewrite("ab c");
+
+        let mut shortcut = |key: &str| {
+            let _ = ui.simulate([Event::Keyboard(
+                keyboard::Event::ModifiersChanged(keyboard::Modifiers::COMMAND),
+            )]);
+
+            let _ = ui.tap_key(keyboard::Key::Character(key.into()));
+
+            let _ = ui.simulate([Event::Keyboard(
+                keyboard::Event::ModifiersChanged(
+                    keyboard::Modifiers::default(),
+                ),
+            )]);
+        };
+
+        shortcut("z");
+        shortcut("z");
+        shortcut("y");
+
+        assert_eq!(
+            ui.into_messages().skip(4).collect::<Vec<_>>(),
+            vec![
+                Message::InputChanged(String::from("ab ")),
+                Message::InputChanged(String::new()),
+                Message::InputChanged(String::from("ab ")),
+            ]
+        );
+    }
+
+    #[test]
+    fn commit_composed_text_into_text_input() {
+        use crate::core::input_method;
+
+        let id = text_input::Id::new("input");
+
+        let mut ui = simulator(
+            iced_widget::text_input::<_, core::Theme, renderer::Renderer>(
+                "Type something...",
+                "",
+            )
+            .id(id.clone())
+            .on_input(Message::InputChanged),
+        );
+
+        ui.run(text_input::focus(id));
+
+        let _ = ui.simulate([
+            Event::InputMethod(input_method::Event::Opened),
+            Event::InputMethod(input_method::Event::Preedit(
+                String::from("にほん"),
+                None,
+            )),
+            Event::InputMethod(input_method::Event::Commit(String::from(
+                "日本",
+            ))),
+        ]);
+
+        assert_eq!(
+            ui.into_messages().collect::<Vec<_>>(),
+            vec![Message::InputChanged(String::from("日本"))]
+        );
+    }
+
+    #[test]
+    fn select_and_copy_text() {
+        let mut ui = simulator::<(), core::Theme, renderer::Renderer>(
+            iced_widget::text("Hello, world!").selectable(true),
+        );
+
+        let text = ui.find(Selector::Kind("text")).expect("Find");
+        let position = Point::new(text.bounds.x + 5.0, text.bounds.center_y());
+
+        ui.point_at(position);
+
+        let click = [
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+        ];
+
+        let _ = ui.simulate(click.clone());
+        let _ = ui.simulate(click);
+
+        let mut shortcut = |key: &str| {
+            let _ = ui.simulate([Event::Keyboard(
+                keyboard::Event::ModifiersChanged(keyboard::Modifiers::COMMAND),
+            )]);
+
+            let _ = ui.tap_key(keyboard::Key::Character(key.into()));
+
+            ui.clipboard.standard.clone()
+        };
+
+        assert_eq!(shortcut("c").as_deref(), Some("Hello"));
+
+        let _ = shortcut("a");
+        assert_eq!(shortcut("c").as_deref(), Some("Hello, world!"));
+    }
+
+    #[test]
+    fn select_centered_text() {
+        let mut ui = simulator::<(), core::Theme, renderer::Renderer>(
+            iced_widget::text("Hello, world!")
+                .width(core::Length::Fill)
+                .height(core::Length::Fill)
+                .center()
+                .selectable(true),
+        );
+
+        let text = ui.find(Selector::Kind("text")).expect("Find");
+        let center = text.bounds.center();
+
+        ui.point_at(Point::new(center.x - 30.0, center.y));
+
+        let click = [
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+        ];
+
+        let _ = ui.simulate(click.clone());
+        let _ = ui.simulate(click);
+
+        let _ = ui.simulate([Event::Keyboard(
+            keyboard::Event::ModifiersChanged(keyboard::Modifiers::COMMAND),
+        )]);
+        let _ = ui.tap_key(keyboard::Key::Character("c".into()));
+
+        assert_eq!(ui.clipboard.standard.as_deref(), Some("Hello"));
+    }
+
+    #[test]
+    fn press_line_in_text_editor_gutter() {
+        let content = text_editor::Content::with_text("one\ntwo\nthree");
+
+        let mut ui = simulator(
+            iced_widget::text_editor::<_, core::Theme, renderer::Renderer>(
+                &content,
+            )
+            .line_numbers(true)
+            .on_gutter_press(Message::GutterPressed),
+        );
+
+        let editor = ui.find(Selector::Kind("text_editor")).expect("Find");
+
+        // The default padding is 5 and the default line height is 20.8
+        ui.point_at(Point::new(editor.bounds.x + 7.0, editor.bounds.y + 36.0));
+
+        let _ = ui.simulate([Event::Mouse(mouse::Event::ButtonPressed(
+            mouse::Button::Left,
+        ))]);
+
+        assert_eq!(
+            ui.into_messages().collect::<Vec<_>>(),
+            vec![Message::GutterPressed(1)]
+        );
+    }
+
+    #[test]
+    fn read_only_text_editor_produces_no_edits() {
+        let content = text_editor::Content::with_text("Hello");
+
+        let mut ui = simulator(
+            iced_widget::text_editor::<_, core::Theme, renderer::Renderer>(
+                &content,
+            )
+            .read_only(true)
+            .on_action(|action| action),
+        );
+
+        let _ = ui.click(Selector::Kind("text_editor")).expect("Click");
+        let _ = ui.typewrite("a");
+        let _ = ui.tap_key(keyboard::Key::Named(keyboard::key::Named::Enter));
+        let _ =
+            ui.tap_key(keyboard::Key::Named(keyboard::key::Named::ArrowLeft));
+
+        let actions: Vec<_> = ui.into_messages().collect();
+
+        assert!(matches!(
+            actions.as_slice(),
+            [
+                text_editor::Action::Click(_),
+                text_editor::Action::Move(text_editor::Motion::Left)
+            ]
+        ));
+    }
+
+    #[test]
+    fn text_editor_indents_with_tab_only_when_enabled() {
+        let content = text_editor::Content::with_text("Hello");
+
+        let tab = |tab_indents| {
+            let mut ui = simulator(
+                iced_widget::text_editor::<_, core::Theme, renderer::Renderer>(
+                    &content,
+                )
+                .tab_indents(tab_indents)
+                .on_action(|action| action),
+            );
+
+            let _ = ui.click(Selector::Kind("text_editor")).expect("Click");
+            let status =
+                ui.tap_key(keyboard::Key::Named(keyboard::key::Named::Tab));
+
+            (status, ui.into_messages().skip(1).collect::<Vec<_>>())
+        };
+
+        let (status, actions) = tab(false);
+
+        assert_eq!(status, event::Status::Ignored);
+        assert!(actions.is_empty());
+
+        let (status, actions) = tab(true);
+
+        assert_eq!(status, event::Status::Captured);
+        assert!(matches!(
+            actions.as_slice(),
+            [text_editor::Action::Edit(text_editor::Edit::Indent(_))]
+        ));
+    }
+
+    #[test]
+    fn scroll_text_editor_horizontally() {
+        const LINE_HEIGHT: f32 = 20.0;
+
+        let content = text_editor::Content::with_text(&"long ".repeat(500));
+
+        let mut ui = simulator(
+            iced_widget::text_editor::<_, core::Theme, renderer::Renderer>(
+                &content,
+            )
+            .line_height(Pixels(LINE_HEIGHT))
+            .wrapping(core::text::Wrapping::None)
+            .on_action(|action| action),
+        );
+
+        let editor = ui.find(Selector::Kind("text_editor")).expect("Find");
+        ui.point_at(editor.bounds.center());
+
+        let _ = ui.simulate([
+            Event::Keyboard(keyboard::Event::ModifiersChanged(
+                keyboard::Modifiers::SHIFT,
+            )),
+            Event::Mouse(mouse::Event::WheelScrolled {
+                delta: mouse::ScrollDelta::Lines { x: 0.0, y: -1.0 },
+            }),
+        ]);
+
+        let actions: Vec<_> = ui.into_messages().collect();
+
+        // Scrolling down with Shift moves right by a whole number of lines
+        assert!(matches!(
+            actions.as_slice(),
+            [text_editor::Action::ScrollHorizontally { pixels }]
+                if *pixels > 0.0 && (pixels / LINE_HEIGHT).fract() == 0.0
+        ));
+    }
+
+    #[test]
+    fn find_widgets_by_kind_and_id_prefix() {
+        let mut ui = simulator::<(), core::Theme, renderer::Renderer>(
+            iced_widget::column![
+                container("Hello").id(container::Id::new("greeting-hello")),
+                container("World").id(container::Id::new("greeting-world")),
+                iced_widget::checkbox("Subscribe", false),
+            ],
+        );
+
+        let greetings = ui.find_all(
+            Selector::IdPrefix(String::from("greeting-"))
+                .and(Selector::Kind("container")),
+        );
+
+        assert_eq!(greetings.len(), 2);
+
+        let checkbox = ui.find(Selector::Kind("checkbox")).expect("Find");
+
+        assert_eq!(checkbox.text.as_deref(), Some("Subscribe"));
+        assert_eq!(
+            ui.find(checkbox.bounds.center())
+                .expect("Find by point")
+                .kind,
+            Some("column")
+        );
+    }
+
+    #[test]
+    fn button_is_exposed_to_assistive_technologies() {
+        let mut ui = simulator::<_, core::Theme, renderer::Renderer>(
+            iced_widget::column![
+                "Counter",
+                button("Increment").on_press(Message::Increment),
+            ],
+        );
+
+        let tree =
+            accesskit_consumer::Tree::new(ui.accessibility().update(1.0), true);
+
+        let state = tree.state();
+        let button = state
+            .root()
+            .filtered_children(|node| {
+                if node.role() == accesskit::Role::GenericContainer {
+                    accesskit_consumer::FilterResult::ExcludeNode
+                } else {
+                    accesskit_consumer::FilterResult::Include
+                }
+            })
+            .find(|node| node.role() == accesskit::Role::Button)
+            .expect("Find button");
+
+        assert_eq!(button.name().as_deref(), Some("Increment"));
+
+        ui.perform_action_request(&accesskit::ActionRequest {
+            action: accesskit::Action::Default,
+            target: button.id(),
+            data: None,
+        });
+
+        assert_eq!(
+            ui.into_messages().collect::<Vec<_>>(),
+            vec![Message::Increment]
+        );
+    }
+
+    #[test]
+    fn widgets_are_announced_by_their_labels() {
+        let mut ui = simulator::<_, core::Theme, renderer::Renderer>(
+            iced_widget::column![
+                button("+")
+                    .accessible_label("Increment")
+                    .accessible_description("Adds one to the counter")
+                    .on_press(Message::Increment),
+                iced_widget::checkbox("Subscribe", true),
+                iced_widget::slider(0..=10, 3, |_| Message::Increment),
+            ],
+        );
+
+        let tree =
+            accesskit_consumer::Tree::new(ui.accessibility().update(1.0), true);
+
+        let state = tree.state();
+        let column = state.root().children().next().expect("Find column");
+        let mut widgets = column.children();
+
+        let button = widgets.next().expect("Find button");
+
+        assert_eq!(button.role(), accesskit::Role::Button);
+        assert_eq!(button.name().as_deref(), Some("Increment"));
+        assert_eq!(
+            button.description().as_deref(),
+            Some("Adds one to the counter")
+        );
+
+        let checkbox = widgets.next().expect("Find checkbox");
+
+        assert_eq!(checkbox.role(), accesskit::Role::CheckBox);
+        assert_eq!(checkbox.name().as_deref(), Some("Subscribe"));
+        assert_eq!(checkbox.toggled(), Some(accesskit::Toggled::True));
+        assert!(checkbox.is_disabled());
+
+        let slider = widgets.next().expect("Find slider");
+
+        assert_eq!(slider.role(), accesskit::Role::Slider);
+        assert_eq!(slider.numeric_value(), Some(3.0));
+        assert_eq!(slider.min_numeric_value(), Some(0.0));
+        assert_eq!(slider.max_numeric_value(), Some(10.0));
+    }
+
+    #[test]
+    fn custom_widgets_report_their_semantics() {
+        use crate::core::layout::{self, Layout};
+        use crate::core::widget::semantics::{Role, Semantics};
+        use crate::core::widget::Tree;
+        use crate::core::{Length, Rectangle, Widget};
+
+        struct Logo;
+
+        impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Logo
+        where
+            Renderer: core::Renderer,
+        {
+            fn size(&self) -> Size<Length> {
+                Size::new(Length::Fixed(10.0), Length::Fixed(10.0))
+            }
+
+            fn layout(
+                &self,
+                _tree: &mut Tree,
+                _renderer: &Renderer,
+                _limits: &layout::Limits,
+            ) -> layout::Node {
+                layout::Node::new(Size::new(10.0, 10.0))
+            }
+
+            fn draw(
+                &self,
+                _tree: &Tree,
+                _renderer: &mut Renderer,
+                _theme: &Theme,
+                _style: &core::renderer::Style,
+                _layout: Layout<'_>,
+                _cursor: mouse::Cursor,
+                _viewport: &Rectangle,
+            ) {
+            }
+
+            fn semantics(
+                &self,
+                _tree: &Tree,
+                _layout: Layout<'_>,
+            ) -> Option<Semantics> {
+                Some(Semantics::new(Role::Image).label("Logo"))
+            }
+        }
+
+        let mut ui = simulator::<Message, core::Theme, renderer::Renderer>(
+            Element::new(Logo),
+        );
+
+        let tree =
+            accesskit_consumer::Tree::new(ui.accessibility().update(1.0), true);
+
+        let state = tree.state();
+        let logo = state.root().children().next().expect("Find logo");
+
+        assert_eq!(logo.role(), accesskit::Role::Image);
+        assert_eq!(logo.name().as_deref(), Some("Logo"));
+    }
+
+    #[test]
+    fn nodes_keep_their_ids_when_widgets_are_inserted() {
+        let view = |header: bool| {
+            simulator::<_, core::Theme, renderer::Renderer>(
+                iced_widget::column![]
+                    .push_maybe(header.then_some("Header"))
+                    .push(
+                        text_input("Name", "")
+                            .id(text_input::Id::new("name"))
+                            .on_input(Message::InputChanged),
+                    ),
+            )
+        };
+
+        let find_input = |update: accesskit::TreeUpdate| {
+            let tree = accesskit_consumer::Tree::new(update, true);
+            let state = tree.state();
+            let column = state.root().children().next().expect("Find column");
+
+            let input = column
+                .children()
+                .find(|node| node.role() == accesskit::Role::TextInput)
+                .expect("Find text input");
+
+            input.id()
+        };
+
+        let before = view(false).accessibility();
+        let after = view(true).accessibility();
+
+        assert_eq!(
+            find_input(before.update(1.0)),
+            find_input(after.update(1.0))
+        );
+        assert!(after.diff(&after.clone(), 1.0).nodes.is_empty());
+
+        let diff = after.diff(&before, 1.0);
+
+        assert!(diff.tree.is_none());
+        assert!(!diff.nodes.is_empty());
+        assert!(diff.nodes.len() < after.update(1.0).nodes.len());
+
+        let mut ui = view(true);
+        let input = find_input(ui.accessibility().update(1.0));
+
+        ui.perform_action_request(&accesskit::ActionRequest {
+            action: accesskit::Action::Focus,
+            target: input,
+            data: None,
+        });
+
+        assert_eq!(ui.accessibility().update(1.0).focus, input);
+    }
+
+    #[test]
+    fn snapshot_matches_stored_image() {
+        let path = std::env::temp_dir()
+            .join(format!("iced_test-{}", std::process::id()))
+            .join("snapshot.png");
+
+        let view = |color: core::Color| {
+            simulator::<(), core::Theme, renderer::Renderer>(
+                container("").width(100).height(100).style(move |_theme| {
+                    container::Style::default().background(color)
+                }),
+            )
+        };
+
+        let theme = core::Theme::Light;
+
+        let snapshot = view(core::Color::BLACK).snapshot(&theme);
+
+        assert!(snapshot.matches_image(&path).expect("Save snapshot"));
+        assert!(path.exists());
+
+        let same = view(core::Color::BLACK).snapshot(&theme);
+        let different = view(core::Color::WHITE).snapshot(&theme);
+
+        assert!(same.matches_image(&path).expect("Compare snapshot"));
+        assert!(!different.matches_image(&path).expect("Compare snapshot"));
+
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+    }
+}
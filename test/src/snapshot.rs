@@ -0,0 +1,138 @@
+//! Compare the rendered output of a user interface against stored images.
+use crate::runtime::window::Screenshot;
+use crate::Error;
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// A rendered image of a user interface taken with a
+/// [`Simulator`](crate::Simulator).
+///
+/// A [`Snapshot`] can be compared against a stored PNG image to catch
+/// visual regressions.
+#[derive(Debug, Clone)]
+pub struct Snapshot {
+    screenshot: Screenshot,
+    tolerance: Tolerance,
+}
+
+impl Snapshot {
+    /// Creates a new [`Snapshot`] from the given [`Screenshot`].
+    pub fn new(screenshot: Screenshot) -> Self {
+        Self {
+            screenshot,
+            tolerance: Tolerance::default(),
+        }
+    }
+
+    /// Sets the [`Tolerance`] used to compare the [`Snapshot`] against
+    /// an image.
+    pub fn tolerance(mut self, tolerance: Tolerance) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Returns the [`Screenshot`] of the [`Snapshot`].
+    pub fn screenshot(&self) -> &Screenshot {
+        &self.screenshot
+    }
+
+    /// Compares the [`Snapshot`] against the PNG image at the given path,
+    /// taking its [`Tolerance`] into account.
+    ///
+    /// If the image does not exist yet, the [`Snapshot`] will be saved
+    /// at the given path and considered a match.
+    pub fn matches_image(&self, path: impl AsRef<Path>) -> Result<bool, Error> {
+        let path = path.as_ref();
+
+        if !path.exists() {
+            self.save(path)?;
+
+            return Ok(true);
+        }
+
+        let decoder = png::Decoder::new(fs::File::open(path)?);
+        let mut reader = decoder.read_info()?;
+
+        let mut bytes = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut bytes)?;
+
+        if info.color_type != png::ColorType::Rgba
+            || info.bit_depth != png::BitDepth::Eight
+            || info.width != self.screenshot.size.width
+            || info.height != self.screenshot.size.height
+        {
+            return Ok(false);
+        }
+
+        Ok(self
+            .tolerance
+            .accepts(&bytes[..info.buffer_size()], &self.screenshot.bytes))
+    }
+
+    /// Saves the [`Snapshot`] as a PNG image at the given path, creating
+    /// any missing parent directories.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        let path = path.as_ref();
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let file = fs::File::create(path)?;
+
+        let mut encoder = png::Encoder::new(
+            io::BufWriter::new(file),
+            self.screenshot.size.width,
+            self.screenshot.size.height,
+        );
+
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&self.screenshot.bytes)?;
+        writer.finish()?;
+
+        Ok(())
+    }
+}
+
+/// The tolerance of a [`Snapshot`] comparison.
+///
+/// By default, images must match exactly.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Tolerance {
+    /// The maximum difference allowed between the channels of two pixels
+    /// for them to be considered equal.
+    pub channel: u8,
+
+    /// The maximum ratio of pixels that may differ, from `0.0` to `1.0`.
+    pub pixels: f32,
+}
+
+impl Tolerance {
+    fn accepts(self, expected: &[u8], actual: &[u8]) -> bool {
+        const PIXEL_SIZE: usize = 4;
+
+        if expected.len() != actual.len() {
+            return false;
+        }
+
+        let total = expected.len() / PIXEL_SIZE;
+
+        let different = expected
+            .chunks_exact(PIXEL_SIZE)
+            .zip(actual.chunks_exact(PIXEL_SIZE))
+            .filter(|(expected, actual)| {
+                expected
+                    .iter()
+                    .zip(actual.iter())
+                    .any(|(a, b)| a.abs_diff(*b) > self.channel)
+            })
+            .count();
+
+        different as f32 <= self.pixels * total as f32
+    }
+}
@@ -1,7 +1,7 @@
 use crate::core::alignment;
 use crate::core::text::Shaping;
 use crate::core::{
-    Color, Font, Pixels, Point, Rectangle, Size, Transformation,
+    Color, Font, Pixels, Point, Rectangle, Size, Transformation, Vector,
 };
 use crate::graphics::text::cache::{self, Cache};
 use crate::graphics::text::editor;
@@ -63,6 +63,7 @@ impl Pipeline {
             color,
             paragraph.horizontal_alignment(),
             paragraph.vertical_alignment(),
+            paragraph.paragraph_spacing(),
             pixels,
             clip_mask,
             transformation,
@@ -90,10 +91,14 @@ impl Pipeline {
             font_system.raw(),
             &mut self.glyph_cache,
             editor.buffer(),
-            Rectangle::new(position, editor.bounds()),
+            Rectangle::new(
+                position - Vector::new(editor.horizontal_scroll(), 0.0),
+                editor.bounds(),
+            ),
             color,
             alignment::Horizontal::Left,
             alignment::Vertical::Top,
+            0.0,
             pixels,
             clip_mask,
             transformation,
@@ -118,7 +123,6 @@ impl Pipeline {
         let line_height = f32::from(line_height);
 
         let mut font_system = font_system().write().expect("Write font system");
-        let font_system = font_system.raw();
 
         let key = cache::Key {
             bounds: bounds.size(),
@@ -129,13 +133,13 @@ impl Pipeline {
             shaping,
         };
 
-        let (_, entry) = self.cache.get_mut().allocate(font_system, key);
+        let (_, entry) = self.cache.get_mut().allocate(&mut font_system, key);
 
         let width = entry.min_bounds.width;
         let height = entry.min_bounds.height;
 
         draw(
-            font_system,
+            font_system.raw(),
             &mut self.glyph_cache,
             &entry.buffer,
             Rectangle {
@@ -146,6 +150,7 @@ impl Pipeline {
             color,
             horizontal_alignment,
             vertical_alignment,
+            0.0,
             pixels,
             clip_mask,
             transformation,
@@ -179,6 +184,7 @@ impl Pipeline {
             color,
             alignment::Horizontal::Left,
             alignment::Vertical::Top,
+            0.0,
             pixels,
             clip_mask,
             transformation,
@@ -199,6 +205,7 @@ fn draw(
     color: Color,
     horizontal_alignment: alignment::Horizontal,
     vertical_alignment: alignment::Vertical,
+    paragraph_spacing: f32,
     pixels: &mut tiny_skia::PixmapMut<'_>,
     clip_mask: Option<&tiny_skia::Mask>,
     transformation: Transformation,
@@ -206,7 +213,9 @@ fn draw(
     let bounds = bounds * transformation;
 
     let x = match horizontal_alignment {
-        alignment::Horizontal::Left => bounds.x,
+        alignment::Horizontal::Left | alignment::Horizontal::Justified => {
+            bounds.x
+        }
         alignment::Horizontal::Center => bounds.x - bounds.width / 2.0,
         alignment::Horizontal::Right => bounds.x - bounds.width,
     };
@@ -220,6 +229,8 @@ fn draw(
     let mut swash = cosmic_text::SwashCache::new();
 
     for run in buffer.layout_runs() {
+        let line_y = run.line_y + run.line_i as f32 * paragraph_spacing;
+
         for glyph in run.glyphs {
             let physical_glyph =
                 glyph.physical((x, y), transformation.scale_factor());
@@ -246,7 +257,7 @@ fn draw(
                 pixels.draw_pixmap(
                     physical_glyph.x + placement.left,
                     physical_glyph.y - placement.top
-                        + (run.line_y * transformation.scale_factor()).round()
+                        + (line_y * transformation.scale_factor()).round()
                             as i32,
                     pixmap,
                     &tiny_skia::PixmapPaint {
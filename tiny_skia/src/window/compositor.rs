@@ -121,12 +121,12 @@ impl crate::graphics::Compositor for Compositor {
     fn screenshot<T: AsRef<str>>(
         &mut self,
         renderer: &mut Self::Renderer,
-        surface: &mut Self::Surface,
+        _surface: &mut Self::Surface,
         viewport: &Viewport,
         background_color: Color,
         overlay: &[T],
     ) -> Vec<u8> {
-        screenshot(renderer, surface, viewport, background_color, overlay)
+        screenshot(renderer, viewport, background_color, overlay)
     }
 }
 
@@ -212,7 +212,6 @@ pub fn present<T: AsRef<str>>(
 
 pub fn screenshot<T: AsRef<str>>(
     renderer: &mut Renderer,
-    surface: &mut Surface,
     viewport: &Viewport,
     background_color: Color,
     overlay: &[T],
@@ -222,6 +221,9 @@ pub fn screenshot<T: AsRef<str>>(
     let mut offscreen_buffer: Vec<u32> =
         vec![0; size.width as usize * size.height as usize];
 
+    let mut clip_mask = tiny_skia::Mask::new(size.width, size.height)
+        .expect("Create clip mask");
+
     renderer.draw(
         &mut tiny_skia::PixmapMut::from_bytes(
             bytemuck::cast_slice_mut(&mut offscreen_buffer),
@@ -229,7 +231,7 @@ pub fn screenshot<T: AsRef<str>>(
             size.height,
         )
         .expect("Create offscreen pixel map"),
-        &mut surface.clip_mask,
+        &mut clip_mask,
         viewport,
         &[Rectangle::with_size(Size::new(
             size.width as f32,
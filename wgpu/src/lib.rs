@@ -409,6 +409,9 @@ impl Renderer {
                         vertical_alignment: alignment::Vertical::Top,
                         shaping: core::text::Shaping::Basic,
                         wrapping: core::text::Wrapping::Word,
+                        ellipsis: core::text::Ellipsis::None,
+                        max_lines: None,
+                        paragraph_spacing: Pixels::ZERO,
                     };
 
                     renderer.fill_text(
@@ -1,5 +1,5 @@
 use crate::core::alignment;
-use crate::core::{Rectangle, Size, Transformation};
+use crate::core::{Rectangle, Size, Transformation, Vector};
 use crate::graphics::cache;
 use crate::graphics::color;
 use crate::graphics::text::cache::{self as text_cache, Cache as BufferCache};
@@ -442,7 +442,6 @@ fn prepare(
     layer_transformation: Transformation,
 ) -> Result<(), glyphon::PrepareError> {
     let mut font_system = font_system().write().expect("Write font system");
-    let font_system = font_system.raw();
 
     enum Allocation {
         Paragraph(Paragraph),
@@ -470,7 +469,7 @@ fn prepare(
                 ..
             } => {
                 let (key, _) = buffer_cache.allocate(
-                    font_system,
+                    &mut font_system,
                     text_cache::Key {
                         content,
                         size: f32::from(*size),
@@ -490,13 +489,16 @@ fn prepare(
         })
         .collect();
 
-    let text_areas = sections.iter().zip(allocations.iter()).filter_map(
-        |(section, allocation)| {
+    let text_areas = sections
+        .iter()
+        .zip(allocations.iter())
+        .filter_map(|(section, allocation)| {
             let (
                 buffer,
                 bounds,
                 horizontal_alignment,
                 vertical_alignment,
+                paragraphs,
                 color,
                 clip_bounds,
                 transformation,
@@ -520,6 +522,7 @@ fn prepare(
                         Rectangle::new(*position, paragraph.min_bounds()),
                         paragraph.horizontal_alignment(),
                         paragraph.vertical_alignment(),
+                        paragraph.paragraphs(),
                         *color,
                         *clip_bounds,
                         *transformation,
@@ -540,9 +543,14 @@ fn prepare(
 
                     (
                         editor.buffer(),
-                        Rectangle::new(*position, editor.bounds()),
+                        Rectangle::new(
+                            *position
+                                - Vector::new(editor.horizontal_scroll(), 0.0),
+                            editor.bounds(),
+                        ),
                         alignment::Horizontal::Left,
                         alignment::Vertical::Top,
+                        &[][..],
                         *color,
                         *clip_bounds,
                         *transformation,
@@ -568,6 +576,7 @@ fn prepare(
                         Rectangle::new(bounds.position(), entry.min_bounds),
                         *horizontal_alignment,
                         *vertical_alignment,
+                        &[][..],
                         *color,
                         *clip_bounds,
                         Transformation::IDENTITY,
@@ -594,6 +603,7 @@ fn prepare(
                         ),
                         alignment::Horizontal::Left,
                         alignment::Vertical::Top,
+                        &[][..],
                         raw.color,
                         raw.clip_bounds,
                         *transformation,
@@ -604,7 +614,8 @@ fn prepare(
             let bounds = bounds * transformation * layer_transformation;
 
             let left = match horizontal_alignment {
-                alignment::Horizontal::Left => bounds.x,
+                alignment::Horizontal::Left
+                | alignment::Horizontal::Justified => bounds.x,
                 alignment::Horizontal::Center => bounds.x - bounds.width / 2.0,
                 alignment::Horizontal::Right => bounds.x - bounds.width,
             };
@@ -619,12 +630,14 @@ fn prepare(
                 &(clip_bounds * transformation * layer_transformation),
             )?;
 
-            Some(glyphon::TextArea {
+            let scale = transformation.scale_factor()
+                * layer_transformation.scale_factor();
+
+            let text_area = |buffer, top| glyphon::TextArea {
                 buffer,
                 left,
                 top,
-                scale: transformation.scale_factor()
-                    * layer_transformation.scale_factor(),
+                scale,
                 bounds: glyphon::TextBounds {
                     left: clip_bounds.x as i32,
                     top: clip_bounds.y as i32,
@@ -632,15 +645,28 @@ fn prepare(
                     bottom: (clip_bounds.y + clip_bounds.height) as i32,
                 },
                 default_color: to_color(color),
-            })
-        },
-    );
+            };
+
+            if paragraphs.is_empty() {
+                return Some(vec![text_area(buffer, top)]);
+            }
+
+            // Every paragraph is laid out in its own buffer and drawn
+            // shifted down to its position
+            Some(
+                paragraphs
+                    .iter()
+                    .map(|(y, buffer)| text_area(buffer, top + y * scale))
+                    .collect(),
+            )
+        })
+        .flatten();
 
     renderer.prepare(
         device,
         queue,
         encoder,
-        font_system,
+        font_system.raw(),
         atlas,
         viewport,
         text_areas,
@@ -7,6 +7,7 @@ use crate::core::overlay;
 use crate::core::renderer;
 use crate::core::theme::palette;
 use crate::core::touch;
+use crate::core::widget::semantics::{Role, Semantics};
 use crate::core::widget::tree::{self, Tree};
 use crate::core::widget::Operation;
 use crate::core::{
@@ -58,6 +59,7 @@ where
     height: Length,
     padding: Padding,
     clip: bool,
+    semantics: Semantics,
     class: Theme::Class<'a>,
 }
 
@@ -94,6 +96,7 @@ where
             height: size.height.fluid(),
             padding: DEFAULT_PADDING,
             clip: false,
+            semantics: Semantics::new(Role::Button),
             class: Theme::default(),
         }
     }
@@ -156,6 +159,24 @@ where
         self
     }
 
+    /// Sets the label of the [`Button`] announced by assistive technologies.
+    ///
+    /// This is useful for buttons without any text, like icon buttons.
+    pub fn accessible_label(mut self, label: impl Into<String>) -> Self {
+        self.semantics = self.semantics.label(label);
+        self
+    }
+
+    /// Sets the description of the [`Button`] announced by assistive
+    /// technologies.
+    pub fn accessible_description(
+        mut self,
+        description: impl Into<String>,
+    ) -> Self {
+        self.semantics = self.semantics.description(description);
+        self
+    }
+
     /// Sets the style of the [`Button`].
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
@@ -231,6 +252,14 @@ where
         )
     }
 
+    fn semantics(
+        &self,
+        _tree: &Tree,
+        _layout: Layout<'_>,
+    ) -> Option<Semantics> {
+        Some(self.semantics.clone().disabled(self.on_press.is_none()))
+    }
+
     fn operate(
         &self,
         tree: &mut Tree,
@@ -238,6 +267,10 @@ where
         renderer: &Renderer,
         operation: &mut dyn Operation,
     ) {
+        operation.kind(None, layout.bounds(), "button");
+        if let Some(semantics) = self.semantics(tree, layout) {
+            operation.semantics(None, layout.bounds(), &semantics);
+        }
         operation.container(None, layout.bounds(), &mut |operation| {
             self.content.as_widget().operate(
                 &mut tree.children[0],
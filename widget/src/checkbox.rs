@@ -8,6 +8,7 @@ use crate::core::text;
 use crate::core::theme::palette;
 use crate::core::touch;
 use crate::core::widget;
+use crate::core::widget::semantics::{Role, Semantics};
 use crate::core::widget::tree::{self, Tree};
 use crate::core::{
     Background, Border, Clipboard, Color, Element, Layout, Length, Pixels,
@@ -53,6 +54,7 @@ pub struct Checkbox<
     text_wrapping: text::Wrapping,
     font: Option<Renderer::Font>,
     icon: Icon<Renderer::Font>,
+    semantics: Semantics,
     class: Theme::Class<'a>,
 }
 
@@ -92,6 +94,7 @@ where
                 line_height: text::LineHeight::default(),
                 shaping: text::Shaping::Basic,
             },
+            semantics: Semantics::new(Role::CheckBox),
             class: Theme::default(),
         }
     }
@@ -180,6 +183,24 @@ where
         self
     }
 
+    /// Sets the label of the [`Checkbox`] announced by assistive technologies.
+    ///
+    /// By default, its visible label is announced.
+    pub fn accessible_label(mut self, label: impl Into<String>) -> Self {
+        self.semantics = self.semantics.label(label);
+        self
+    }
+
+    /// Sets the description of the [`Checkbox`] announced by assistive
+    /// technologies.
+    pub fn accessible_description(
+        mut self,
+        description: impl Into<String>,
+    ) -> Self {
+        self.semantics = self.semantics.description(description);
+        self
+    }
+
     /// Sets the style of the [`Checkbox`].
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
@@ -249,11 +270,42 @@ where
                     alignment::Vertical::Top,
                     self.text_shaping,
                     self.text_wrapping,
+                    text::Ellipsis::None,
+                    None,
+                    Pixels::ZERO,
                 )
             },
         )
     }
 
+    fn semantics(
+        &self,
+        _tree: &Tree,
+        _layout: Layout<'_>,
+    ) -> Option<Semantics> {
+        Some(
+            self.semantics
+                .clone()
+                .or_label(self.label.clone())
+                .toggled(self.is_checked)
+                .disabled(self.on_toggle.is_none()),
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        operation.kind(None, layout.bounds(), "checkbox");
+        if let Some(semantics) = self.semantics(tree, layout) {
+            operation.semantics(None, layout.bounds(), &semantics);
+        }
+        operation.text(None, layout.bounds(), &self.label);
+    }
+
     fn on_event(
         &mut self,
         _tree: &mut Tree,
@@ -358,6 +410,9 @@ where
                         vertical_alignment: alignment::Vertical::Center,
                         shaping: *shaping,
                         wrapping: text::Wrapping::default(),
+                        ellipsis: text::Ellipsis::None,
+                        max_lines: None,
+                        paragraph_spacing: Pixels::ZERO,
                     },
                     bounds.center(),
                     style.icon_color,
@@ -224,6 +224,7 @@ where
         renderer: &Renderer,
         operation: &mut dyn Operation,
     ) {
+        operation.kind(None, layout.bounds(), "column");
         operation.container(None, layout.bounds(), &mut |operation| {
             self.children
                 .iter()
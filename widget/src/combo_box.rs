@@ -8,6 +8,7 @@ use crate::core::overlay;
 use crate::core::renderer;
 use crate::core::text;
 use crate::core::time::Instant;
+use crate::core::widget::semantics::{Role, Semantics};
 use crate::core::widget::{self, Widget};
 use crate::core::{
     Clipboard, Element, Length, Padding, Rectangle, Shell, Size, Theme, Vector,
@@ -156,6 +157,23 @@ where
         }
     }
 
+    /// Sets the label of the [`ComboBox`] announced by assistive
+    /// technologies.
+    pub fn accessible_label(mut self, label: impl Into<String>) -> Self {
+        self.text_input = self.text_input.accessible_label(label);
+        self
+    }
+
+    /// Sets the description of the [`ComboBox`] announced by assistive
+    /// technologies.
+    pub fn accessible_description(
+        mut self,
+        description: impl Into<String>,
+    ) -> Self {
+        self.text_input = self.text_input.accessible_description(description);
+        self
+    }
+
     /// Sets the style of the input of the [`ComboBox`].
     #[must_use]
     pub fn input_style(
@@ -394,6 +412,43 @@ where
         vec![widget::Tree::new(&self.text_input as &dyn Widget<_, _, _>)]
     }
 
+    fn semantics(
+        &self,
+        tree: &widget::Tree,
+        layout: Layout<'_>,
+    ) -> Option<Semantics> {
+        let semantics = (&self.text_input as &dyn Widget<_, _, _>)
+            .semantics(&tree.children[0], layout)?;
+
+        Some(Semantics {
+            role: Role::ComboBox,
+            ..semantics
+        })
+    }
+
+    fn operate(
+        &self,
+        tree: &mut widget::Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        operation.kind(None, layout.bounds(), "combo_box");
+
+        if let Some(semantics) = self.semantics(tree, layout) {
+            operation.semantics(None, layout.bounds(), &semantics);
+        }
+
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.text_input.operate(
+                &mut tree.children[0],
+                layout,
+                renderer,
+                operation,
+            );
+        });
+    }
+
     fn on_event(
         &mut self,
         tree: &mut widget::Tree,
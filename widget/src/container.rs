@@ -246,6 +246,11 @@ where
         renderer: &Renderer,
         operation: &mut dyn Operation,
     ) {
+        operation.kind(
+            self.id.as_ref().map(|id| &id.0),
+            layout.bounds(),
+            "container",
+        );
         operation.container(
             self.id.as_ref().map(|id| &id.0),
             layout.bounds(),
@@ -0,0 +1,267 @@
+use crate::core::event::{self, Event};
+use crate::core::layout::{self, Direction};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::{Operation, Semantics};
+use crate::core::{
+    Clipboard, Element, Layout, Length, Point, Rectangle, Shell, Size, Vector,
+    Widget,
+};
+
+/// A widget that lays out its contents in a specific [`Direction`].
+///
+/// A [`Direction::RightToLeft`] mirrors the layout of the contents, along
+/// with any overlays they produce.
+#[allow(missing_debug_implementations)]
+pub struct Directional<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Renderer: crate::core::Renderer,
+{
+    direction: Direction,
+    content: Element<'a, Message, Theme, Renderer>,
+}
+
+impl<'a, Message, Theme, Renderer> Directional<'a, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    /// Creates a [`Directional`] that lays out the provided `content` in
+    /// the given [`Direction`].
+    pub fn new(
+        direction: Direction,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            direction,
+            content: content.into(),
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Directional<'a, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        self.content.as_widget().tag()
+    }
+
+    fn state(&self) -> tree::State {
+        self.content.as_widget().state()
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.content.as_widget().children()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        self.content.as_widget().diff(tree);
+    }
+
+    fn semantics(&self, tree: &Tree, layout: Layout<'_>) -> Option<Semantics> {
+        self.content.as_widget().semantics(tree, layout)
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(tree, renderer, limits)
+            .with_direction(self.direction)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget()
+            .operate(tree, layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.content.as_widget_mut().on_event(
+            tree, event, layout, cursor, renderer, clipboard, shell, viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content
+            .as_widget()
+            .mouse_interaction(tree, layout, cursor, viewport, renderer)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content
+            .as_widget()
+            .draw(tree, renderer, theme, style, layout, cursor, viewport);
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        struct Overlay<'a, Message, Theme, Renderer> {
+            direction: Direction,
+            content: overlay::Element<'a, Message, Theme, Renderer>,
+        }
+
+        impl<'a, Message, Theme, Renderer>
+            overlay::Overlay<Message, Theme, Renderer>
+            for Overlay<'a, Message, Theme, Renderer>
+        where
+            Renderer: crate::core::Renderer,
+        {
+            fn layout(
+                &mut self,
+                renderer: &Renderer,
+                bounds: Size,
+            ) -> layout::Node {
+                self.content
+                    .layout(renderer, bounds)
+                    .with_direction(self.direction)
+            }
+
+            fn draw(
+                &self,
+                renderer: &mut Renderer,
+                theme: &Theme,
+                style: &renderer::Style,
+                layout: Layout<'_>,
+                cursor: mouse::Cursor,
+            ) {
+                self.content.draw(renderer, theme, style, layout, cursor);
+            }
+
+            fn on_event(
+                &mut self,
+                event: Event,
+                layout: Layout<'_>,
+                cursor: mouse::Cursor,
+                renderer: &Renderer,
+                clipboard: &mut dyn Clipboard,
+                shell: &mut Shell<'_, Message>,
+            ) -> event::Status {
+                self.content
+                    .on_event(event, layout, cursor, renderer, clipboard, shell)
+            }
+
+            fn operate(
+                &mut self,
+                layout: Layout<'_>,
+                renderer: &Renderer,
+                operation: &mut dyn Operation,
+            ) {
+                self.content.operate(layout, renderer, operation);
+            }
+
+            fn mouse_interaction(
+                &self,
+                layout: Layout<'_>,
+                cursor: mouse::Cursor,
+                viewport: &Rectangle,
+                renderer: &Renderer,
+            ) -> mouse::Interaction {
+                self.content
+                    .mouse_interaction(layout, cursor, viewport, renderer)
+            }
+
+            fn is_over(
+                &self,
+                layout: Layout<'_>,
+                renderer: &Renderer,
+                cursor_position: Point,
+            ) -> bool {
+                self.content.is_over(layout, renderer, cursor_position)
+            }
+
+            fn overlay<'b>(
+                &'b mut self,
+                layout: Layout<'_>,
+                renderer: &Renderer,
+            ) -> Option<overlay::Element<'b, Message, Theme, Renderer>>
+            {
+                let direction = self.direction;
+
+                self.content
+                    .overlay(layout, renderer)
+                    .map(|content| Overlay { direction, content })
+                    .map(|overlay| overlay::Element::new(Box::new(overlay)))
+            }
+        }
+
+        let direction = self.direction;
+
+        self.content
+            .as_widget_mut()
+            .overlay(tree, layout, renderer, translation)
+            .map(|content| Overlay { direction, content })
+            .map(|overlay| overlay::Element::new(Box::new(overlay)))
+    }
+}
+
+impl<'a, Message, Theme, Renderer>
+    From<Directional<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: 'a + crate::core::Renderer,
+{
+    fn from(
+        directional: Directional<'a, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(directional)
+    }
+}
@@ -4,6 +4,7 @@ use crate::checkbox::{self, Checkbox};
 use crate::combo_box::{self, ComboBox};
 use crate::container::{self, Container};
 use crate::core;
+use crate::core::layout::Direction;
 use crate::core::widget::operation::{self, Operation};
 use crate::core::window;
 use crate::core::{Element, Length, Pixels, Widget};
@@ -23,7 +24,7 @@ use crate::text_input::{self, TextInput};
 use crate::toggler::{self, Toggler};
 use crate::tooltip::{self, Tooltip};
 use crate::vertical_slider::{self, VerticalSlider};
-use crate::{Column, MouseArea, Row, Space, Stack, Themer};
+use crate::{Column, Directional, MouseArea, Row, Space, Stack, Themer};
 
 use std::borrow::Borrow;
 use std::ops::RangeInclusive;
@@ -226,6 +227,7 @@ where
     use crate::core::mouse;
     use crate::core::renderer;
     use crate::core::widget::tree::{self, Tree};
+    use crate::core::widget::Semantics;
     use crate::core::{Rectangle, Shell, Size};
 
     struct Opaque<'a, Message, Theme, Renderer> {
@@ -253,6 +255,14 @@ where
             self.content.as_widget().diff(tree);
         }
 
+        fn semantics(
+            &self,
+            tree: &Tree,
+            layout: Layout<'_>,
+        ) -> Option<Semantics> {
+            self.content.as_widget().semantics(tree, layout)
+        }
+
         fn size(&self) -> Size<Length> {
             self.content.as_widget().size()
         }
@@ -1044,6 +1054,17 @@ where
     MouseArea::new(widget)
 }
 
+/// A widget that lays out its contents in the given [`Direction`].
+pub fn directional<'a, Message, Theme, Renderer>(
+    direction: Direction,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Directional<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    Directional::new(direction, content)
+}
+
 /// A widget that applies any `Theme` to its contents.
 pub fn themer<'a, Message, OldTheme, NewTheme, Renderer>(
     new_theme: NewTheme,
@@ -6,6 +6,7 @@ use crate::core::image;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
+use crate::core::widget::semantics::{Role, Semantics};
 use crate::core::widget::Tree;
 use crate::core::{
     ContentFit, Element, Layout, Length, Point, Rectangle, Rotation, Size,
@@ -39,6 +40,7 @@ pub struct Image<Handle> {
     filter_method: FilterMethod,
     rotation: Rotation,
     opacity: f32,
+    semantics: Semantics,
 }
 
 impl<Handle> Image<Handle> {
@@ -52,6 +54,7 @@ impl<Handle> Image<Handle> {
             filter_method: FilterMethod::default(),
             rotation: Rotation::default(),
             opacity: 1.0,
+            semantics: Semantics::new(Role::Image),
         }
     }
 
@@ -95,6 +98,22 @@ impl<Handle> Image<Handle> {
         self.opacity = opacity.into();
         self
     }
+
+    /// Sets the label of the [`Image`] announced by assistive technologies.
+    pub fn accessible_label(mut self, label: impl Into<String>) -> Self {
+        self.semantics = self.semantics.label(label);
+        self
+    }
+
+    /// Sets the description of the [`Image`] announced by assistive
+    /// technologies.
+    pub fn accessible_description(
+        mut self,
+        description: impl Into<String>,
+    ) -> Self {
+        self.semantics = self.semantics.description(description);
+        self
+    }
 }
 
 /// Computes the layout of an [`Image`].
@@ -230,6 +249,14 @@ where
         )
     }
 
+    fn semantics(
+        &self,
+        _tree: &Tree,
+        _layout: Layout<'_>,
+    ) -> Option<Semantics> {
+        Some(self.semantics.clone())
+    }
+
     fn draw(
         &self,
         _state: &Tree,
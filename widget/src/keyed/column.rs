@@ -267,6 +267,7 @@ where
         renderer: &Renderer,
         operation: &mut dyn Operation,
     ) {
+        operation.kind(None, layout.bounds(), "keyed_column");
         operation.container(None, layout.bounds(), &mut |operation| {
             self.children
                 .iter()
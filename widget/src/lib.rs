@@ -9,6 +9,7 @@ pub use iced_runtime as runtime;
 pub use iced_runtime::core;
 
 mod column;
+mod directional;
 mod mouse_area;
 mod row;
 mod space;
@@ -56,6 +57,8 @@ pub use combo_box::ComboBox;
 #[doc(no_inline)]
 pub use container::Container;
 #[doc(no_inline)]
+pub use directional::Directional;
+#[doc(no_inline)]
 pub use mouse_area::MouseArea;
 #[doc(no_inline)]
 pub use pane_grid::PaneGrid;
@@ -4,14 +4,18 @@
 //! in code blocks.
 //!
 //! Only the variants of [`Item`] are currently supported.
+use crate::core::alignment;
 use crate::core::border;
 use crate::core::font::{self, Font};
 use crate::core::padding;
-use crate::core::theme;
+use crate::core::theme::{self, palette};
 use crate::core::{
-    self, color, Color, Element, Length, Padding, Pixels, Theme,
+    self, color, Border, Color, Element, Length, Padding, Pixels, Theme,
+};
+use crate::{
+    checkbox, column, container, horizontal_rule, rich_text, row, rule,
+    scrollable, span, text, Space,
 };
-use crate::{column, container, rich_text, row, scrollable, span, text};
 
 use std::cell::{Cell, RefCell};
 use std::rc::Rc;
@@ -36,10 +40,66 @@ pub enum Item {
         /// The first number of the list, if it is ordered.
         start: Option<u64>,
         /// The items of the list.
-        items: Vec<Vec<Item>>,
+        items: Vec<Bullet>,
+    },
+    /// A block quote.
+    Quote(Vec<Item>),
+    /// A thematic break.
+    Rule,
+    /// An image.
+    ///
+    /// Images are displayed with the loader given to [`view_with`].
+    Image {
+        /// The URL of the image, as written in the Markdown.
+        url: String,
+        /// The title of the image.
+        title: String,
+        /// The alternative text of the image.
+        alt: Text,
+    },
+    /// A table.
+    Table {
+        /// The alignment of each column.
+        alignments: Vec<alignment::Horizontal>,
+        /// The cells of the header.
+        header: Vec<Text>,
+        /// The cells of each row.
+        rows: Vec<Vec<Text>>,
     },
 }
 
+/// A bullet of a [`Item::List`].
+#[derive(Debug, Clone)]
+pub enum Bullet {
+    /// A plain bullet point.
+    Point {
+        /// The items of the bullet.
+        items: Vec<Item>,
+    },
+    /// An entry of a task list.
+    Task {
+        /// The items of the bullet.
+        items: Vec<Item>,
+        /// Whether the task is done.
+        done: bool,
+    },
+}
+
+impl Bullet {
+    /// Returns the items of the [`Bullet`].
+    pub fn items(&self) -> &[Item] {
+        match self {
+            Self::Point { items } | Self::Task { items, .. } => items,
+        }
+    }
+
+    fn items_mut(&mut self) -> &mut Vec<Item> {
+        match self {
+            Self::Point { items } | Self::Task { items, .. } => items,
+        }
+    }
+}
+
 /// A bunch of parsed Markdown text.
 #[derive(Debug, Clone)]
 pub struct Text {
@@ -86,8 +146,7 @@ enum Span {
     #[cfg(feature = "highlighter")]
     Highlight {
         text: String,
-        color: Option<Color>,
-        font: Option<Font>,
+        highlight: iced_highlighter::Highlight,
     },
 }
 
@@ -137,18 +196,114 @@ impl Span {
                 span
             }
             #[cfg(feature = "highlighter")]
-            Span::Highlight { text, color, font } => {
-                span(text.clone()).color_maybe(*color).font_maybe(*font)
+            Span::Highlight { text, highlight } => {
+                let format = highlight.to_format_with(&style.code_palette);
+
+                span(text.clone())
+                    .color_maybe(format.color)
+                    .font_maybe(format.font)
             }
         }
     }
 }
 
+/// Some Markdown content that can be appended to incrementally.
+///
+/// Appending text to the [`Content`] only parses its last block again,
+/// along with the new text. The [`Item`]s of the previous blocks are kept
+/// intact, together with their cached spans and highlighted code; which
+/// makes it a good fit for text that is streamed in chunks.
+///
+/// Link reference definitions only apply to the links that are parsed
+/// after them.
+#[derive(Debug, Clone, Default)]
+pub struct Content {
+    markdown: String,
+    items: Vec<Item>,
+    last_block: usize,
+    stable_items: usize,
+}
+
+impl Content {
+    /// Creates an empty [`Content`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a [`Content`] by parsing the given Markdown.
+    pub fn parse(markdown: &str) -> Self {
+        let mut content = Self::new();
+        content.push_str(markdown);
+        content
+    }
+
+    /// Appends the given Markdown to the [`Content`].
+    pub fn push_str(&mut self, markdown: &str) {
+        if markdown.is_empty() {
+            return;
+        }
+
+        self.markdown.push_str(markdown);
+        self.items.truncate(self.stable_items);
+
+        let offset = self.last_block;
+        let mut current_block = None;
+
+        for (start, item) in parse_with_offsets(&self.markdown[offset..]) {
+            if current_block != Some(start) {
+                current_block = Some(start);
+
+                // Blocks are parsed again from the start of their line,
+                // so their indentation is kept
+                self.last_block = self.markdown[..offset + start]
+                    .rfind('\n')
+                    .map_or(0, |i| i + 1);
+                self.stable_items = self.items.len();
+            }
+
+            self.items.push(item);
+        }
+    }
+
+    /// Returns the parsed [`Item`]s of the [`Content`].
+    pub fn items(&self) -> &[Item] {
+        &self.items
+    }
+
+    /// Returns the Markdown of the [`Content`].
+    pub fn as_str(&self) -> &str {
+        &self.markdown
+    }
+}
+
 /// Parse the given Markdown content.
 pub fn parse(markdown: &str) -> impl Iterator<Item = Item> + '_ {
-    struct List {
-        start: Option<u64>,
-        items: Vec<Vec<Item>>,
+    parse_with_offsets(markdown).map(|(_, item)| item)
+}
+
+/// Parses the given Markdown content, along with the byte offset where the
+/// top-level block of each [`Item`] starts.
+fn parse_with_offsets(
+    markdown: &str,
+) -> impl Iterator<Item = (usize, Item)> + '_ {
+    enum Scope {
+        List {
+            start: Option<u64>,
+            items: Vec<Bullet>,
+        },
+        Quote(Vec<Item>),
+    }
+
+    struct Table {
+        alignments: Vec<alignment::Horizontal>,
+        header: Vec<Text>,
+        rows: Vec<Vec<Text>>,
+        cells: Vec<Text>,
+    }
+
+    struct Image {
+        url: String,
+        title: String,
     }
 
     let mut spans = Vec::new();
@@ -156,9 +311,10 @@ pub fn parse(markdown: &str) -> impl Iterator<Item = Item> + '_ {
     let mut emphasis = false;
     let mut strikethrough = false;
     let mut metadata = false;
-    let mut table = false;
+    let mut table = None;
+    let mut image = None;
     let mut link = None;
-    let mut lists = Vec::new();
+    let mut scopes = Vec::new();
 
     #[cfg(feature = "highlighter")]
     let mut highlighter = None;
@@ -168,44 +324,59 @@ pub fn parse(markdown: &str) -> impl Iterator<Item = Item> + '_ {
         pulldown_cmark::Options::ENABLE_YAML_STYLE_METADATA_BLOCKS
             | pulldown_cmark::Options::ENABLE_PLUSES_DELIMITED_METADATA_BLOCKS
             | pulldown_cmark::Options::ENABLE_TABLES
-            | pulldown_cmark::Options::ENABLE_STRIKETHROUGH,
+            | pulldown_cmark::Options::ENABLE_STRIKETHROUGH
+            | pulldown_cmark::Options::ENABLE_TASKLISTS,
     );
 
-    let produce = |lists: &mut Vec<List>, item| {
-        if lists.is_empty() {
-            Some(item)
-        } else {
-            lists
-                .last_mut()
-                .expect("list context")
-                .items
+    let produce = |scopes: &mut Vec<Scope>, item| match scopes.last_mut() {
+        None => Some(item),
+        Some(Scope::List { items, .. }) => {
+            items
                 .last_mut()
                 .expect("item context")
+                .items_mut()
                 .push(item);
 
             None
         }
+        Some(Scope::Quote(items)) => {
+            items.push(item);
+
+            None
+        }
+    };
+
+    // The text of a tight list item is not wrapped in a paragraph,
+    // so it must be produced before any nested block starts
+    #[allow(clippy::drain_collect)]
+    let flush = move |scopes: &mut Vec<Scope>, spans: &mut Vec<Span>| {
+        if spans.is_empty() {
+            None
+        } else {
+            produce(
+                scopes,
+                Item::Paragraph(Text::new(spans.drain(..).collect())),
+            )
+        }
     };
 
     // We want to keep the `spans` capacity
     #[allow(clippy::drain_collect)]
-    parser.filter_map(move |event| match event {
+    let mut item = move |event| match event {
         pulldown_cmark::Event::Start(tag) => match tag {
-            pulldown_cmark::Tag::Strong if !metadata && !table => {
+            pulldown_cmark::Tag::Strong if !metadata => {
                 strong = true;
                 None
             }
-            pulldown_cmark::Tag::Emphasis if !metadata && !table => {
+            pulldown_cmark::Tag::Emphasis if !metadata => {
                 emphasis = true;
                 None
             }
-            pulldown_cmark::Tag::Strikethrough if !metadata && !table => {
+            pulldown_cmark::Tag::Strikethrough if !metadata => {
                 strikethrough = true;
                 None
             }
-            pulldown_cmark::Tag::Link { dest_url, .. }
-                if !metadata && !table =>
-            {
+            pulldown_cmark::Tag::Link { dest_url, .. } if !metadata => {
                 match Url::parse(&dest_url) {
                     Ok(url)
                         if url.scheme() == "http"
@@ -218,25 +389,43 @@ pub fn parse(markdown: &str) -> impl Iterator<Item = Item> + '_ {
 
                 None
             }
-            pulldown_cmark::Tag::List(first_item) if !metadata && !table => {
-                lists.push(List {
+            pulldown_cmark::Tag::Image {
+                dest_url, title, ..
+            } if !metadata && table.is_none() => {
+                image = Some(Image {
+                    url: dest_url.into_string(),
+                    title: title.into_string(),
+                });
+
+                flush(&mut scopes, &mut spans)
+            }
+            pulldown_cmark::Tag::List(first_item) if !metadata => {
+                let item = flush(&mut scopes, &mut spans);
+
+                scopes.push(Scope::List {
                     start: first_item,
                     items: Vec::new(),
                 });
 
-                None
+                item
             }
             pulldown_cmark::Tag::Item => {
-                lists
-                    .last_mut()
-                    .expect("list context")
-                    .items
-                    .push(Vec::new());
+                if let Some(Scope::List { items, .. }) = scopes.last_mut() {
+                    items.push(Bullet::Point { items: Vec::new() });
+                }
+
                 None
             }
+            pulldown_cmark::Tag::BlockQuote(_) if !metadata => {
+                let item = flush(&mut scopes, &mut spans);
+
+                scopes.push(Scope::Quote(Vec::new()));
+
+                item
+            }
             pulldown_cmark::Tag::CodeBlock(
                 pulldown_cmark::CodeBlockKind::Fenced(_language),
-            ) if !metadata && !table => {
+            ) if !metadata => {
                 #[cfg(feature = "highlighter")]
                 {
                     use iced_highlighter::{self, Highlighter};
@@ -244,81 +433,104 @@ pub fn parse(markdown: &str) -> impl Iterator<Item = Item> + '_ {
 
                     highlighter =
                         Some(Highlighter::new(&iced_highlighter::Settings {
-                            theme: iced_highlighter::Theme::Base16Ocean,
+                            theme: iced_highlighter::Theme::Palette,
                             token: _language.to_string(),
                         }));
                 }
 
-                None
+                flush(&mut scopes, &mut spans)
             }
             pulldown_cmark::Tag::MetadataBlock(_) => {
                 metadata = true;
                 None
             }
-            pulldown_cmark::Tag::Table(_) => {
-                table = true;
-                None
+            pulldown_cmark::Tag::Table(alignments) if !metadata => {
+                table = Some(Table {
+                    alignments: alignments
+                        .into_iter()
+                        .map(|alignment| match alignment {
+                            pulldown_cmark::Alignment::None
+                            | pulldown_cmark::Alignment::Left => {
+                                alignment::Horizontal::Left
+                            }
+                            pulldown_cmark::Alignment::Center => {
+                                alignment::Horizontal::Center
+                            }
+                            pulldown_cmark::Alignment::Right => {
+                                alignment::Horizontal::Right
+                            }
+                        })
+                        .collect(),
+                    header: Vec::new(),
+                    rows: Vec::new(),
+                    cells: Vec::new(),
+                });
+
+                flush(&mut scopes, &mut spans)
             }
             _ => None,
         },
         pulldown_cmark::Event::End(tag) => match tag {
-            pulldown_cmark::TagEnd::Heading(level) if !metadata && !table => {
-                produce(
-                    &mut lists,
-                    Item::Heading(level, Text::new(spans.drain(..).collect())),
-                )
-            }
-            pulldown_cmark::TagEnd::Strong if !metadata && !table => {
+            pulldown_cmark::TagEnd::Heading(level) if !metadata => produce(
+                &mut scopes,
+                Item::Heading(level, Text::new(spans.drain(..).collect())),
+            ),
+            pulldown_cmark::TagEnd::Strong if !metadata => {
                 strong = false;
                 None
             }
-            pulldown_cmark::TagEnd::Emphasis if !metadata && !table => {
+            pulldown_cmark::TagEnd::Emphasis if !metadata => {
                 emphasis = false;
                 None
             }
-            pulldown_cmark::TagEnd::Strikethrough if !metadata && !table => {
+            pulldown_cmark::TagEnd::Strikethrough if !metadata => {
                 strikethrough = false;
                 None
             }
-            pulldown_cmark::TagEnd::Link if !metadata && !table => {
+            pulldown_cmark::TagEnd::Link if !metadata => {
                 link = None;
                 None
             }
-            pulldown_cmark::TagEnd::Paragraph if !metadata && !table => {
+            pulldown_cmark::TagEnd::Image if !metadata => {
+                let Image { url, title } = image.take()?;
+
                 produce(
-                    &mut lists,
-                    Item::Paragraph(Text::new(spans.drain(..).collect())),
+                    &mut scopes,
+                    Item::Image {
+                        url,
+                        title,
+                        alt: Text::new(spans.drain(..).collect()),
+                    },
                 )
             }
-            pulldown_cmark::TagEnd::Item if !metadata && !table => {
-                if spans.is_empty() {
-                    None
-                } else {
-                    produce(
-                        &mut lists,
-                        Item::Paragraph(Text::new(spans.drain(..).collect())),
-                    )
-                }
+            pulldown_cmark::TagEnd::Paragraph if !metadata => {
+                flush(&mut scopes, &mut spans)
+            }
+            pulldown_cmark::TagEnd::Item if !metadata => {
+                flush(&mut scopes, &mut spans)
+            }
+            pulldown_cmark::TagEnd::List(_) if !metadata => {
+                let Some(Scope::List { start, items }) = scopes.pop() else {
+                    return None;
+                };
+
+                produce(&mut scopes, Item::List { start, items })
             }
-            pulldown_cmark::TagEnd::List(_) if !metadata && !table => {
-                let list = lists.pop().expect("list context");
+            pulldown_cmark::TagEnd::BlockQuote if !metadata => {
+                let Some(Scope::Quote(items)) = scopes.pop() else {
+                    return None;
+                };
 
-                produce(
-                    &mut lists,
-                    Item::List {
-                        start: list.start,
-                        items: list.items,
-                    },
-                )
+                produce(&mut scopes, Item::Quote(items))
             }
-            pulldown_cmark::TagEnd::CodeBlock if !metadata && !table => {
+            pulldown_cmark::TagEnd::CodeBlock if !metadata => {
                 #[cfg(feature = "highlighter")]
                 {
                     highlighter = None;
                 }
 
                 produce(
-                    &mut lists,
+                    &mut scopes,
                     Item::CodeBlock(Text::new(spans.drain(..).collect())),
                 )
             }
@@ -326,13 +538,47 @@ pub fn parse(markdown: &str) -> impl Iterator<Item = Item> + '_ {
                 metadata = false;
                 None
             }
-            pulldown_cmark::TagEnd::Table => {
-                table = false;
+            pulldown_cmark::TagEnd::TableCell if !metadata => {
+                if let Some(table) = &mut table {
+                    table.cells.push(Text::new(spans.drain(..).collect()));
+                }
+
+                None
+            }
+            pulldown_cmark::TagEnd::TableHead if !metadata => {
+                if let Some(table) = &mut table {
+                    table.header = table.cells.drain(..).collect();
+                }
+
                 None
             }
+            pulldown_cmark::TagEnd::TableRow if !metadata => {
+                if let Some(table) = &mut table {
+                    table.rows.push(table.cells.drain(..).collect());
+                }
+
+                None
+            }
+            pulldown_cmark::TagEnd::Table if !metadata => {
+                let Table {
+                    alignments,
+                    header,
+                    rows,
+                    ..
+                } = table.take()?;
+
+                produce(
+                    &mut scopes,
+                    Item::Table {
+                        alignments,
+                        header,
+                        rows,
+                    },
+                )
+            }
             _ => None,
         },
-        pulldown_cmark::Event::Text(text) if !metadata && !table => {
+        pulldown_cmark::Event::Text(text) if !metadata => {
             #[cfg(feature = "highlighter")]
             if let Some(highlighter) = &mut highlighter {
                 use text::Highlighter as _;
@@ -342,8 +588,7 @@ pub fn parse(markdown: &str) -> impl Iterator<Item = Item> + '_ {
                 {
                     let span = Span::Highlight {
                         text: text[range].to_owned(),
-                        color: highlight.color(),
-                        font: highlight.font(),
+                        highlight,
                     };
 
                     spans.push(span);
@@ -365,7 +610,7 @@ pub fn parse(markdown: &str) -> impl Iterator<Item = Item> + '_ {
 
             None
         }
-        pulldown_cmark::Event::Code(code) if !metadata && !table => {
+        pulldown_cmark::Event::Code(code) if !metadata => {
             let span = Span::Standard {
                 text: code.into_string(),
                 strong,
@@ -378,7 +623,7 @@ pub fn parse(markdown: &str) -> impl Iterator<Item = Item> + '_ {
             spans.push(span);
             None
         }
-        pulldown_cmark::Event::SoftBreak if !metadata && !table => {
+        pulldown_cmark::Event::SoftBreak if !metadata => {
             spans.push(Span::Standard {
                 text: String::from(" "),
                 strikethrough,
@@ -389,7 +634,7 @@ pub fn parse(markdown: &str) -> impl Iterator<Item = Item> + '_ {
             });
             None
         }
-        pulldown_cmark::Event::HardBreak if !metadata && !table => {
+        pulldown_cmark::Event::HardBreak if !metadata => {
             spans.push(Span::Standard {
                 text: String::from("\n"),
                 strikethrough,
@@ -400,7 +645,46 @@ pub fn parse(markdown: &str) -> impl Iterator<Item = Item> + '_ {
             });
             None
         }
+        pulldown_cmark::Event::Rule if !metadata => {
+            produce(&mut scopes, Item::Rule)
+        }
+        pulldown_cmark::Event::TaskListMarker(done) if !metadata => {
+            if let Some(Scope::List { items, .. }) = scopes.last_mut() {
+                if let Some(bullet) = items.last_mut() {
+                    *bullet = Bullet::Task {
+                        items: std::mem::take(bullet.items_mut()),
+                        done,
+                    };
+                }
+            }
+
+            None
+        }
         _ => None,
+    };
+
+    let mut depth = 0;
+    let mut block = 0;
+
+    parser.into_offset_iter().filter_map(move |(event, range)| {
+        match &event {
+            pulldown_cmark::Event::Start(_) => {
+                if depth == 0 {
+                    block = range.start;
+                }
+
+                depth += 1;
+            }
+            pulldown_cmark::Event::End(_) => {
+                depth -= 1;
+            }
+            _ if depth == 0 => {
+                block = range.start;
+            }
+            _ => {}
+        }
+
+        item(event).map(|item| (block, item))
     })
 }
 
@@ -464,6 +748,9 @@ pub struct Style {
     pub inline_code_color: Color,
     /// The [`Color`] to be applied to links.
     pub link_color: Color,
+    /// The [`palette::Extended`] used to highlight the syntax of code
+    /// blocks.
+    pub code_palette: palette::Extended,
 }
 
 impl Style {
@@ -477,6 +764,7 @@ impl Style {
             },
             inline_code_color: Color::WHITE,
             link_color: palette.primary,
+            code_palette: palette::Extended::generate(palette),
         }
     }
 }
@@ -484,11 +772,33 @@ impl Style {
 /// Display a bunch of Markdown items.
 ///
 /// You can obtain the items with [`parse`].
+///
+/// Images are displayed with their alternative text. Use [`view_with`]
+/// to load them.
 pub fn view<'a, Theme, Renderer>(
     items: impl IntoIterator<Item = &'a Item>,
     settings: Settings,
     style: Style,
 ) -> Element<'a, Url, Theme, Renderer>
+where
+    Theme: Catalog + 'a,
+    Renderer: core::text::Renderer<Font = Font> + 'a,
+{
+    view_with(items, settings, style, &|_| None)
+}
+
+/// Display a bunch of Markdown items, loading images with the given
+/// closure.
+///
+/// The closure receives the URL of an image, as written in the Markdown,
+/// and produces the widget that displays it; or [`None`] to display its
+/// alternative text instead.
+pub fn view_with<'a, Theme, Renderer>(
+    items: impl IntoIterator<Item = &'a Item>,
+    settings: Settings,
+    style: Style,
+    image: &dyn Fn(&str) -> Option<Element<'a, Url, Theme, Renderer>>,
+) -> Element<'a, Url, Theme, Renderer>
 where
     Theme: Catalog + 'a,
     Renderer: core::text::Renderer<Font = Font> + 'a,
@@ -506,6 +816,46 @@ where
 
     let spacing = text_size * 0.625;
 
+    let marker = |bullet: &Bullet,
+                  marker: Element<'a, Url, Theme, Renderer>| {
+        match bullet {
+            Bullet::Point { .. } => marker,
+            Bullet::Task { done, .. } => {
+                checkbox("", *done).size(text_size).spacing(0).into()
+            }
+        }
+    };
+
+    let cells = |cells: &'a [Text],
+                 alignments: &'a [alignment::Horizontal],
+                 is_header: bool| {
+        row(alignments.iter().enumerate().map(|(i, alignment)| {
+            let cell: Element<'a, Url, Theme, Renderer> = match cells.get(i) {
+                Some(cell) => {
+                    let cell = rich_text(cell.spans(style)).size(text_size);
+
+                    if is_header {
+                        cell.font(Font {
+                            weight: font::Weight::Bold,
+                            ..Font::default()
+                        })
+                        .into()
+                    } else {
+                        cell.into()
+                    }
+                }
+                None => Space::new(Length::Shrink, Length::Shrink).into(),
+            };
+
+            container(cell)
+                .width(Length::Fill)
+                .align_x(*alignment)
+                .padding(spacing.0 / 2.0)
+                .into()
+        }))
+        .into()
+    };
+
     let blocks = items.into_iter().enumerate().map(|(i, item)| match item {
         Item::Heading(level, heading) => {
             container(rich_text(heading.spans(style)).size(match level {
@@ -527,10 +877,13 @@ where
             rich_text(paragraph.spans(style)).size(text_size).into()
         }
         Item::List { start: None, items } => {
-            column(items.iter().map(|items| {
-                row![text("•").size(text_size), view(items, settings, style)]
-                    .spacing(spacing)
-                    .into()
+            column(items.iter().map(|bullet| {
+                row![
+                    marker(bullet, text("•").size(text_size).into()),
+                    view_with(bullet.items(), settings, style, image)
+                ]
+                .spacing(spacing)
+                .into()
             }))
             .spacing(spacing)
             .into()
@@ -538,10 +891,13 @@ where
         Item::List {
             start: Some(start),
             items,
-        } => column(items.iter().enumerate().map(|(i, items)| {
+        } => column(items.iter().enumerate().map(|(i, bullet)| {
             row![
-                text!("{}.", i as u64 + *start).size(text_size),
-                view(items, settings, style)
+                marker(
+                    bullet,
+                    text!("{}.", i as u64 + *start).size(text_size).into()
+                ),
+                view_with(bullet.items(), settings, style, image)
             ]
             .spacing(spacing)
             .into()
@@ -567,6 +923,35 @@ where
         .padding(spacing.0 / 2.0)
         .class(Theme::code_block())
         .into(),
+        Item::Quote(items) => {
+            container(view_with(items, settings, style, image))
+                .width(Length::Fill)
+                .padding(spacing.0)
+                .class(Theme::quote())
+                .into()
+        }
+        Item::Rule => horizontal_rule(1).into(),
+        Item::Image { url, alt, .. } => image(url).unwrap_or_else(|| {
+            rich_text(alt.spans(style)).size(text_size).into()
+        }),
+        Item::Table {
+            alignments,
+            header,
+            rows,
+        } => {
+            let mut lines = Vec::with_capacity(rows.len() * 2 + 1);
+            lines.push(cells(header, alignments, true));
+
+            for row in rows {
+                lines.push(horizontal_rule(1).into());
+                lines.push(cells(row, alignments, false));
+            }
+
+            container(column(lines))
+                .width(Length::Fill)
+                .class(Theme::table())
+                .into()
+        }
     });
 
     Element::new(column(blocks).width(Length::Fill).spacing(text_size))
@@ -574,14 +959,173 @@ where
 
 /// The theme catalog of Markdown items.
 pub trait Catalog:
-    container::Catalog + scrollable::Catalog + text::Catalog
+    container::Catalog
+    + scrollable::Catalog
+    + text::Catalog
+    + rule::Catalog
+    + checkbox::Catalog
 {
     /// The styling class of a Markdown code block.
     fn code_block<'a>() -> <Self as container::Catalog>::Class<'a>;
+
+    /// The styling class of a Markdown block quote.
+    fn quote<'a>() -> <Self as container::Catalog>::Class<'a> {
+        <Self as container::Catalog>::default()
+    }
+
+    /// The styling class of a Markdown table.
+    fn table<'a>() -> <Self as container::Catalog>::Class<'a> {
+        <Self as container::Catalog>::default()
+    }
 }
 
 impl Catalog for Theme {
     fn code_block<'a>() -> <Self as container::Catalog>::Class<'a> {
-        Box::new(container::dark)
+        Box::new(container::rounded_box)
+    }
+
+    fn quote<'a>() -> <Self as container::Catalog>::Class<'a> {
+        Box::new(quote)
+    }
+
+    fn table<'a>() -> <Self as container::Catalog>::Class<'a> {
+        Box::new(container::bordered_box)
+    }
+}
+
+/// The default style of a Markdown block quote.
+pub fn quote(theme: &Theme) -> container::Style {
+    let palette = theme.extended_palette();
+
+    container::Style {
+        border: Border {
+            width: 1.0,
+            radius: 2.0.into(),
+            color: palette.background.strong.color,
+        },
+        ..container::Style::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn content(text: &Text) -> String {
+        text.spans
+            .iter()
+            .map(|span| match span {
+                Span::Standard { text, .. } => text.as_str(),
+                #[cfg(feature = "highlighter")]
+                Span::Highlight { text, .. } => text.as_str(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn parse_quotes_rules_and_images() {
+        let items: Vec<_> =
+            parse("> A *quote*\n\n---\n\nLook: ![Alt](image.png \"Title\")")
+                .collect();
+
+        let [Item::Quote(quote), Item::Rule, Item::Paragraph(paragraph), Item::Image { url, title, alt }] =
+            items.as_slice()
+        else {
+            panic!("unexpected items: {items:#?}");
+        };
+
+        assert!(matches!(
+            quote.as_slice(),
+            [Item::Paragraph(text)] if content(text) == "A quote"
+        ));
+        assert_eq!(content(paragraph), "Look: ");
+        assert_eq!((url.as_str(), title.as_str()), ("image.png", "Title"));
+        assert_eq!(content(alt), "Alt");
+    }
+
+    #[test]
+    fn parse_tables() {
+        let items: Vec<_> =
+            parse("| Name | Size |\n| ---- | ---: |\n| a    | 1    |")
+                .collect();
+
+        let [Item::Table {
+            alignments,
+            header,
+            rows,
+        }] = items.as_slice()
+        else {
+            panic!("unexpected items: {items:#?}");
+        };
+
+        let contents =
+            |cells: &[Text]| cells.iter().map(content).collect::<Vec<_>>();
+
+        assert_eq!(
+            alignments,
+            &[alignment::Horizontal::Left, alignment::Horizontal::Right]
+        );
+        assert_eq!(contents(header), ["Name", "Size"]);
+        assert_eq!(
+            rows.iter().map(|row| contents(row)).collect::<Vec<_>>(),
+            [["a", "1"]]
+        );
+    }
+
+    #[test]
+    fn parse_task_lists() {
+        let items: Vec<_> =
+            parse("- [x] Done\n- [ ] Pending\n- Plain\n  - Nested").collect();
+
+        let [Item::List {
+            start: None,
+            items: bullets,
+        }] = items.as_slice()
+        else {
+            panic!("unexpected items: {items:#?}");
+        };
+
+        assert!(matches!(bullets[0], Bullet::Task { done: true, .. }));
+        assert!(matches!(bullets[1], Bullet::Task { done: false, .. }));
+        assert!(matches!(
+            bullets[2].items(),
+            [Item::Paragraph(text), Item::List { .. }] if content(text) == "Plain"
+        ));
+    }
+
+    #[test]
+    fn append_content_incrementally() {
+        let markdown =
+            "# Title\n\nSome *text*\n\n- a\n- b\n\n```\ncode\n```\n\nEnd";
+        let style = Style::from_palette(Theme::Light.palette());
+
+        let mut content = Content::new();
+        content.push_str("# Title\n\nSome ");
+
+        let Item::Heading(_, title) = &content.items()[0] else {
+            panic!("title must be a heading");
+        };
+
+        let spans = title.spans(style);
+
+        for chunk in markdown.as_bytes()[content.as_str().len()..]
+            .chunks(3)
+            .map(|chunk| std::str::from_utf8(chunk).expect("ASCII text"))
+        {
+            content.push_str(chunk);
+        }
+
+        let Item::Heading(_, title) = &content.items()[0] else {
+            panic!("title must be a heading");
+        };
+
+        assert!(Rc::ptr_eq(&spans, &title.spans(style)));
+        assert_eq!(content.as_str(), markdown);
+
+        // The cached spans of the title are left out of the comparison
+        assert_eq!(
+            format!("{:?}", &content.items()[1..]),
+            format!("{:?}", &parse(markdown).collect::<Vec<_>>()[1..])
+        );
     }
 }
@@ -483,6 +483,7 @@ where
     ) {
         let style = Catalog::style(theme, self.class);
         let bounds = layout.bounds();
+        let direction = layout.direction();
 
         let text_size =
             self.text_size.unwrap_or_else(|| renderer.default_size());
@@ -529,12 +530,23 @@ where
                     size: text_size,
                     line_height: self.text_line_height,
                     font: self.font.unwrap_or_else(|| renderer.default_font()),
-                    horizontal_alignment: alignment::Horizontal::Left,
+                    horizontal_alignment: direction
+                        .align_x(alignment::Horizontal::Left),
                     vertical_alignment: alignment::Vertical::Center,
                     shaping: self.text_shaping,
                     wrapping: text::Wrapping::default(),
+                    ellipsis: text::Ellipsis::None,
+                    max_lines: None,
+                    paragraph_spacing: Pixels::ZERO,
                 },
-                Point::new(bounds.x + self.padding.left, bounds.center_y()),
+                Point::new(
+                    if direction.is_right_to_left() {
+                        bounds.x + bounds.width - self.padding.left
+                    } else {
+                        bounds.x + self.padding.left
+                    },
+                    bounds.center_y(),
+                ),
                 if is_selected {
                     style.selected_text_color
                 } else {
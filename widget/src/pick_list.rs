@@ -9,7 +9,9 @@ use crate::core::renderer;
 use crate::core::text::paragraph;
 use crate::core::text::{self, Text};
 use crate::core::touch;
+use crate::core::widget::semantics::{Role, Semantics};
 use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Operation;
 use crate::core::{
     Background, Border, Clipboard, Color, Element, Layout, Length, Padding,
     Pixels, Point, Rectangle, Shell, Size, Theme, Vector, Widget,
@@ -49,6 +51,7 @@ pub struct PickList<
     text_shaping: text::Shaping,
     font: Option<Renderer::Font>,
     handle: Handle<Renderer::Font>,
+    semantics: Semantics,
     class: <Theme as Catalog>::Class<'a>,
     menu_class: <Theme as menu::Catalog>::Class<'a>,
 }
@@ -84,6 +87,7 @@ where
             text_shaping: text::Shaping::default(),
             font: None,
             handle: Handle::default(),
+            semantics: Semantics::new(Role::ComboBox),
             class: <Theme as Catalog>::default(),
             menu_class: <Theme as Catalog>::default_menu(),
         }
@@ -152,6 +156,24 @@ where
         self
     }
 
+    /// Sets the label of the [`PickList`] announced by assistive technologies.
+    ///
+    /// By default, its placeholder, if any, is announced.
+    pub fn accessible_label(mut self, label: impl Into<String>) -> Self {
+        self.semantics = self.semantics.label(label);
+        self
+    }
+
+    /// Sets the description of the [`PickList`] announced by assistive
+    /// technologies.
+    pub fn accessible_description(
+        mut self,
+        description: impl Into<String>,
+    ) -> Self {
+        self.semantics = self.semantics.description(description);
+        self
+    }
+
     /// Sets the style of the [`PickList`].
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
@@ -251,6 +273,9 @@ where
             vertical_alignment: alignment::Vertical::Center,
             shaping: self.text_shaping,
             wrapping: text::Wrapping::default(),
+            ellipsis: text::Ellipsis::None,
+            max_lines: None,
+            paragraph_spacing: Pixels::ZERO,
         };
 
         for (option, paragraph) in options.iter().zip(state.options.iter_mut())
@@ -303,6 +328,40 @@ where
         layout::Node::new(size)
     }
 
+    fn semantics(
+        &self,
+        _tree: &Tree,
+        _layout: Layout<'_>,
+    ) -> Option<Semantics> {
+        Some(match &self.placeholder {
+            Some(placeholder) => {
+                self.semantics.clone().or_label(placeholder.as_str())
+            }
+            None => self.semantics.clone(),
+        })
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.kind(None, layout.bounds(), "pick_list");
+        if let Some(semantics) = self.semantics(tree, layout) {
+            operation.semantics(None, layout.bounds(), &semantics);
+        }
+
+        if let Some(selected) = &self.selected {
+            operation.text(
+                None,
+                layout.bounds(),
+                &selected.borrow().to_string(),
+            );
+        }
+    }
+
     fn on_event(
         &mut self,
         tree: &mut Tree,
@@ -441,6 +500,7 @@ where
         let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
 
         let bounds = layout.bounds();
+        let direction = layout.direction();
         let is_mouse_over = cursor.is_over(bounds);
         let is_selected = selected.is_some();
 
@@ -513,13 +573,21 @@ where
                         bounds.width,
                         f32::from(line_height.to_absolute(size)),
                     ),
-                    horizontal_alignment: alignment::Horizontal::Right,
+                    horizontal_alignment: direction
+                        .align_x(alignment::Horizontal::Right),
                     vertical_alignment: alignment::Vertical::Center,
                     shaping,
                     wrapping: text::Wrapping::default(),
+                    ellipsis: text::Ellipsis::None,
+                    max_lines: None,
+                    paragraph_spacing: Pixels::ZERO,
                 },
                 Point::new(
-                    bounds.x + bounds.width - self.padding.right,
+                    if direction.is_right_to_left() {
+                        bounds.x + self.padding.right
+                    } else {
+                        bounds.x + bounds.width - self.padding.right
+                    },
                     bounds.center_y(),
                 ),
                 style.handle_color,
@@ -543,12 +611,23 @@ where
                         bounds.width - self.padding.horizontal(),
                         f32::from(self.text_line_height.to_absolute(text_size)),
                     ),
-                    horizontal_alignment: alignment::Horizontal::Left,
+                    horizontal_alignment: direction
+                        .align_x(alignment::Horizontal::Left),
                     vertical_alignment: alignment::Vertical::Center,
                     shaping: self.text_shaping,
                     wrapping: text::Wrapping::default(),
+                    ellipsis: text::Ellipsis::None,
+                    max_lines: None,
+                    paragraph_spacing: Pixels::ZERO,
                 },
-                Point::new(bounds.x + self.padding.left, bounds.center_y()),
+                Point::new(
+                    if direction.is_right_to_left() {
+                        bounds.x + bounds.width - self.padding.left
+                    } else {
+                        bounds.x + self.padding.left
+                    },
+                    bounds.center_y(),
+                ),
                 if is_selected {
                     style.text_color
                 } else {
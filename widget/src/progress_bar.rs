@@ -3,7 +3,8 @@ use crate::core::border::{self, Border};
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
-use crate::core::widget::Tree;
+use crate::core::widget::semantics::{self, Role, Semantics};
+use crate::core::widget::{Operation, Tree};
 use crate::core::{
     self, Background, Element, Layout, Length, Rectangle, Size, Theme, Widget,
 };
@@ -31,6 +32,7 @@ where
     value: f32,
     width: Length,
     height: Option<Length>,
+    semantics: Semantics,
     class: Theme::Class<'a>,
 }
 
@@ -52,6 +54,7 @@ where
             range,
             width: Length::Fill,
             height: None,
+            semantics: Semantics::new(Role::ProgressIndicator),
             class: Theme::default(),
         }
     }
@@ -68,6 +71,22 @@ where
         self
     }
 
+    /// Sets the label of the [`ProgressBar`] announced by assistive technologies.
+    pub fn accessible_label(mut self, label: impl Into<String>) -> Self {
+        self.semantics = self.semantics.label(label);
+        self
+    }
+
+    /// Sets the description of the [`ProgressBar`] announced by assistive
+    /// technologies.
+    pub fn accessible_description(
+        mut self,
+        description: impl Into<String>,
+    ) -> Self {
+        self.semantics = self.semantics.description(description);
+        self
+    }
+
     /// Sets the style of the [`ProgressBar`].
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
@@ -113,6 +132,34 @@ where
         )
     }
 
+    fn semantics(
+        &self,
+        _tree: &Tree,
+        _layout: Layout<'_>,
+    ) -> Option<Semantics> {
+        Some(self.semantics.clone().value(semantics::Value {
+            current: f64::from(self.value),
+            min: f64::from(*self.range.start()),
+            max: f64::from(*self.range.end()),
+            step: None,
+        }))
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let semantics =
+            Widget::<Message, Theme, Renderer>::semantics(self, tree, layout);
+
+        if let Some(semantics) = semantics {
+            operation.semantics(None, layout.bounds(), &semantics);
+        }
+    }
+
     fn draw(
         &self,
         _state: &Tree,
@@ -148,6 +195,11 @@ where
             renderer.fill_quad(
                 renderer::Quad {
                     bounds: Rectangle {
+                        x: if layout.direction().is_right_to_left() {
+                            bounds.x + bounds.width - active_progress_width
+                        } else {
+                            bounds.x
+                        },
                         width: active_progress_width,
                         ..bounds
                     },
@@ -8,6 +8,7 @@ use crate::core::renderer;
 use crate::core::text;
 use crate::core::touch;
 use crate::core::widget;
+use crate::core::widget::semantics::{Role, Semantics};
 use crate::core::widget::tree::{self, Tree};
 use crate::core::{
     Background, Clipboard, Color, Element, Layout, Length, Pixels, Rectangle,
@@ -84,6 +85,7 @@ where
     text_shaping: text::Shaping,
     text_wrapping: text::Wrapping,
     font: Option<Renderer::Font>,
+    semantics: Semantics,
     class: Theme::Class<'a>,
 }
 
@@ -129,6 +131,7 @@ where
             text_shaping: text::Shaping::default(),
             text_wrapping: text::Wrapping::default(),
             font: None,
+            semantics: Semantics::new(Role::RadioButton),
             class: Theme::default(),
         }
     }
@@ -184,6 +187,24 @@ where
         self
     }
 
+    /// Sets the label of the [`Radio`] announced by assistive technologies.
+    ///
+    /// By default, its visible label is announced.
+    pub fn accessible_label(mut self, label: impl Into<String>) -> Self {
+        self.semantics = self.semantics.label(label);
+        self
+    }
+
+    /// Sets the description of the [`Radio`] announced by assistive
+    /// technologies.
+    pub fn accessible_description(
+        mut self,
+        description: impl Into<String>,
+    ) -> Self {
+        self.semantics = self.semantics.description(description);
+        self
+    }
+
     /// Sets the style of the [`Radio`] button.
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
@@ -254,11 +275,41 @@ where
                     alignment::Vertical::Top,
                     self.text_shaping,
                     self.text_wrapping,
+                    text::Ellipsis::None,
+                    None,
+                    Pixels::ZERO,
                 )
             },
         )
     }
 
+    fn semantics(
+        &self,
+        _tree: &Tree,
+        _layout: Layout<'_>,
+    ) -> Option<Semantics> {
+        Some(
+            self.semantics
+                .clone()
+                .or_label(self.label.clone())
+                .toggled(self.is_selected),
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        operation.kind(None, layout.bounds(), "radio");
+        if let Some(semantics) = self.semantics(tree, layout) {
+            operation.semantics(None, layout.bounds(), &semantics);
+        }
+        operation.text(None, layout.bounds(), &self.label);
+    }
+
     fn on_event(
         &mut self,
         _state: &mut Tree,
@@ -220,6 +220,7 @@ where
         renderer: &Renderer,
         operation: &mut dyn Operation,
     ) {
+        operation.kind(None, layout.bounds(), "row");
         operation.container(None, layout.bounds(), &mut |operation| {
             self.children
                 .iter()
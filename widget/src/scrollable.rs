@@ -12,8 +12,8 @@ use crate::core::widget;
 use crate::core::widget::operation::{self, Operation};
 use crate::core::widget::tree::{self, Tree};
 use crate::core::{
-    self, Background, Clipboard, Color, Element, Layout, Length, Padding,
-    Pixels, Point, Rectangle, Shell, Size, Theme, Vector, Widget,
+    self, Background, Clipboard, Color, Element, InputMethod, Layout, Length,
+    Padding, Pixels, Point, Rectangle, Shell, Size, Theme, Vector, Widget,
 };
 use crate::runtime::task::{self, Task};
 use crate::runtime::Action;
@@ -242,6 +242,26 @@ impl Direction {
             Self::Horizontal(_) => None,
         }
     }
+
+    /// Flips the [`Anchor`] of the horizontal [`Scrollbar`], if any, when
+    /// laid out right to left; so the scroller starts at the right.
+    fn align(mut self, layout: layout::Direction) -> Self {
+        if layout.is_right_to_left() {
+            if let Self::Horizontal(scrollbar)
+            | Self::Both {
+                horizontal: scrollbar,
+                ..
+            } = &mut self
+            {
+                scrollbar.alignment = match scrollbar.alignment {
+                    Anchor::Start => Anchor::End,
+                    Anchor::End => Anchor::Start,
+                };
+            }
+        }
+
+        self
+    }
 }
 
 impl Default for Direction {
@@ -420,11 +440,12 @@ where
         let state = tree.state.downcast_mut::<State>();
 
         let bounds = layout.bounds();
+        let direction = self.direction.align(layout.direction());
         let content_layout = layout.children().next().unwrap();
         let content_bounds = content_layout.bounds();
-        let translation =
-            state.translation(self.direction, bounds, content_bounds);
+        let translation = state.translation(direction, bounds, content_bounds);
 
+        operation.kind(self.id.as_ref().map(|id| &id.0), bounds, "scrollable");
         operation.scrollable(
             state,
             self.id.as_ref().map(|id| &id.0),
@@ -459,13 +480,19 @@ where
     ) -> event::Status {
         let state = tree.state.downcast_mut::<State>();
         let bounds = layout.bounds();
+        let direction = self.direction.align(layout.direction());
         let cursor_over_scrollable = cursor.position_over(bounds);
 
         let content = layout.children().next().unwrap();
         let content_bounds = content.bounds();
 
-        let scrollbars =
-            Scrollbars::new(state, self.direction, bounds, content_bounds);
+        let scrollbars = Scrollbars::new(
+            state,
+            direction,
+            bounds,
+            content_bounds,
+            layout.direction(),
+        );
 
         let (mouse_over_y_scrollbar, mouse_over_x_scrollbar) =
             scrollbars.is_mouse_over(cursor);
@@ -620,7 +647,7 @@ where
                     mouse::Cursor::Available(
                         cursor_position
                             + state.translation(
-                                self.direction,
+                                direction,
                                 bounds,
                                 content_bounds,
                             ),
@@ -630,9 +657,11 @@ where
             };
 
             let translation =
-                state.translation(self.direction, bounds, content_bounds);
+                state.translation(direction, bounds, content_bounds);
+
+            let had_input_method = shell.input_method().is_enabled();
 
-            self.content.as_widget_mut().on_event(
+            let status = self.content.as_widget_mut().on_event(
                 &mut tree.children[0],
                 event.clone(),
                 content,
@@ -645,7 +674,17 @@ where
                     x: bounds.x + translation.x,
                     ..bounds
                 },
-            )
+            );
+
+            if !had_input_method {
+                if let InputMethod::Enabled { cursor, .. } =
+                    shell.input_method_mut()
+                {
+                    *cursor = *cursor - translation;
+                }
+            }
+
+            status
         };
 
         if matches!(
@@ -697,7 +736,7 @@ where
                     mouse::ScrollDelta::Pixels { x, y } => Vector::new(x, y),
                 };
 
-                state.scroll(delta, self.direction, bounds, content_bounds);
+                state.scroll(delta, direction, bounds, content_bounds);
 
                 event_status = if notify_on_scroll(
                     state,
@@ -739,7 +778,7 @@ where
 
                             state.scroll(
                                 delta,
-                                self.direction,
+                                direction,
                                 bounds,
                                 content_bounds,
                             );
@@ -781,6 +820,7 @@ where
         let state = tree.state.downcast_ref::<State>();
 
         let bounds = layout.bounds();
+        let direction = self.direction.align(layout.direction());
         let content_layout = layout.children().next().unwrap();
         let content_bounds = content_layout.bounds();
 
@@ -788,15 +828,19 @@ where
             return;
         };
 
-        let scrollbars =
-            Scrollbars::new(state, self.direction, bounds, content_bounds);
+        let scrollbars = Scrollbars::new(
+            state,
+            direction,
+            bounds,
+            content_bounds,
+            layout.direction(),
+        );
 
         let cursor_over_scrollable = cursor.position_over(bounds);
         let (mouse_over_y_scrollbar, mouse_over_x_scrollbar) =
             scrollbars.is_mouse_over(cursor);
 
-        let translation =
-            state.translation(self.direction, bounds, content_bounds);
+        let translation = state.translation(direction, bounds, content_bounds);
 
         let cursor = match cursor_over_scrollable {
             Some(cursor_position)
@@ -967,13 +1011,19 @@ where
     ) -> mouse::Interaction {
         let state = tree.state.downcast_ref::<State>();
         let bounds = layout.bounds();
+        let direction = self.direction.align(layout.direction());
         let cursor_over_scrollable = cursor.position_over(bounds);
 
         let content_layout = layout.children().next().unwrap();
         let content_bounds = content_layout.bounds();
 
-        let scrollbars =
-            Scrollbars::new(state, self.direction, bounds, content_bounds);
+        let scrollbars = Scrollbars::new(
+            state,
+            direction,
+            bounds,
+            content_bounds,
+            layout.direction(),
+        );
 
         let (mouse_over_y_scrollbar, mouse_over_x_scrollbar) =
             scrollbars.is_mouse_over(cursor);
@@ -984,7 +1034,7 @@ where
             mouse::Interaction::None
         } else {
             let translation =
-                state.translation(self.direction, bounds, content_bounds);
+                state.translation(direction, bounds, content_bounds);
 
             let cursor = match cursor_over_scrollable {
                 Some(cursor_position)
@@ -1017,11 +1067,12 @@ where
         translation: Vector,
     ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
         let bounds = layout.bounds();
+        let direction = self.direction.align(layout.direction());
         let content_layout = layout.children().next().unwrap();
         let content_bounds = content_layout.bounds();
 
         let offset = tree.state.downcast_ref::<State>().translation(
-            self.direction,
+            direction,
             bounds,
             content_bounds,
         );
@@ -1088,6 +1139,12 @@ pub fn scroll_to<T>(id: Id, offset: AbsoluteOffset) -> Task<T> {
     )))
 }
 
+/// Returns how much the contents of a [`Scrollable`] laid out right to left
+/// overflow its bounds to the left, as a negative amount.
+fn overflow_x(bounds: Rectangle, content_bounds: Rectangle) -> f32 {
+    (content_bounds.x - bounds.x).min(0.0)
+}
+
 /// Returns [`true`] if the viewport actually changed.
 fn notify_on_scroll<Message>(
     state: &mut State,
@@ -1380,7 +1437,7 @@ impl State {
                     bounds.width,
                     content_bounds.width,
                     horizontal.alignment,
-                )
+                ) + overflow_x(bounds, content_bounds)
             } else {
                 0.0
             },
@@ -1417,9 +1474,19 @@ impl Scrollbars {
         direction: Direction,
         bounds: Rectangle,
         content_bounds: Rectangle,
+        layout: layout::Direction,
     ) -> Self {
         let translation = state.translation(direction, bounds, content_bounds);
 
+        // The vertical scrollbar is on the left when laid out right to left
+        let y_scrollbar_x = |inset: f32, width: f32| {
+            if layout.is_right_to_left() {
+                bounds.x + inset
+            } else {
+                bounds.x + bounds.width - inset - width
+            }
+        };
+
         let show_scrollbar_x = direction.horizontal().filter(|scrollbar| {
             scrollbar.spacing.is_some() || content_bounds.width > bounds.width
         });
@@ -1446,7 +1513,7 @@ impl Scrollbars {
 
             // Total bounds of the scrollbar + margin + scroller width
             let total_scrollbar_bounds = Rectangle {
-                x: bounds.x + bounds.width - total_scrollbar_width,
+                x: y_scrollbar_x(0.0, total_scrollbar_width),
                 y: bounds.y,
                 width: total_scrollbar_width,
                 height: (bounds.height - x_scrollbar_height).max(0.0),
@@ -1454,9 +1521,10 @@ impl Scrollbars {
 
             // Bounds of just the scrollbar
             let scrollbar_bounds = Rectangle {
-                x: bounds.x + bounds.width
-                    - total_scrollbar_width / 2.0
-                    - width / 2.0,
+                x: y_scrollbar_x(
+                    total_scrollbar_width / 2.0 - width / 2.0,
+                    width,
+                ),
                 y: bounds.y,
                 width,
                 height: (bounds.height - x_scrollbar_height).max(0.0),
@@ -1475,9 +1543,10 @@ impl Scrollbars {
                         / bounds.height;
 
                 let scroller_bounds = Rectangle {
-                    x: bounds.x + bounds.width
-                        - total_scrollbar_width / 2.0
-                        - scroller_width / 2.0,
+                    x: y_scrollbar_x(
+                        total_scrollbar_width / 2.0 - scroller_width / 2.0,
+                        scroller_width,
+                    ),
                     y: (scrollbar_bounds.y + scroller_offset).max(0.0),
                     width: scroller_width,
                     height: scroller_height,
@@ -1511,12 +1580,18 @@ impl Scrollbars {
             let scrollbar_y_width = y_scrollbar
                 .map_or(0.0, |scrollbar| scrollbar.total_bounds.width);
 
+            let scrollbar_x = if layout.is_right_to_left() {
+                bounds.x + scrollbar_y_width
+            } else {
+                bounds.x
+            };
+
             let total_scrollbar_height =
                 width.max(scroller_width) + 2.0 * margin;
 
             // Total bounds of the scrollbar + margin + scroller width
             let total_scrollbar_bounds = Rectangle {
-                x: bounds.x,
+                x: scrollbar_x,
                 y: bounds.y + bounds.height - total_scrollbar_height,
                 width: (bounds.width - scrollbar_y_width).max(0.0),
                 height: total_scrollbar_height,
@@ -1524,7 +1599,7 @@ impl Scrollbars {
 
             // Bounds of just the scrollbar
             let scrollbar_bounds = Rectangle {
-                x: bounds.x,
+                x: scrollbar_x,
                 y: bounds.y + bounds.height
                     - total_scrollbar_height / 2.0
                     - width / 2.0,
@@ -1539,9 +1614,11 @@ impl Scrollbars {
             } else {
                 // min width for easier grabbing with extra wide content
                 let scroller_length = (scrollbar_bounds.width * ratio).max(2.0);
-                let scroller_offset =
-                    translation.x * ratio * scrollbar_bounds.width
-                        / bounds.width;
+                let scroller_offset = (translation.x
+                    - overflow_x(bounds, content_bounds))
+                    * ratio
+                    * scrollbar_bounds.width
+                    / bounds.width;
 
                 let scroller_bounds = Rectangle {
                     x: (scrollbar_bounds.x + scroller_offset).max(0.0),
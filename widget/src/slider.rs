@@ -7,7 +7,9 @@ use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::touch;
+use crate::core::widget::semantics::{self, Role, Semantics};
 use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Operation;
 use crate::core::{
     self, Clipboard, Color, Element, Layout, Length, Pixels, Point, Rectangle,
     Shell, Size, Theme, Widget,
@@ -52,6 +54,7 @@ where
     on_release: Option<Message>,
     width: Length,
     height: f32,
+    semantics: Semantics,
     class: Theme::Class<'a>,
 }
 
@@ -98,6 +101,7 @@ where
             on_release: None,
             width: Length::Fill,
             height: Self::DEFAULT_HEIGHT,
+            semantics: Semantics::new(Role::Slider),
             class: Theme::default(),
         }
     }
@@ -147,6 +151,22 @@ where
         self
     }
 
+    /// Sets the label of the [`Slider`] announced by assistive technologies.
+    pub fn accessible_label(mut self, label: impl Into<String>) -> Self {
+        self.semantics = self.semantics.label(label);
+        self
+    }
+
+    /// Sets the description of the [`Slider`] announced by assistive
+    /// technologies.
+    pub fn accessible_description(
+        mut self,
+        description: impl Into<String>,
+    ) -> Self {
+        self.semantics = self.semantics.description(description);
+        self
+    }
+
     /// Sets the style of the [`Slider`].
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
@@ -198,6 +218,36 @@ where
         layout::atomic(limits, self.width, self.height)
     }
 
+    fn semantics(
+        &self,
+        _tree: &Tree,
+        _layout: Layout<'_>,
+    ) -> Option<Semantics> {
+        Some(self.semantics.clone().value(semantics::Value {
+            current: self.value.into(),
+            min: (*self.range.start()).into(),
+            max: (*self.range.end()).into(),
+            step: Some(self.step.into()),
+        }))
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.kind(None, layout.bounds(), "slider");
+
+        let semantics =
+            Widget::<Message, Theme, Renderer>::semantics(self, tree, layout);
+
+        if let Some(semantics) = semantics {
+            operation.semantics(None, layout.bounds(), &semantics);
+        }
+    }
+
     fn on_event(
         &mut self,
         tree: &mut Tree,
@@ -216,9 +266,17 @@ where
 
         let locate = |cursor_position: Point| -> Option<T> {
             let bounds = layout.bounds();
-            let new_value = if cursor_position.x <= bounds.x {
+
+            // The range starts on the right in right-to-left layouts
+            let x = if layout.direction().is_right_to_left() {
+                2.0 * bounds.x + bounds.width - cursor_position.x
+            } else {
+                cursor_position.x
+            };
+
+            let new_value = if x <= bounds.x {
                 Some(*self.range.start())
-            } else if cursor_position.x >= bounds.x + bounds.width {
+            } else if x >= bounds.x + bounds.width {
                 Some(*self.range.end())
             } else {
                 let step = if state.keyboard_modifiers.shift() {
@@ -231,8 +289,7 @@ where
                 let start = (*self.range.start()).into();
                 let end = (*self.range.end()).into();
 
-                let percent = f64::from(cursor_position.x - bounds.x)
-                    / f64::from(bounds.width);
+                let percent = f64::from(x - bounds.x) / f64::from(bounds.width);
 
                 let steps = (percent * (end - start) / step).round();
                 let value = steps * step + start;
@@ -400,14 +457,27 @@ where
 
         let rail_y = bounds.y + bounds.height / 2.0;
 
+        let mirror = |rectangle: Rectangle| {
+            if layout.direction().is_right_to_left() {
+                Rectangle {
+                    x: 2.0 * bounds.x + bounds.width
+                        - rectangle.x
+                        - rectangle.width,
+                    ..rectangle
+                }
+            } else {
+                rectangle
+            }
+        };
+
         renderer.fill_quad(
             renderer::Quad {
-                bounds: Rectangle {
+                bounds: mirror(Rectangle {
                     x: bounds.x,
                     y: rail_y - style.rail.width / 2.0,
                     width: offset + handle_width / 2.0,
                     height: style.rail.width,
-                },
+                }),
                 border: border::rounded(style.rail.border_radius),
                 ..renderer::Quad::default()
             },
@@ -416,12 +486,12 @@ where
 
         renderer.fill_quad(
             renderer::Quad {
-                bounds: Rectangle {
+                bounds: mirror(Rectangle {
                     x: bounds.x + offset + handle_width / 2.0,
                     y: rail_y - style.rail.width / 2.0,
                     width: bounds.width - offset - handle_width / 2.0,
                     height: style.rail.width,
-                },
+                }),
                 border: border::rounded(style.rail.border_radius),
                 ..renderer::Quad::default()
             },
@@ -430,12 +500,12 @@ where
 
         renderer.fill_quad(
             renderer::Quad {
-                bounds: Rectangle {
+                bounds: mirror(Rectangle {
                     x: bounds.x + offset,
                     y: rail_y - handle_height / 2.0,
                     width: handle_width,
                     height: handle_height,
-                },
+                }),
                 border: Border {
                     radius: handle_border_radius,
                     width: style.handle.border_width,
@@ -191,6 +191,7 @@ where
         renderer: &Renderer,
         operation: &mut dyn Operation,
     ) {
+        operation.kind(None, layout.bounds(), "stack");
         operation.container(None, layout.bounds(), &mut |operation| {
             self.children
                 .iter()
@@ -3,6 +3,7 @@ use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::svg;
+use crate::core::widget::semantics::{Role, Semantics};
 use crate::core::widget::Tree;
 use crate::core::{
     Color, ContentFit, Element, Layout, Length, Point, Rectangle, Rotation,
@@ -28,6 +29,7 @@ where
     width: Length,
     height: Length,
     content_fit: ContentFit,
+    semantics: Semantics,
     class: Theme::Class<'a>,
     rotation: Rotation,
     opacity: f32,
@@ -44,6 +46,7 @@ where
             width: Length::Fill,
             height: Length::Shrink,
             content_fit: ContentFit::Contain,
+            semantics: Semantics::new(Role::Image),
             class: Theme::default(),
             rotation: Rotation::default(),
             opacity: 1.0,
@@ -82,6 +85,22 @@ where
         }
     }
 
+    /// Sets the label of the [`Svg`] announced by assistive technologies.
+    pub fn accessible_label(mut self, label: impl Into<String>) -> Self {
+        self.semantics = self.semantics.label(label);
+        self
+    }
+
+    /// Sets the description of the [`Svg`] announced by assistive
+    /// technologies.
+    pub fn accessible_description(
+        mut self,
+        description: impl Into<String>,
+    ) -> Self {
+        self.semantics = self.semantics.description(description);
+        self
+    }
+
     /// Sets the style of the [`Svg`].
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
@@ -163,6 +182,14 @@ where
         layout::Node::new(final_size)
     }
 
+    fn semantics(
+        &self,
+        _tree: &Tree,
+        _layout: Layout<'_>,
+    ) -> Option<Semantics> {
+        Some(self.semantics.clone())
+    }
+
     fn draw(
         &self,
         _state: &Tree,
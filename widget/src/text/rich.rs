@@ -5,9 +5,11 @@ use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::text::{Paragraph, Span};
 use crate::core::widget::text::{
-    self, Catalog, LineHeight, Shaping, Style, StyleFn, Wrapping,
+    self, Catalog, Ellipsis, LineHeight, Selection, Shaping, Style, StyleFn,
+    Wrapping,
 };
 use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Operation;
 use crate::core::{
     self, Clipboard, Color, Element, Event, Layout, Length, Pixels, Point,
     Rectangle, Shell, Size, Vector, Widget,
@@ -30,6 +32,8 @@ where
     align_x: alignment::Horizontal,
     align_y: alignment::Vertical,
     wrapping: Wrapping,
+    paragraph_spacing: Pixels,
+    selectable: bool,
     class: Theme::Class<'a>,
 }
 
@@ -52,6 +56,8 @@ where
             align_x: alignment::Horizontal::Left,
             align_y: alignment::Vertical::Top,
             wrapping: Wrapping::default(),
+            paragraph_spacing: Pixels::ZERO,
+            selectable: false,
             class: Theme::default(),
         }
     }
@@ -126,6 +132,24 @@ where
         self
     }
 
+    /// Sets the spacing between the paragraphs of the [`Rich`] text.
+    ///
+    /// Paragraphs are separated by line breaks in the text of the spans.
+    pub fn paragraph_spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.paragraph_spacing = spacing.into();
+        self
+    }
+
+    /// Sets whether the contents of the [`Rich`] text can be selected and
+    /// copied.
+    ///
+    /// A selectable [`Rich`] text can be selected with the mouse, just like
+    /// a selectable [`Text`](crate::Text). Links keep working as usual.
+    pub fn selectable(mut self, selectable: bool) -> Self {
+        self.selectable = selectable;
+        self
+    }
+
     /// Sets the default style of the [`Rich`] text.
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
@@ -179,6 +203,8 @@ struct State<Link, P: Paragraph> {
     spans: Vec<Span<'static, Link, P::Font>>,
     span_pressed: Option<usize>,
     paragraph: P,
+    content: String,
+    selection: Selection,
 }
 
 impl<'a, Link, Theme, Renderer> Widget<Link, Theme, Renderer>
@@ -197,6 +223,8 @@ where
             spans: Vec::new(),
             span_pressed: None,
             paragraph: Renderer::Paragraph::default(),
+            content: String::new(),
+            selection: Selection::default(),
         })
     }
 
@@ -227,6 +255,7 @@ where
             self.align_x,
             self.align_y,
             self.wrapping,
+            self.paragraph_spacing,
         )
     }
 
@@ -246,6 +275,16 @@ where
 
         let style = theme.style(&self.class);
 
+        if self.selectable {
+            state.selection.draw(
+                renderer,
+                &state.paragraph,
+                &state.content,
+                layout,
+                style.color.unwrap_or(defaults.text_color).scale_alpha(0.3),
+            );
+        }
+
         let hovered_span = cursor
             .position_in(layout.bounds())
             .and_then(|position| state.paragraph.hit_span(position));
@@ -355,6 +394,25 @@ where
         );
     }
 
+    fn operate(
+        &self,
+        _tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let text: String = self
+            .spans
+            .as_ref()
+            .as_ref()
+            .iter()
+            .map(|span| span.text.as_ref())
+            .collect();
+
+        operation.kind(None, layout.bounds(), "rich_text");
+        operation.text(None, layout.bounds(), &text);
+    }
+
     fn on_event(
         &mut self,
         tree: &mut Tree,
@@ -362,11 +420,11 @@ where
         layout: Layout<'_>,
         cursor: mouse::Cursor,
         _renderer: &Renderer,
-        _clipboard: &mut dyn Clipboard,
+        clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Link>,
         _viewport: &Rectangle,
     ) -> event::Status {
-        match event {
+        match &event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
                 if let Some(position) = cursor.position_in(layout.bounds()) {
                     let state = tree
@@ -410,6 +468,21 @@ where
             _ => {}
         }
 
+        if self.selectable {
+            let state = tree
+                .state
+                .downcast_mut::<State<Link, Renderer::Paragraph>>();
+
+            return state.selection.update(
+                &event,
+                layout,
+                cursor,
+                &state.paragraph,
+                &state.content,
+                clipboard,
+            );
+        }
+
         event::Status::Ignored
     }
 
@@ -421,11 +494,11 @@ where
         _viewport: &Rectangle,
         _renderer: &Renderer,
     ) -> mouse::Interaction {
-        if let Some(position) = cursor.position_in(layout.bounds()) {
-            let state = tree
-                .state
-                .downcast_ref::<State<Link, Renderer::Paragraph>>();
+        let state = tree
+            .state
+            .downcast_ref::<State<Link, Renderer::Paragraph>>();
 
+        if let Some(position) = cursor.position_in(layout.bounds()) {
             if let Some(span) = state
                 .paragraph
                 .hit_span(position)
@@ -437,6 +510,13 @@ where
             }
         }
 
+        if self.selectable
+            && (cursor.is_over(layout.bounds())
+                || state.selection.is_dragging())
+        {
+            return mouse::Interaction::Text;
+        }
+
         mouse::Interaction::None
     }
 }
@@ -454,6 +534,7 @@ fn layout<Link, Renderer>(
     horizontal_alignment: alignment::Horizontal,
     vertical_alignment: alignment::Vertical,
     wrapping: Wrapping,
+    paragraph_spacing: Pixels,
 ) -> layout::Node
 where
     Link: Clone,
@@ -475,12 +556,17 @@ where
             vertical_alignment,
             shaping: Shaping::Advanced,
             wrapping,
+            ellipsis: Ellipsis::None,
+            max_lines: None,
+            paragraph_spacing,
         };
 
         if state.spans != spans {
             state.paragraph =
                 Renderer::Paragraph::with_spans(text_with_spans());
             state.spans = spans.iter().cloned().map(Span::to_static).collect();
+            state.content =
+                spans.iter().map(|span| span.text.as_ref()).collect();
         } else {
             match state.paragraph.compare(core::Text {
                 content: (),
@@ -492,6 +578,9 @@ where
                 vertical_alignment,
                 shaping: Shaping::Advanced,
                 wrapping,
+                ellipsis: Ellipsis::None,
+                max_lines: None,
+                paragraph_spacing,
             }) {
                 core::text::Difference::None => {}
                 core::text::Difference::Bounds => {
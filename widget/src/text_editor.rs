@@ -1,7 +1,13 @@
 //! Display a multi-line text input for text editing.
+mod history;
+
+pub mod search;
+
 use crate::core::alignment;
+use crate::core::border;
 use crate::core::clipboard::{self, Clipboard};
 use crate::core::event::{self, Event};
+use crate::core::input_method::{self, InputMethod};
 use crate::core::keyboard;
 use crate::core::keyboard::key;
 use crate::core::layout::{self, Layout};
@@ -9,9 +15,11 @@ use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::text::editor::{Cursor, Editor as _};
 use crate::core::text::highlighter::{self, Highlighter};
-use crate::core::text::{self, LineHeight, Text, Wrapping};
+use crate::core::text::{self, LineHeight, Paragraph as _, Text, Wrapping};
+use crate::core::theme::palette;
 use crate::core::time::{Duration, Instant};
 use crate::core::widget::operation;
+use crate::core::widget::semantics::{Role, Semantics};
 use crate::core::widget::{self, Widget};
 use crate::core::window;
 use crate::core::{
@@ -19,12 +27,14 @@ use crate::core::{
     Rectangle, Shell, Size, SmolStr, Theme, Vector,
 };
 
+use history::History;
+
 use std::cell::RefCell;
 use std::fmt;
 use std::ops::DerefMut;
 use std::sync::Arc;
 
-pub use text::editor::{Action, Edit, Motion};
+pub use text::editor::{Action, Edit, Indentation, Motion, Position};
 
 /// A multi-line text input.
 #[allow(missing_debug_implementations)]
@@ -48,9 +58,14 @@ pub struct TextEditor<
     height: Length,
     padding: Padding,
     wrapping: Wrapping,
+    semantics: Semantics,
     class: Theme::Class<'a>,
     key_binding: Option<Box<dyn Fn(KeyPress) -> Option<Binding<Message>> + 'a>>,
+    editing: Editing<'a>,
     on_edit: Option<Box<dyn Fn(Action) -> Message + 'a>>,
+    line_numbers: bool,
+    markers: Option<Box<dyn Fn(usize) -> Option<Marker<Renderer::Font>> + 'a>>,
+    on_gutter_press: Option<Box<dyn Fn(usize) -> Message + 'a>>,
     highlighter_settings: Highlighter::Settings,
     highlighter_format: fn(
         &Highlighter::Highlight,
@@ -76,9 +91,14 @@ where
             height: Length::Shrink,
             padding: Padding::new(5.0),
             wrapping: Wrapping::default(),
+            semantics: Semantics::new(Role::MultilineTextInput),
             class: Theme::default(),
             key_binding: None,
+            editing: Editing::default(),
             on_edit: None,
+            line_numbers: false,
+            markers: None,
+            on_gutter_press: None,
             highlighter_settings: (),
             highlighter_format: |_highlight, _theme| {
                 highlighter::Format::default()
@@ -156,7 +176,43 @@ where
         self
     }
 
+    /// Sets whether the [`TextEditor`] should display the number of every
+    /// line in its gutter.
+    pub fn line_numbers(mut self, line_numbers: bool) -> Self {
+        self.line_numbers = line_numbers;
+        self
+    }
+
+    /// Sets the closure that produces the [`Marker`] displayed in the gutter
+    /// of the [`TextEditor`] for every line, if any.
+    ///
+    /// The closure receives the index of the line.
+    pub fn markers(
+        mut self,
+        markers: impl Fn(usize) -> Option<Marker<Renderer::Font>> + 'a,
+    ) -> Self {
+        self.markers = Some(Box::new(markers));
+        self
+    }
+
+    /// Sets the message that should be produced when the gutter of the
+    /// [`TextEditor`] is pressed next to a line.
+    ///
+    /// The closure receives the index of the line.
+    pub fn on_gutter_press(
+        mut self,
+        on_gutter_press: impl Fn(usize) -> Message + 'a,
+    ) -> Self {
+        self.on_gutter_press = Some(Box::new(on_gutter_press));
+        self
+    }
+
     /// Highlights the [`TextEditor`] using the given syntax and theme.
+    ///
+    /// A [`Theme::Palette`] follows the colors of the current theme of
+    /// the application, as given by [`Catalog::highlight_palette`].
+    ///
+    /// [`Theme::Palette`]: iced_highlighter::Theme::Palette
     #[cfg(feature = "highlighter")]
     pub fn highlight(
         self,
@@ -171,7 +227,10 @@ where
                 theme,
                 token: syntax.to_owned(),
             },
-            |highlight, _theme| highlight.to_format(),
+            |highlight, theme| match theme.highlight_palette() {
+                Some(palette) => highlight.to_format_with(palette),
+                None => highlight.to_format(),
+            },
         )
     }
 
@@ -195,9 +254,14 @@ where
             height: self.height,
             padding: self.padding,
             wrapping: self.wrapping,
+            semantics: self.semantics,
             class: self.class,
             key_binding: self.key_binding,
+            editing: self.editing,
             on_edit: self.on_edit,
+            line_numbers: self.line_numbers,
+            markers: self.markers,
+            on_gutter_press: self.on_gutter_press,
             highlighter_settings: settings,
             highlighter_format: to_format,
         }
@@ -214,6 +278,79 @@ where
         self
     }
 
+    /// Sets whether the [`TextEditor`] is read-only.
+    ///
+    /// A read-only [`TextEditor`] never produces edits, but its contents can
+    /// still be navigated, selected, and copied with the actions produced
+    /// by [`on_action`](Self::on_action).
+    pub fn read_only(mut self, read_only: bool) -> Self {
+        self.editing.read_only = read_only;
+        self
+    }
+
+    /// Sets the [`Indentation`] inserted and removed by the [`Binding::Indent`]
+    /// and [`Binding::Unindent`] bindings.
+    ///
+    /// By default, a level of indentation is a tab character.
+    pub fn indentation(mut self, indentation: Indentation) -> Self {
+        self.editing.indentation = indentation;
+        self
+    }
+
+    /// Sets whether the Tab and Shift+Tab keys should indent and unindent
+    /// the [`TextEditor`] when using the default key bindings.
+    ///
+    /// By default, Tab is left alone, so it can still move the focus to
+    /// other widgets.
+    pub fn tab_indents(mut self, tab_indents: bool) -> Self {
+        self.editing.tab_indents = tab_indents;
+        self
+    }
+
+    /// Sets whether breaking a line in the [`TextEditor`] should copy its
+    /// leading whitespace to the new one.
+    pub fn auto_indent(mut self, auto_indent: bool) -> Self {
+        self.editing.auto_indent = auto_indent;
+        self
+    }
+
+    /// Sets the pairs of opening and closing characters that the
+    /// [`TextEditor`] should close automatically, like [`PAIRS`].
+    ///
+    /// Typing an opening character surrounds the selection with the pair,
+    /// while typing a closing character moves past it if it is already
+    /// next to the cursor.
+    pub fn auto_pairs(mut self, pairs: &'a [(char, char)]) -> Self {
+        self.editing.auto_pairs = pairs;
+        self
+    }
+
+    /// Sets the line comment prefix used by the [`Binding::ToggleComment`]
+    /// binding.
+    ///
+    /// By default, the prefix is provided by the highlighter of the
+    /// [`TextEditor`], if the language it highlights has any.
+    pub fn line_comment(mut self, prefix: &'a str) -> Self {
+        self.editing.line_comment = Some(prefix);
+        self
+    }
+
+    /// Sets the label of the [`TextEditor`] announced by assistive technologies.
+    pub fn accessible_label(mut self, label: impl Into<String>) -> Self {
+        self.semantics = self.semantics.label(label);
+        self
+    }
+
+    /// Sets the description of the [`TextEditor`] announced by assistive
+    /// technologies.
+    pub fn accessible_description(
+        mut self,
+        description: impl Into<String>,
+    ) -> Self {
+        self.semantics = self.semantics.description(description);
+        self
+    }
+
     /// Sets the style of the [`TextEditor`].
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
@@ -231,6 +368,381 @@ where
         self.class = class.into();
         self
     }
+
+    /// Returns the width of the gutter of the [`TextEditor`] for the given
+    /// amount of lines.
+    fn gutter_width(&self, line_count: usize, renderer: &Renderer) -> f32 {
+        if !self.line_numbers && self.markers.is_none() {
+            return 0.0;
+        }
+
+        let size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let line_height = f32::from(self.line_height.to_absolute(size));
+
+        let markers = if self.markers.is_some() {
+            line_height
+        } else {
+            0.0
+        };
+
+        let numbers = if self.line_numbers {
+            let digits = line_count.max(1).ilog10() as usize + 1;
+
+            Renderer::Paragraph::with_text(Text {
+                content: &"0".repeat(digits),
+                bounds: Size::INFINITY,
+                size,
+                line_height: self.line_height,
+                font: self.font.unwrap_or_else(|| renderer.default_font()),
+                horizontal_alignment: alignment::Horizontal::Left,
+                vertical_alignment: alignment::Vertical::Top,
+                shaping: text::Shaping::Basic,
+                wrapping: Wrapping::None,
+                ellipsis: text::Ellipsis::None,
+                max_lines: None,
+                paragraph_spacing: Pixels::ZERO,
+            })
+            .min_width()
+        } else {
+            0.0
+        };
+
+        markers + numbers + line_height / 2.0
+    }
+
+    /// Returns the [`Padding`] around the text of the [`TextEditor`],
+    /// including its gutter.
+    fn text_padding(&self, state: &State<Highlighter>) -> Padding {
+        Padding {
+            left: self.padding.left + state.gutter_width,
+            ..self.padding
+        }
+    }
+
+    /// Returns the index of the line next to the cursor, if it is over the
+    /// gutter of the [`TextEditor`].
+    fn gutter_line(
+        &self,
+        state: &State<Highlighter>,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) -> Option<usize> {
+        let bounds = layout.bounds();
+
+        let gutter = Rectangle {
+            x: bounds.x + self.padding.left,
+            width: state.gutter_width,
+            ..bounds
+        };
+
+        let y = cursor.position_in(gutter)?.y - self.padding.top;
+
+        self.content
+            .0
+            .borrow()
+            .editor
+            .visible_lines()
+            .into_iter()
+            .find(|(_, line)| y >= line.y && y < line.y + line.height)
+            .map(|(index, _)| index)
+    }
+
+    /// Draws the gutter of the [`TextEditor`] next to the visible lines.
+    fn draw_gutter(
+        &self,
+        state: &State<Highlighter>,
+        editor: &Renderer::Editor,
+        renderer: &mut Renderer,
+        style: &Style,
+        bounds: Rectangle,
+    ) {
+        let size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let line_height = f32::from(self.line_height.to_absolute(size));
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+
+        let gutter = Rectangle {
+            x: bounds.x + self.padding.left,
+            width: state.gutter_width,
+            ..bounds.shrink(self.padding)
+        };
+
+        let (cursor_line, _) = editor.cursor_position();
+
+        for (index, line) in editor.visible_lines() {
+            let y = gutter.y + line.y;
+
+            if y >= gutter.y + gutter.height {
+                break;
+            }
+
+            if let Some(marker) =
+                self.markers.as_ref().and_then(|markers| markers(index))
+            {
+                renderer.fill_text(
+                    Text {
+                        content: marker.icon.to_string(),
+                        bounds: Size::new(line_height, line_height),
+                        size,
+                        line_height: self.line_height,
+                        font: marker.font.unwrap_or(font),
+                        horizontal_alignment: alignment::Horizontal::Center,
+                        vertical_alignment: alignment::Vertical::Center,
+                        shaping: text::Shaping::Advanced,
+                        wrapping: Wrapping::None,
+                        ellipsis: text::Ellipsis::None,
+                        max_lines: None,
+                        paragraph_spacing: Pixels::ZERO,
+                    },
+                    Point::new(
+                        gutter.x + line_height / 2.0,
+                        y + line_height / 2.0,
+                    ),
+                    marker.color.unwrap_or(style.icon),
+                    gutter,
+                );
+            }
+
+            if self.line_numbers {
+                let color = if index == cursor_line && state.focus.is_some() {
+                    style.value
+                } else {
+                    style.placeholder
+                };
+
+                renderer.fill_text(
+                    Text {
+                        content: (index + 1).to_string(),
+                        bounds: Size::new(gutter.width, line_height),
+                        size,
+                        line_height: self.line_height,
+                        font,
+                        horizontal_alignment: alignment::Horizontal::Right,
+                        vertical_alignment: alignment::Vertical::Top,
+                        shaping: text::Shaping::Basic,
+                        wrapping: Wrapping::None,
+                        ellipsis: text::Ellipsis::None,
+                        max_lines: None,
+                        paragraph_spacing: Pixels::ZERO,
+                    },
+                    Point::new(gutter.x + gutter.width - line_height / 2.0, y),
+                    color,
+                    gutter,
+                );
+            }
+        }
+    }
+
+    /// Returns the [`Action`] that scrolls the [`TextEditor`] horizontally
+    /// to the given offset.
+    fn scroll_to(&self, offset: f32) -> Action {
+        let current = self.content.0.borrow().editor.horizontal_scroll();
+
+        Action::ScrollHorizontally {
+            pixels: offset - current,
+        }
+    }
+
+    /// Returns the [`InputMethod`] requested by the [`TextEditor`], with its
+    /// text cursor placed at the given [`Layout`].
+    fn input_method(
+        &self,
+        state: &State<Highlighter>,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> InputMethod {
+        let text_bounds = layout.bounds().shrink(self.text_padding(state));
+        let translation = text_bounds.position() - Point::ORIGIN;
+
+        let position = match self.content.0.borrow().editor.cursor() {
+            Cursor::Caret(position) => position,
+            Cursor::Selection(ranges) => ranges
+                .first()
+                .map(Rectangle::position)
+                .unwrap_or(Point::ORIGIN),
+        };
+
+        let line_height = self.line_height.to_absolute(
+            self.text_size.unwrap_or_else(|| renderer.default_size()),
+        );
+
+        InputMethod::Enabled {
+            cursor: Rectangle::new(
+                position + translation,
+                Size::new(1.0, line_height.into()),
+            ),
+            purpose: input_method::Purpose::Normal,
+        }
+    }
+
+    /// Returns the paragraph of the text being composed by an input method,
+    /// if any.
+    fn preedit(
+        &self,
+        state: &State<Highlighter>,
+        renderer: &Renderer,
+    ) -> Option<Renderer::Paragraph> {
+        let preedit = state.preedit.as_deref()?;
+
+        Some(Renderer::Paragraph::with_text(Text {
+            content: preedit,
+            bounds: Size::INFINITY,
+            size: self.text_size.unwrap_or_else(|| renderer.default_size()),
+            line_height: self.line_height,
+            font: self.font.unwrap_or_else(|| renderer.default_font()),
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Top,
+            shaping: text::Shaping::Advanced,
+            wrapping: Wrapping::None,
+            ellipsis: text::Ellipsis::None,
+            max_lines: None,
+            paragraph_spacing: Pixels::ZERO,
+        }))
+    }
+}
+
+/// A marker displayed in the gutter of a [`TextEditor`] next to a line,
+/// like a breakpoint or a diagnostic.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Marker<Font> {
+    /// The icon of the [`Marker`].
+    pub icon: char,
+    /// The font of the icon, if different from the one of the
+    /// [`TextEditor`].
+    pub font: Option<Font>,
+    /// The [`Color`] of the icon, if different from the icon color of the
+    /// [`Style`].
+    pub color: Option<Color>,
+}
+
+impl<Font> Marker<Font> {
+    /// Creates a new [`Marker`] with the given icon.
+    pub fn new(icon: char) -> Self {
+        Self {
+            icon,
+            font: None,
+            color: None,
+        }
+    }
+
+    /// Sets the font of the icon of the [`Marker`].
+    pub fn font(mut self, font: impl Into<Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the [`Color`] of the icon of the [`Marker`].
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+}
+
+/// The horizontal scrollbar of a [`TextEditor`].
+#[derive(Debug, Clone, Copy)]
+struct Scrollbar {
+    rail: Rectangle,
+    thumb: Rectangle,
+    max_scroll: f32,
+}
+
+impl Scrollbar {
+    const WIDTH: f32 = 4.0;
+
+    /// Creates the [`Scrollbar`] of an [`Editor`](text::Editor) drawn in the
+    /// given bounds, if its contents overflow horizontally.
+    fn new(editor: &impl text::Editor, text_bounds: Rectangle) -> Option<Self> {
+        let content_width = editor.min_bounds().width;
+        let max_scroll = content_width - text_bounds.width;
+
+        if max_scroll <= 0.0 {
+            return None;
+        }
+
+        let rail = Rectangle {
+            x: text_bounds.x,
+            y: text_bounds.y + text_bounds.height - Self::WIDTH,
+            width: text_bounds.width,
+            height: Self::WIDTH,
+        };
+
+        let thumb_width = (rail.width * rail.width / content_width)
+            .max(Self::WIDTH * 4.0)
+            .min(rail.width);
+
+        let offset = editor.horizontal_scroll().min(max_scroll) / max_scroll;
+
+        let thumb = Rectangle {
+            x: rail.x + (rail.width - thumb_width) * offset,
+            width: thumb_width,
+            ..rail
+        };
+
+        Some(Self {
+            rail,
+            thumb,
+            max_scroll,
+        })
+    }
+
+    /// Returns the area of the [`Scrollbar`] that reacts to the mouse in a
+    /// [`TextEditor`] with the given bounds.
+    ///
+    /// It spans from the rail to the bottom edge of the [`TextEditor`], so
+    /// it never overlaps the text above the rail.
+    fn hit_area(&self, bounds: Rectangle) -> Rectangle {
+        Rectangle {
+            y: self.rail.y,
+            height: (bounds.y + bounds.height - self.rail.y)
+                .max(self.rail.height),
+            ..self.rail
+        }
+    }
+
+    /// Returns the scroll offset that places the thumb of the [`Scrollbar`]
+    /// under the given horizontal position, grabbed at the given offset.
+    fn scroll_to(&self, x: f32, grab: f32) -> f32 {
+        let free_width = self.rail.width - self.thumb.width;
+
+        if free_width <= 0.0 {
+            return 0.0;
+        }
+
+        ((x - grab - self.rail.x) / free_width).clamp(0.0, 1.0)
+            * self.max_scroll
+    }
+}
+
+/// The brackets and quotes commonly closed automatically by a
+/// [`TextEditor`].
+///
+/// See [`TextEditor::auto_pairs`].
+pub const PAIRS: &[(char, char)] =
+    &[('(', ')'), ('[', ']'), ('{', '}'), ('"', '"')];
+
+/// The editing settings of a [`TextEditor`].
+#[derive(Debug, Clone, Copy, Default)]
+struct Editing<'a> {
+    read_only: bool,
+    indentation: Indentation,
+    tab_indents: bool,
+    auto_indent: bool,
+    auto_pairs: &'a [(char, char)],
+    line_comment: Option<&'a str>,
+}
+
+impl Editing<'_> {
+    /// Returns the [`Edit`] that inserts the given character.
+    fn insert(&self, c: char) -> Edit {
+        if let Some((open, close)) =
+            self.auto_pairs.iter().find(|(open, _)| *open == c)
+        {
+            Edit::InsertPair(*open, *close)
+        } else if self.auto_pairs.iter().any(|(_, close)| *close == c) {
+            Edit::InsertClosing(c)
+        } else {
+            Edit::Insert(c)
+        }
+    }
 }
 
 /// The content of a [`TextEditor`].
@@ -243,6 +755,7 @@ where
     R: text::Renderer,
 {
     editor: R::Editor,
+    history: History,
     is_dirty: bool,
 }
 
@@ -259,18 +772,60 @@ where
     pub fn with_text(text: &str) -> Self {
         Self(RefCell::new(Internal {
             editor: R::Editor::with_text(text),
+            history: History::default(),
             is_dirty: true,
         }))
     }
 
     /// Performs an [`Action`] on the [`Content`].
+    ///
+    /// Edits are recorded in the history of the [`Content`], which can be
+    /// traversed with [`Action::Undo`] and [`Action::Redo`].
     pub fn perform(&mut self, action: Action) {
         let internal = self.0.get_mut();
 
-        internal.editor.perform(action);
+        match action {
+            Action::Edit(edit) => {
+                internal.history.edit(&mut internal.editor, edit);
+            }
+            Action::Undo => {
+                internal.history.undo(&mut internal.editor);
+            }
+            Action::Redo => {
+                internal.history.redo(&mut internal.editor);
+            }
+            Action::Scroll { .. } | Action::ScrollHorizontally { .. } => {
+                internal.editor.perform(action);
+            }
+            _ => {
+                internal.history.seal();
+                internal.editor.perform(action);
+            }
+        }
+
         internal.is_dirty = true;
     }
 
+    /// Reverts the last edit of the [`Content`], if any.
+    pub fn undo(&mut self) {
+        self.perform(Action::Undo);
+    }
+
+    /// Applies the last reverted edit of the [`Content`] again, if any.
+    pub fn redo(&mut self) {
+        self.perform(Action::Redo);
+    }
+
+    /// Returns true if the [`Content`] has an edit that can be undone.
+    pub fn can_undo(&self) -> bool {
+        self.0.borrow().history.can_undo()
+    }
+
+    /// Returns true if the [`Content`] has an edit that can be redone.
+    pub fn can_redo(&self) -> bool {
+        self.0.borrow().history.can_redo()
+    }
+
     /// Returns the amount of lines of the [`Content`].
     pub fn line_count(&self) -> usize {
         self.0.borrow().editor.line_count()
@@ -322,24 +877,7 @@ where
     ///
     /// Lines are joined with `'\n'`.
     pub fn text(&self) -> String {
-        let mut text = self.lines().enumerate().fold(
-            String::new(),
-            |mut contents, (i, line)| {
-                if i > 0 {
-                    contents.push('\n');
-                }
-
-                contents.push_str(&line);
-
-                contents
-            },
-        );
-
-        if !text.ends_with('\n') {
-            text.push('\n');
-        }
-
-        text
+        self.to_string()
     }
 
     /// Returns the selected text of the [`Content`].
@@ -351,6 +889,126 @@ where
     pub fn cursor_position(&self) -> (usize, usize) {
         self.0.borrow().editor.cursor_position()
     }
+
+    /// Returns all the matches of the given [`search::Query`] in the
+    /// [`Content`].
+    pub fn find(&self, query: &search::Query) -> Vec<search::Match> {
+        search::find(&self.0.borrow().editor, query)
+    }
+
+    /// Selects the first match of the given [`search::Query`] after the
+    /// cursor, wrapping around the end of the [`Content`].
+    pub fn select_next(
+        &mut self,
+        query: &search::Query,
+    ) -> Option<search::Match> {
+        let (_, end) = self.selection_bounds();
+        let match_ = search::next(&self.find(query), end)?;
+
+        self.select(match_);
+
+        Some(match_)
+    }
+
+    /// Selects the last match of the given [`search::Query`] before the
+    /// cursor, wrapping around the start of the [`Content`].
+    pub fn select_previous(
+        &mut self,
+        query: &search::Query,
+    ) -> Option<search::Match> {
+        let (start, _) = self.selection_bounds();
+        let match_ = search::previous(&self.find(query), start)?;
+
+        self.select(match_);
+
+        Some(match_)
+    }
+
+    /// Replaces the selected match of the given [`search::Query`], if any,
+    /// and selects the next one.
+    ///
+    /// Returns true if a match was replaced.
+    pub fn replace(
+        &mut self,
+        query: &search::Query,
+        replacement: &str,
+    ) -> bool {
+        let (start, end) = self.selection_bounds();
+
+        let replaced = self
+            .find(query)
+            .into_iter()
+            .find(|match_| match_.start == start && match_.end == end)
+            .map(|match_| {
+                let internal = self.0.borrow();
+                let line = internal.editor.line(start.line).unwrap_or_default();
+
+                query.replacement(
+                    line,
+                    match_.start.column..match_.end.column,
+                    replacement,
+                )
+            });
+
+        let is_replaced = replaced.is_some();
+
+        if let Some(replaced) = replaced {
+            self.perform(Action::Edit(Edit::Paste(Arc::new(replaced))));
+        }
+
+        let _ = self.select_next(query);
+
+        is_replaced
+    }
+
+    /// Replaces all the matches of the given [`search::Query`] as a single
+    /// edit, returning the amount of matches replaced.
+    pub fn replace_all(
+        &mut self,
+        query: &search::Query,
+        replacement: &str,
+    ) -> usize {
+        let matches = self.find(query);
+
+        let Some((lines, replaced)) = search::replace_all(
+            &self.0.borrow().editor,
+            query,
+            &matches,
+            replacement,
+        ) else {
+            return 0;
+        };
+
+        let last = lines.end - 1;
+        let end =
+            Position::new(last, self.line(last).map_or(0, |line| line.len()));
+
+        self.perform(Action::SelectRange {
+            start: Position::new(lines.start, 0),
+            end,
+        });
+        self.perform(Action::Edit(Edit::Paste(Arc::new(replaced))));
+
+        matches.len()
+    }
+
+    fn selection_bounds(&self) -> (Position, Position) {
+        let internal = self.0.borrow();
+
+        internal.editor.selection_range().unwrap_or_else(|| {
+            let (line, column) = internal.editor.cursor_position();
+            let position = Position::new(line, column);
+
+            (position, position)
+        })
+    }
+
+    fn select(&mut self, match_: search::Match) {
+        self.perform(Action::SelectRange {
+            start: match_.start,
+            end: match_.end,
+        });
+    }
 }
 
 impl<Renderer> Default for Content<Renderer>
@@ -362,6 +1020,33 @@ where
     }
 }
 
+impl<Renderer> fmt::Display for Content<Renderer>
+where
+    Renderer: text::Renderer,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use std::fmt::Write;
+
+        let mut ends_with_newline = false;
+
+        for (i, line) in self.lines().enumerate() {
+            if i > 0 {
+                f.write_char('\n')?;
+            }
+
+            f.write_str(&line)?;
+
+            ends_with_newline = i > 0 && line.is_empty();
+        }
+
+        if !ends_with_newline {
+            f.write_char('\n')?;
+        }
+
+        Ok(())
+    }
+}
+
 impl<Renderer> fmt::Debug for Content<Renderer>
 where
     Renderer: text::Renderer,
@@ -383,10 +1068,15 @@ pub struct State<Highlighter: text::Highlighter> {
     focus: Option<Focus>,
     last_click: Option<mouse::Click>,
     drag_click: Option<mouse::click::Kind>,
+    drag_box: Option<DragBox>,
+    keyboard_modifiers: keyboard::Modifiers,
     partial_scroll: f32,
     highlighter: RefCell<Highlighter>,
     highlighter_settings: Highlighter::Settings,
     highlighter_format_address: usize,
+    preedit: Option<String>,
+    gutter_width: f32,
+    scrollbar_grab: Option<f32>,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -418,6 +1108,18 @@ impl Focus {
     }
 }
 
+/// A rectangular selection being dragged with the mouse.
+#[derive(Debug, Clone, Copy)]
+struct DragBox {
+    origin: Point,
+    is_selecting: bool,
+}
+
+impl DragBox {
+    /// The distance the mouse must travel before the selection starts.
+    const THRESHOLD: f32 = 5.0;
+}
+
 impl<Highlighter: text::Highlighter> State<Highlighter> {
     /// Returns whether the [`TextEditor`] is currently focused or not.
     pub fn is_focused(&self) -> bool {
@@ -457,12 +1159,17 @@ where
             focus: None,
             last_click: None,
             drag_click: None,
+            drag_box: None,
+            keyboard_modifiers: keyboard::Modifiers::default(),
             partial_scroll: 0.0,
             highlighter: RefCell::new(Highlighter::new(
                 &self.highlighter_settings,
             )),
             highlighter_settings: self.highlighter_settings.clone(),
             highlighter_format_address: self.highlighter_format as usize,
+            preedit: None,
+            gutter_width: 0.0,
+            scrollbar_grab: None,
         })
     }
 
@@ -500,8 +1207,11 @@ where
 
         let limits = limits.height(self.height);
 
+        state.gutter_width =
+            self.gutter_width(internal.editor.line_count(), renderer);
+
         internal.editor.update(
-            limits.shrink(self.padding).max(),
+            limits.shrink(self.text_padding(state)).max(),
             self.font.unwrap_or_else(|| renderer.default_font()),
             self.text_size.unwrap_or_else(|| renderer.default_size()),
             self.line_height,
@@ -532,18 +1242,34 @@ where
         event: Event,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
-        _renderer: &Renderer,
+        renderer: &Renderer,
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
         _viewport: &Rectangle,
     ) -> event::Status {
+        let state = tree.state.downcast_mut::<State<Highlighter>>();
+
+        if let Some(on_gutter_press) = &self.on_gutter_press {
+            if let Event::Mouse(mouse::Event::ButtonPressed(
+                mouse::Button::Left,
+            )) = event
+            {
+                if let Some(line) = self.gutter_line(state, layout, cursor) {
+                    shell.publish(on_gutter_press(line));
+
+                    return event::Status::Captured;
+                }
+            }
+        }
+
         let Some(on_edit) = self.on_edit.as_ref() else {
             return event::Status::Ignored;
         };
 
-        let state = tree.state.downcast_mut::<State<Highlighter>>();
-
         match event {
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.keyboard_modifiers = modifiers;
+            }
             Event::Window(window::Event::Unfocused) => {
                 if let Some(focus) = &mut state.focus {
                     focus.is_window_focused = false;
@@ -572,9 +1298,72 @@ where
                                 millis_until_redraw as u64,
                             ),
                         ));
+
+                        if !self.editing.read_only {
+                            shell.request_input_method(
+                                self.input_method(state, layout, renderer),
+                            );
+                        }
                     }
+                } else {
+                    state.preedit = None;
+                }
+            }
+            Event::InputMethod(input_method::Event::Closed) => {
+                state.preedit = None;
+            }
+            _ => {}
+        }
+
+        let text_bounds = layout.bounds().shrink(self.text_padding(state));
+        let scrollbar =
+            Scrollbar::new(&self.content.0.borrow().editor, text_bounds);
+
+        match (&event, scrollbar) {
+            (
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+                Some(scrollbar),
+            ) => {
+                if let Some(position) =
+                    cursor.position_over(scrollbar.hit_area(layout.bounds()))
+                {
+                    let thumb = scrollbar.thumb;
+
+                    let grab = if (thumb.x..thumb.x + thumb.width)
+                        .contains(&position.x)
+                    {
+                        position.x - thumb.x
+                    } else {
+                        thumb.width / 2.0
+                    };
+
+                    state.scrollbar_grab = Some(grab);
+
+                    shell.publish(on_edit(
+                        self.scroll_to(scrollbar.scroll_to(position.x, grab)),
+                    ));
+
+                    return event::Status::Captured;
                 }
             }
+            (
+                Event::Mouse(mouse::Event::CursorMoved { position }),
+                Some(scrollbar),
+            ) => {
+                if let Some(grab) = state.scrollbar_grab {
+                    shell.publish(on_edit(
+                        self.scroll_to(scrollbar.scroll_to(position.x, grab)),
+                    ));
+
+                    return event::Status::Captured;
+                }
+            }
+            (
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+                _,
+            ) => {
+                state.scrollbar_grab = None;
+            }
             _ => {}
         }
 
@@ -582,9 +1371,10 @@ where
             event,
             state,
             layout.bounds(),
-            self.padding,
+            self.text_padding(state),
             cursor,
             self.key_binding.as_deref(),
+            self.editing.tab_indents,
         ) else {
             return event::Status::Ignored;
         };
@@ -605,11 +1395,52 @@ where
 
                 shell.publish(on_edit(action));
             }
+            Update::AddCursor(position) => {
+                state.focus = Some(Focus::now());
+                state.drag_click = None;
+                state.drag_box = Some(DragBox {
+                    origin: position,
+                    is_selecting: false,
+                });
+
+                shell.publish(on_edit(Action::AddCursor(position)));
+            }
             Update::Drag(position) => {
                 shell.publish(on_edit(Action::Drag(position)));
             }
+            Update::SelectBox(start, end) => {
+                if let Some(drag_box) = &mut state.drag_box {
+                    drag_box.is_selecting = true;
+                }
+
+                shell.publish(on_edit(Action::SelectBox { start, end }));
+            }
             Update::Release => {
                 state.drag_click = None;
+                state.drag_box = None;
+            }
+            Update::Preedit(_) | Update::Commit(_)
+                if self.editing.read_only =>
+            {
+                return event::Status::Ignored;
+            }
+            Update::Preedit(content) => {
+                state.preedit = (!content.is_empty()).then_some(content);
+
+                if let Some(focus) = &mut state.focus {
+                    focus.updated_at = Instant::now();
+                }
+            }
+            Update::Commit(content) => {
+                state.preedit = None;
+
+                if let Some(focus) = &mut state.focus {
+                    focus.updated_at = Instant::now();
+                }
+
+                shell.publish(on_edit(Action::Edit(Edit::Paste(Arc::new(
+                    content,
+                )))));
             }
             Update::Scroll(lines) => {
                 let bounds = self.content.0.borrow().editor.bounds();
@@ -625,6 +1456,19 @@ where
                     lines: lines as i32,
                 }));
             }
+            Update::ScrollHorizontally(lines) => {
+                if scrollbar.is_none() {
+                    return event::Status::Ignored;
+                }
+
+                let line_height = self.line_height.to_absolute(
+                    self.text_size.unwrap_or_else(|| renderer.default_size()),
+                );
+
+                shell.publish(on_edit(Action::ScrollHorizontally {
+                    pixels: lines * f32::from(line_height),
+                }));
+            }
             Update::Binding(binding) => {
                 fn apply_binding<
                     H: text::Highlighter,
@@ -634,11 +1478,16 @@ where
                     binding: Binding<Message>,
                     content: &Content<R>,
                     state: &mut State<H>,
+                    editing: &Editing<'_>,
                     on_edit: &dyn Fn(Action) -> Message,
                     clipboard: &mut dyn Clipboard,
                     shell: &mut Shell<'_, Message>,
                 ) {
-                    let mut publish = |action| shell.publish(on_edit(action));
+                    let mut publish = |action: Action| {
+                        if !(editing.read_only && action.is_edit()) {
+                            shell.publish(on_edit(action));
+                        }
+                    };
 
                     match binding {
                         Binding::Unfocus => {
@@ -688,10 +1537,42 @@ where
                             publish(Action::SelectAll);
                         }
                         Binding::Insert(c) => {
-                            publish(Action::Edit(Edit::Insert(c)));
+                            publish(Action::Edit(editing.insert(c)));
                         }
                         Binding::Enter => {
-                            publish(Action::Edit(Edit::Enter));
+                            publish(Action::Edit(if editing.auto_indent {
+                                Edit::EnterIndented
+                            } else {
+                                Edit::Enter
+                            }));
+                        }
+                        Binding::Indent => {
+                            publish(Action::Edit(Edit::Indent(
+                                editing.indentation,
+                            )));
+                        }
+                        Binding::Unindent => {
+                            publish(Action::Edit(Edit::Unindent(
+                                editing.indentation,
+                            )));
+                        }
+                        Binding::ToggleComment => {
+                            let prefix = editing
+                                .line_comment
+                                .map(str::to_owned)
+                                .or_else(|| {
+                                    state
+                                        .highlighter
+                                        .borrow()
+                                        .line_comment()
+                                        .map(str::to_owned)
+                                });
+
+                            if let Some(prefix) = prefix {
+                                publish(Action::Edit(Edit::ToggleComment(
+                                    Arc::new(prefix),
+                                )));
+                            }
                         }
                         Binding::Backspace => {
                             publish(Action::Edit(Edit::Backspace));
@@ -699,10 +1580,16 @@ where
                         Binding::Delete => {
                             publish(Action::Edit(Edit::Delete));
                         }
+                        Binding::Undo => {
+                            publish(Action::Undo);
+                        }
+                        Binding::Redo => {
+                            publish(Action::Redo);
+                        }
                         Binding::Sequence(sequence) => {
                             for binding in sequence {
                                 apply_binding(
-                                    binding, content, state, on_edit,
+                                    binding, content, state, editing, on_edit,
                                     clipboard, shell,
                                 );
                             }
@@ -717,6 +1604,7 @@ where
                     binding,
                     self.content,
                     state,
+                    &self.editing,
                     on_edit,
                     clipboard,
                     shell,
@@ -778,9 +1666,106 @@ where
             style.background,
         );
 
-        let text_bounds = bounds.shrink(self.padding);
+        let text_bounds = bounds.shrink(self.text_padding(state));
+
+        if state.gutter_width > 0.0 {
+            self.draw_gutter(state, &internal.editor, renderer, &style, bounds);
+        }
+
+        let translation = text_bounds.position() - Point::ORIGIN;
+
+        let line_height: f32 = self
+            .line_height
+            .to_absolute(
+                self.text_size.unwrap_or_else(|| renderer.default_size()),
+            )
+            .into();
+
+        // Text being composed is displayed at the current caret
+        let preedit = state
+            .focus
+            .is_some()
+            .then(|| self.preedit(state, renderer))
+            .flatten()
+            .map(|preedit| {
+                let position = match internal.editor.cursor() {
+                    Cursor::Caret(position) => position,
+                    Cursor::Selection(ranges) => ranges
+                        .first()
+                        .map(Rectangle::position)
+                        .unwrap_or(Point::ORIGIN),
+                };
+
+                (preedit, position + translation)
+            });
+
+        let preedit_width = preedit
+            .as_ref()
+            .map_or(0.0, |(preedit, _)| preedit.min_width());
+
+        let fill_editor = |renderer: &mut Renderer| match &preedit {
+            Some((_, position)) => {
+                // The rest of the line of the caret is shifted to make room
+                // for the text being composed
+                let line = Rectangle {
+                    y: position.y,
+                    height: line_height,
+                    ..text_bounds
+                };
+
+                let clips = [
+                    Rectangle {
+                        height: line.y - text_bounds.y,
+                        ..text_bounds
+                    },
+                    Rectangle {
+                        y: line.y + line.height,
+                        ..text_bounds
+                    },
+                    Rectangle {
+                        width: position.x - text_bounds.x,
+                        ..line
+                    },
+                ];
+
+                for clip in clips
+                    .iter()
+                    .filter_map(|clip| clip.intersection(&text_bounds))
+                {
+                    renderer.fill_editor(
+                        &internal.editor,
+                        text_bounds.position(),
+                        defaults.text_color,
+                        clip,
+                    );
+                }
+
+                let rest = Rectangle {
+                    x: position.x + preedit_width,
+                    ..line
+                };
+
+                if let Some(clip) = rest.intersection(&text_bounds) {
+                    renderer.fill_editor(
+                        &internal.editor,
+                        text_bounds.position()
+                            + Vector::new(preedit_width, 0.0),
+                        defaults.text_color,
+                        clip,
+                    );
+                }
+            }
+            None => {
+                renderer.fill_editor(
+                    &internal.editor,
+                    text_bounds.position(),
+                    defaults.text_color,
+                    text_bounds,
+                );
+            }
+        };
 
-        if internal.editor.is_empty() {
+        if internal.editor.is_empty() && preedit.is_none() {
             if let Some(placeholder) = self.placeholder.clone() {
                 renderer.fill_text(
                     Text {
@@ -795,29 +1780,51 @@ where
                         vertical_alignment: alignment::Vertical::Top,
                         shaping: text::Shaping::Advanced,
                         wrapping: self.wrapping,
+                        ellipsis: text::Ellipsis::None,
+                        max_lines: None,
+                        paragraph_spacing: Pixels::ZERO,
                     },
                     text_bounds.position(),
                     style.placeholder,
                     text_bounds,
                 );
             }
+        } else if internal.editor.horizontal_scroll() > 0.0 {
+            renderer.with_layer(text_bounds, fill_editor);
         } else {
-            renderer.fill_editor(
-                &internal.editor,
-                text_bounds.position(),
-                defaults.text_color,
-                text_bounds,
-            );
+            fill_editor(renderer);
         }
 
-        let translation = text_bounds.position() - Point::ORIGIN;
+        if let Some(scrollbar) = Scrollbar::new(&internal.editor, text_bounds) {
+            let is_active = state.scrollbar_grab.is_some()
+                || cursor.is_over(scrollbar.hit_area(bounds));
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: scrollbar.thumb,
+                    border: border::rounded(Scrollbar::WIDTH / 2.0),
+                    ..renderer::Quad::default()
+                },
+                if is_active {
+                    style.placeholder
+                } else {
+                    style.placeholder.scale_alpha(0.5)
+                },
+            );
+        }
 
         if let Some(focus) = state.focus.as_ref() {
-            match internal.editor.cursor() {
-                Cursor::Caret(position) if focus.is_cursor_visible() => {
-                    let cursor =
-                        Rectangle::new(
-                            position + translation,
+            for (i, cursor) in internal.editor.cursors().into_iter().enumerate()
+            {
+                // Text being composed is placed after the current caret
+                let preedit_width = if i == 0 { preedit_width } else { 0.0 };
+
+                match cursor {
+                    Cursor::Caret(position) if focus.is_cursor_visible() => {
+                        let cursor = Rectangle::new(
+                            position
+                                + translation
+                                + Vector::new(preedit_width, 0.0),
                             Size::new(
                                 1.0,
                                 self.line_height
@@ -828,47 +1835,87 @@ where
                             ),
                         );
 
-                    if let Some(clipped_cursor) =
-                        text_bounds.intersection(&cursor)
-                    {
-                        renderer.fill_quad(
-                            renderer::Quad {
-                                bounds: clipped_cursor,
-                                ..renderer::Quad::default()
-                            },
-                            style.value,
-                        );
+                        if let Some(clipped_cursor) =
+                            text_bounds.intersection(&cursor)
+                        {
+                            renderer.fill_quad(
+                                renderer::Quad {
+                                    bounds: clipped_cursor,
+                                    ..renderer::Quad::default()
+                                },
+                                style.value,
+                            );
+                        }
                     }
-                }
-                Cursor::Selection(ranges) => {
-                    for range in ranges.into_iter().filter_map(|range| {
-                        text_bounds.intersection(&(range + translation))
-                    }) {
-                        renderer.fill_quad(
-                            renderer::Quad {
-                                bounds: range,
-                                ..renderer::Quad::default()
-                            },
-                            style.selection,
-                        );
+                    Cursor::Selection(ranges) => {
+                        for range in ranges.into_iter().filter_map(|range| {
+                            text_bounds.intersection(&(range + translation))
+                        }) {
+                            renderer.fill_quad(
+                                renderer::Quad {
+                                    bounds: range,
+                                    ..renderer::Quad::default()
+                                },
+                                style.selection,
+                            );
+                        }
                     }
+                    Cursor::Caret(_) => {}
                 }
-                Cursor::Caret(_) => {}
             }
         }
+
+        if let Some((preedit, position)) = preedit {
+            let bounds = Rectangle::new(position, preedit.min_bounds());
+
+            renderer.with_layer(text_bounds, |renderer| {
+                renderer.fill_paragraph(
+                    &preedit,
+                    bounds.position(),
+                    style.value,
+                    text_bounds,
+                );
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            y: bounds.y + bounds.height - 1.0,
+                            height: 1.0,
+                            ..bounds
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    style.value,
+                );
+            });
+        }
     }
 
     fn mouse_interaction(
         &self,
-        _state: &widget::Tree,
+        tree: &widget::Tree,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
         _viewport: &Rectangle,
         _renderer: &Renderer,
     ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State<Highlighter>>();
         let is_disabled = self.on_edit.is_none();
 
-        if cursor.is_over(layout.bounds()) {
+        let text_bounds = layout.bounds().shrink(self.text_padding(state));
+        let is_over_scrollbar =
+            Scrollbar::new(&self.content.0.borrow().editor, text_bounds)
+                .is_some_and(|scrollbar| {
+                    cursor.is_over(scrollbar.hit_area(layout.bounds()))
+                });
+
+        if self.on_gutter_press.is_some()
+            && self.gutter_line(state, layout, cursor).is_some()
+        {
+            mouse::Interaction::Pointer
+        } else if is_over_scrollbar || state.scrollbar_grab.is_some() {
+            mouse::Interaction::Idle
+        } else if cursor.is_over(layout.bounds()) {
             if is_disabled {
                 mouse::Interaction::NotAllowed
             } else {
@@ -879,16 +1926,31 @@ where
         }
     }
 
+    fn semantics(
+        &self,
+        _tree: &widget::Tree,
+        _layout: Layout<'_>,
+    ) -> Option<Semantics> {
+        Some(self.semantics.clone().disabled(self.on_edit.is_none()))
+    }
+
     fn operate(
         &self,
         tree: &mut widget::Tree,
-        _layout: Layout<'_>,
+        layout: Layout<'_>,
         _renderer: &Renderer,
         operation: &mut dyn widget::Operation,
     ) {
+        operation.kind(None, layout.bounds(), "text_editor");
+
+        if let Some(semantics) = self.semantics(tree, layout) {
+            operation.semantics(None, layout.bounds(), &semantics);
+        }
+
         let state = tree.state.downcast_mut::<State<Highlighter>>();
 
-        operation.focusable(state, None);
+        operation.focusable(state, None, layout.bounds());
+        operation.text(None, layout.bounds(), &self.content);
     }
 }
 
@@ -937,6 +1999,19 @@ pub enum Binding<Message> {
     Backspace,
     /// Delete the next character.
     Delete,
+    /// Indent the selected lines, or the cursor.
+    Indent,
+    /// Unindent the selected lines, or the current one.
+    Unindent,
+    /// Toggle the line comments of the selected lines, or the current one.
+    ///
+    /// The comment prefix is provided by the [`Highlighter`] of the
+    /// [`TextEditor`].
+    ToggleComment,
+    /// Undo the last edit.
+    Undo,
+    /// Redo the last undone edit.
+    Redo,
     /// A sequence of bindings to execute.
     Sequence(Vec<Self>),
     /// Produce the given message.
@@ -977,6 +2052,19 @@ impl<Message> Binding<Message> {
             }
             keyboard::Key::Named(key::Named::Delete) => Some(Self::Delete),
             keyboard::Key::Named(key::Named::Escape) => Some(Self::Unfocus),
+            keyboard::Key::Character("/") if modifiers.command() => {
+                Some(Self::ToggleComment)
+            }
+            keyboard::Key::Character("z") if modifiers.command() => {
+                Some(if modifiers.shift() {
+                    Self::Redo
+                } else {
+                    Self::Undo
+                })
+            }
+            keyboard::Key::Character("y") if modifiers.command() => {
+                Some(Self::Redo)
+            }
             keyboard::Key::Character("c") if modifiers.command() => {
                 Some(Self::Copy)
             }
@@ -1030,9 +2118,14 @@ impl<Message> Binding<Message> {
 
 enum Update<Message> {
     Click(mouse::Click),
+    AddCursor(Point),
     Drag(Point),
+    SelectBox(Point, Point),
     Release,
     Scroll(f32),
+    ScrollHorizontally(f32),
+    Preedit(String),
+    Commit(String),
     Binding(Binding<Message>),
 }
 
@@ -1044,6 +2137,7 @@ impl<Message> Update<Message> {
         padding: Padding,
         cursor: mouse::Cursor,
         key_binding: Option<&dyn Fn(KeyPress) -> Option<Binding<Message>>>,
+        tab_indents: bool,
     ) -> Option<Self> {
         let binding = |binding| Some(Update::Binding(binding));
 
@@ -1052,7 +2146,11 @@ impl<Message> Update<Message> {
                 mouse::Event::ButtonPressed(mouse::Button::Left) => {
                     if let Some(cursor_position) = cursor.position_in(bounds) {
                         let cursor_position = cursor_position
-                            - Vector::new(padding.top, padding.left);
+                            - Vector::new(padding.left, padding.top);
+
+                        if state.keyboard_modifiers.alt() {
+                            return Some(Update::AddCursor(cursor_position));
+                        }
 
                         let click = mouse::Click::new(
                             cursor_position,
@@ -1069,10 +2167,26 @@ impl<Message> Update<Message> {
                 mouse::Event::ButtonReleased(mouse::Button::Left) => {
                     Some(Update::Release)
                 }
+                mouse::Event::CursorMoved { .. }
+                    if state.drag_box.is_some() =>
+                {
+                    let drag_box = state.drag_box?;
+                    let end = cursor.position_in(bounds)?
+                        - Vector::new(padding.left, padding.top);
+
+                    // Small movements while clicking only add a caret
+                    if !drag_box.is_selecting
+                        && drag_box.origin.distance(end) < DragBox::THRESHOLD
+                    {
+                        return None;
+                    }
+
+                    Some(Update::SelectBox(drag_box.origin, end))
+                }
                 mouse::Event::CursorMoved { .. } => match state.drag_click {
                     Some(mouse::click::Kind::Single) => {
                         let cursor_position = cursor.position_in(bounds)?
-                            - Vector::new(padding.top, padding.left);
+                            - Vector::new(padding.left, padding.top);
 
                         Some(Update::Drag(cursor_position))
                     }
@@ -1081,16 +2195,31 @@ impl<Message> Update<Message> {
                 mouse::Event::WheelScrolled { delta }
                     if cursor.is_over(bounds) =>
                 {
-                    Some(Update::Scroll(match delta {
-                        mouse::ScrollDelta::Lines { y, .. } => {
-                            if y.abs() > 0.0 {
-                                y.signum() * -(y.abs() * 4.0).max(1.0)
-                            } else {
-                                0.0
-                            }
+                    let (x, y) = match delta {
+                        mouse::ScrollDelta::Lines { x, y } => {
+                            let lines = |amount: f32| {
+                                if amount.abs() > 0.0 {
+                                    amount.signum()
+                                        * -(amount.abs() * 4.0).max(1.0)
+                                } else {
+                                    0.0
+                                }
+                            };
+
+                            (lines(x), lines(y))
+                        }
+                        mouse::ScrollDelta::Pixels { x, y } => {
+                            (-x / 4.0, -y / 4.0)
                         }
-                        mouse::ScrollDelta::Pixels { y, .. } => -y / 4.0,
-                    }))
+                    };
+
+                    if state.keyboard_modifiers.shift() {
+                        Some(Update::ScrollHorizontally(y))
+                    } else if x.abs() > y.abs() {
+                        Some(Update::ScrollHorizontally(x))
+                    } else {
+                        Some(Update::Scroll(y))
+                    }
                 }
                 _ => None,
             },
@@ -1113,13 +2242,34 @@ impl<Message> Update<Message> {
                     status,
                 };
 
-                if let Some(key_binding) = key_binding {
-                    key_binding(key_press)
-                } else {
-                    Binding::from_key_press(key_press)
+                match key_binding {
+                    Some(key_binding) => key_binding(key_press),
+                    None if tab_indents
+                        && status == Status::Focused
+                        && key_press.key
+                            == keyboard::Key::Named(key::Named::Tab) =>
+                    {
+                        Some(if modifiers.shift() {
+                            Binding::Unindent
+                        } else {
+                            Binding::Indent
+                        })
+                    }
+                    None => Binding::from_key_press(key_press),
                 }
                 .map(Self::Binding)
             }
+            Event::InputMethod(event) if state.focus.is_some() => match event {
+                input_method::Event::Preedit(content, _) => {
+                    Some(Update::Preedit(content))
+                }
+                input_method::Event::Commit(content) => {
+                    Some(Update::Commit(content))
+                }
+                input_method::Event::Opened | input_method::Event::Closed => {
+                    None
+                }
+            },
             _ => None,
         }
     }
@@ -1179,6 +2329,12 @@ pub trait Catalog {
 
     /// The [`Style`] of a class with the given status.
     fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+
+    /// The [`palette::Extended`] used to color syntax highlighting that
+    /// follows the theme, if any.
+    fn highlight_palette(&self) -> Option<&palette::Extended> {
+        None
+    }
 }
 
 /// A styling function for a [`TextEditor`].
@@ -1194,6 +2350,10 @@ impl Catalog for Theme {
     fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
         class(self, status)
     }
+
+    fn highlight_palette(&self) -> Option<&palette::Extended> {
+        Some(self.extended_palette())
+    }
 }
 
 /// The default style of a [`TextEditor`].
@@ -0,0 +1,312 @@
+//! Keep track of the edits of some text.
+use crate::core::text::editor::{Action, Edit, Editor, Position};
+
+use std::sync::Arc;
+
+/// The edit history of an [`Editor`].
+///
+/// Only the lines touched by each edit are stored, so recording an edit
+/// does not depend on the size of the whole text.
+#[derive(Debug, Default)]
+pub struct History {
+    undo: Vec<Change>,
+    redo: Vec<Change>,
+    is_sealed: bool,
+}
+
+/// A reversible change to a range of lines.
+#[derive(Debug)]
+struct Change {
+    line: usize,
+    before: Vec<String>,
+    after: Vec<String>,
+    selection: Selection,
+    cursor: Position,
+    typed: Option<char>,
+}
+
+/// The cursor of an [`Editor`] before a [`Change`].
+#[derive(Debug, Clone, Copy)]
+enum Selection {
+    Caret(Position),
+    Range(Position, Position),
+}
+
+impl History {
+    /// Performs the given [`Edit`] on the [`Editor`] and records it.
+    ///
+    /// Consecutive typing is coalesced into a single change, until some
+    /// whitespace is followed by a new word.
+    pub fn edit(&mut self, editor: &mut impl Editor, edit: Edit) {
+        let cursor = cursor(editor);
+        let selection = editor.selection_range();
+
+        // Edits apply to every caret
+        let ranges = editor.selection_ranges();
+
+        let first = ranges
+            .iter()
+            .map(|(start, _)| start.line)
+            .min()
+            .unwrap_or(cursor.line);
+
+        let last = ranges
+            .iter()
+            .map(|(_, end)| end.line)
+            .max()
+            .unwrap_or(cursor.line);
+
+        // Edits may join the adjacent lines
+        let line_count = editor.line_count();
+        let start = first.saturating_sub(1).min(line_count);
+        let end = (last + 2).min(line_count);
+
+        let before = lines(editor, start, end);
+
+        let typed = match edit {
+            Edit::Insert(c) if selection.is_none() => Some(c),
+            _ => None,
+        };
+
+        editor.perform(Action::Edit(edit));
+
+        let end = (end + editor.line_count()).saturating_sub(line_count);
+        let after = lines(editor, start, end.max(start));
+
+        if before == after {
+            return;
+        }
+
+        self.redo.clear();
+
+        let new_cursor = self::cursor(editor);
+
+        if let (Some(c), false) = (typed, self.is_sealed) {
+            if let Some(last) = self.undo.last_mut() {
+                let is_continuation = last.typed.is_some_and(|previous| {
+                    !previous.is_whitespace() || c.is_whitespace()
+                });
+
+                if is_continuation
+                    && last.cursor == cursor
+                    && last.line == start
+                    && last.after.len() == before.len()
+                {
+                    last.after = after;
+                    last.cursor = new_cursor;
+                    last.typed = Some(c);
+
+                    return;
+                }
+            }
+        }
+
+        self.undo.push(Change {
+            line: start,
+            before,
+            after,
+            selection: match selection {
+                Some((start, end)) => Selection::Range(start, end),
+                None => Selection::Caret(cursor),
+            },
+            cursor: new_cursor,
+            typed,
+        });
+
+        self.is_sealed = false;
+    }
+
+    /// Stops coalescing the next typing with the last recorded change.
+    ///
+    /// This should be called whenever the cursor is moved.
+    pub fn seal(&mut self) {
+        self.is_sealed = true;
+    }
+
+    /// Reverts the last recorded change, if any.
+    pub fn undo(&mut self, editor: &mut impl Editor) {
+        let Some(change) = self.undo.pop() else {
+            return;
+        };
+
+        replace(editor, change.line, change.after.len(), &change.before);
+
+        editor.perform(match change.selection {
+            Selection::Caret(position) => Action::MoveTo(position),
+            Selection::Range(start, end) => Action::SelectRange { start, end },
+        });
+
+        self.redo.push(change);
+        self.is_sealed = true;
+    }
+
+    /// Applies the last reverted change again, if any.
+    pub fn redo(&mut self, editor: &mut impl Editor) {
+        let Some(change) = self.redo.pop() else {
+            return;
+        };
+
+        replace(editor, change.line, change.before.len(), &change.after);
+
+        editor.perform(Action::MoveTo(change.cursor));
+
+        self.undo.push(change);
+        self.is_sealed = true;
+    }
+
+    /// Returns true if there is a change that can be undone.
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    /// Returns true if there is a change that can be redone.
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+}
+
+fn cursor(editor: &impl Editor) -> Position {
+    let (line, column) = editor.cursor_position();
+
+    Position::new(line, column)
+}
+
+fn lines(editor: &impl Editor, start: usize, end: usize) -> Vec<String> {
+    (start..end)
+        .filter_map(|line| editor.line(line).map(str::to_owned))
+        .collect()
+}
+
+/// Replaces `count` lines of the [`Editor`], starting at `line`, with the
+/// given ones.
+fn replace(
+    editor: &mut impl Editor,
+    line: usize,
+    count: usize,
+    lines: &[String],
+) {
+    let start = Position::new(line, 0);
+
+    if count > 0 {
+        let last = line + count - 1;
+        let end = Position::new(last, editor.line(last).map_or(0, str::len));
+
+        editor.perform(Action::SelectRange { start, end });
+    } else {
+        editor.perform(Action::MoveTo(start));
+    }
+
+    if lines.is_empty() {
+        editor.perform(Action::Edit(Edit::Delete));
+    } else {
+        editor.perform(Action::Edit(Edit::Paste(Arc::new(lines.join("\n")))));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::text::editor::Motion;
+    use crate::core::text::highlighter::PlainText;
+    use crate::core::text::{LineHeight, Wrapping};
+    use crate::core::{Font, Pixels, Point, Size};
+    use crate::graphics::text;
+
+    fn editor(text: &str) -> text::Editor {
+        text::Editor::with_text(text)
+    }
+
+    fn text(editor: &text::Editor) -> String {
+        lines(editor, 0, editor.line_count()).join("\n")
+    }
+
+    #[test]
+    fn undo_and_redo_coalesce_typing_by_words() {
+        let mut editor = editor("Hello");
+        let mut history = History::default();
+
+        editor.perform(Action::Move(Motion::End));
+        history.seal();
+
+        for c in " wo".chars() {
+            history.edit(&mut editor, Edit::Insert(c));
+        }
+
+        assert_eq!(text(&editor), "Hello wo");
+
+        history.undo(&mut editor);
+        assert_eq!(text(&editor), "Hello ");
+
+        history.undo(&mut editor);
+        assert_eq!(text(&editor), "Hello");
+        assert!(!history.can_undo());
+
+        history.redo(&mut editor);
+        history.redo(&mut editor);
+        assert_eq!(text(&editor), "Hello wo");
+        assert!(!history.can_redo());
+    }
+
+    #[test]
+    fn undo_restores_joined_lines_and_selection() {
+        let mut editor = editor("first\nsecond\nthird");
+        let mut history = History::default();
+
+        editor.perform(Action::MoveTo(Position::new(1, 0)));
+        history.edit(&mut editor, Edit::Backspace);
+
+        editor.perform(Action::SelectRange {
+            start: Position::new(0, 2),
+            end: Position::new(1, 3),
+        });
+        history.edit(&mut editor, Edit::Paste(Arc::new(String::from("X\nY"))));
+
+        assert_eq!(text(&editor), "fiX\nYrd");
+
+        history.undo(&mut editor);
+        assert_eq!(text(&editor), "firstsecond\nthird");
+        assert_eq!(editor.selection().as_deref(), Some("rstsecond\nthi"));
+
+        history.undo(&mut editor);
+        assert_eq!(text(&editor), "first\nsecond\nthird");
+        assert_eq!(editor.cursor_position(), (1, 0));
+
+        history.redo(&mut editor);
+        assert_eq!(text(&editor), "firstsecond\nthird");
+    }
+
+    #[test]
+    fn edits_apply_to_every_caret_and_undo_at_once() {
+        let mut editor = editor("one\ntwo\nthree");
+        let mut history = History::default();
+
+        editor.update(
+            Size::new(1000.0, 1000.0),
+            Font::default(),
+            Pixels(10.0),
+            LineHeight::Absolute(Pixels(10.0)),
+            Wrapping::None,
+            &mut PlainText,
+        );
+
+        editor.perform(Action::SelectBox {
+            start: Point::new(0.0, 1.0),
+            end: Point::new(0.0, 25.0),
+        });
+
+        assert_eq!(editor.selection_ranges().len(), 3);
+
+        history.edit(&mut editor, Edit::Insert('-'));
+        assert_eq!(text(&editor), "-one\n-two\n-three");
+
+        editor.perform(Action::Move(Motion::End));
+        history
+            .edit(&mut editor, Edit::Paste(Arc::new(String::from("1\n2\n3"))));
+        assert_eq!(text(&editor), "-one1\n-two2\n-three3");
+
+        history.undo(&mut editor);
+        history.undo(&mut editor);
+        assert_eq!(text(&editor), "one\ntwo\nthree");
+        assert_eq!(editor.selection_ranges().len(), 1);
+    }
+}
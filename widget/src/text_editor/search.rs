@@ -0,0 +1,303 @@
+//! Find and replace text in a [`TextEditor`].
+//!
+//! Every match of a [`Query`] can be highlighted by passing the search
+//! [`Highlighter`] to [`TextEditor::highlight_with`], along with the
+//! [`Format`] of the matches.
+//!
+//! [`Format`]: highlighter::Format
+//! [`TextEditor`]: super::TextEditor
+//! [`TextEditor::highlight_with`]: super::TextEditor::highlight_with
+use crate::core::text::editor::{Editor, Position};
+use crate::core::text::highlighter;
+
+use std::ops::Range;
+
+/// Something to search for in the lines of some text.
+///
+/// Matches never span multiple lines.
+#[derive(Debug, Clone)]
+pub enum Query {
+    /// Some literal text.
+    Text(String),
+    /// A regular expression.
+    #[cfg(feature = "regex")]
+    Regex(regex::Regex),
+}
+
+impl Query {
+    /// Returns true if the [`Query`] cannot match anything.
+    pub fn is_empty(&self) -> bool {
+        match self {
+            Self::Text(text) => text.is_empty(),
+            #[cfg(feature = "regex")]
+            Self::Regex(regex) => regex.as_str().is_empty(),
+        }
+    }
+
+    /// Returns the byte ranges of all the matches of the [`Query`] in the
+    /// given line.
+    ///
+    /// Empty matches are ignored.
+    pub fn find_in(&self, line: &str) -> Vec<Range<usize>> {
+        match self {
+            Self::Text(text) if text.is_empty() => Vec::new(),
+            Self::Text(text) => line
+                .match_indices(text.as_str())
+                .map(|(start, text)| start..start + text.len())
+                .collect(),
+            #[cfg(feature = "regex")]
+            Self::Regex(regex) => regex
+                .find_iter(line)
+                .map(|match_| match_.range())
+                .filter(|range| !range.is_empty())
+                .collect(),
+        }
+    }
+
+    /// Returns the text that replaces the match of the [`Query`] in the
+    /// given range of a line.
+    ///
+    /// A [`Query::Regex`] expands any references to its capture groups
+    /// in the replacement, like `$1` or `${name}`.
+    #[cfg_attr(not(feature = "regex"), allow(unused_variables))]
+    pub fn replacement(
+        &self,
+        line: &str,
+        range: Range<usize>,
+        replacement: &str,
+    ) -> String {
+        match self {
+            Self::Text(_) => replacement.to_owned(),
+            #[cfg(feature = "regex")]
+            Self::Regex(regex) => {
+                let mut expanded = String::new();
+
+                if let Some(captures) = regex.captures_at(line, range.start) {
+                    captures.expand(replacement, &mut expanded);
+                } else {
+                    expanded.push_str(replacement);
+                }
+
+                expanded
+            }
+        }
+    }
+}
+
+impl Default for Query {
+    fn default() -> Self {
+        Self::Text(String::new())
+    }
+}
+
+impl PartialEq for Query {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Text(a), Self::Text(b)) => a == b,
+            #[cfg(feature = "regex")]
+            (Self::Regex(a), Self::Regex(b)) => a.as_str() == b.as_str(),
+            #[cfg(feature = "regex")]
+            _ => false,
+        }
+    }
+}
+
+impl From<&str> for Query {
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_owned())
+    }
+}
+
+impl From<String> for Query {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+#[cfg(feature = "regex")]
+impl From<regex::Regex> for Query {
+    fn from(regex: regex::Regex) -> Self {
+        Self::Regex(regex)
+    }
+}
+
+/// A match of a [`Query`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Match {
+    /// The [`Position`] where the match starts.
+    pub start: Position,
+    /// The [`Position`] where the match ends.
+    pub end: Position,
+}
+
+/// A [`highlighter::Highlighter`] of the matches of a [`Query`].
+#[derive(Debug)]
+pub struct Highlighter {
+    query: Query,
+    current_line: usize,
+}
+
+impl highlighter::Highlighter for Highlighter {
+    type Settings = Query;
+    type Highlight = Match;
+
+    type Iterator<'a> = std::vec::IntoIter<(Range<usize>, Match)>;
+
+    fn new(settings: &Self::Settings) -> Self {
+        Self {
+            query: settings.clone(),
+            current_line: 0,
+        }
+    }
+
+    fn update(&mut self, new_settings: &Self::Settings) {
+        self.query = new_settings.clone();
+        self.change_line(0);
+    }
+
+    fn change_line(&mut self, line: usize) {
+        self.current_line = line;
+    }
+
+    fn highlight_line(&mut self, line: &str) -> Self::Iterator<'_> {
+        let index = self.current_line;
+        self.current_line += 1;
+
+        self.query
+            .find_in(line)
+            .into_iter()
+            .map(|range| {
+                let match_ = Match {
+                    start: Position::new(index, range.start),
+                    end: Position::new(index, range.end),
+                };
+
+                (range, match_)
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
+    fn current_line(&self) -> usize {
+        self.current_line
+    }
+}
+
+/// Finds all the matches of a [`Query`] in an [`Editor`].
+pub(super) fn find(editor: &impl Editor, query: &Query) -> Vec<Match> {
+    if query.is_empty() {
+        return Vec::new();
+    }
+
+    (0..editor.line_count())
+        .filter_map(|index| Some((index, editor.line(index)?)))
+        .flat_map(|(index, line)| {
+            query.find_in(line).into_iter().map(move |range| Match {
+                start: Position::new(index, range.start),
+                end: Position::new(index, range.end),
+            })
+        })
+        .collect()
+}
+
+/// Returns the first match after the given [`Position`], wrapping around.
+pub(super) fn next(matches: &[Match], position: Position) -> Option<Match> {
+    matches
+        .iter()
+        .find(|match_| match_.start >= position)
+        .or_else(|| matches.first())
+        .copied()
+}
+
+/// Returns the last match before the given [`Position`], wrapping around.
+pub(super) fn previous(matches: &[Match], position: Position) -> Option<Match> {
+    matches
+        .iter()
+        .rev()
+        .find(|match_| match_.end <= position)
+        .or_else(|| matches.last())
+        .copied()
+}
+
+/// Replaces all the given matches of a [`Query`] in the lines of an
+/// [`Editor`], returning the range of lines that changed with their new
+/// contents.
+pub(super) fn replace_all(
+    editor: &impl Editor,
+    query: &Query,
+    matches: &[Match],
+    replacement: &str,
+) -> Option<(Range<usize>, String)> {
+    let first = matches.first()?.start.line;
+    let last = matches.last()?.start.line;
+
+    let mut lines = Vec::with_capacity(last - first + 1);
+
+    for index in first..=last {
+        let line = editor.line(index).unwrap_or_default();
+        let mut replaced = String::with_capacity(line.len());
+        let mut cursor = 0;
+
+        for match_ in matches.iter().filter(|match_| match_.start.line == index)
+        {
+            let range = match_.start.column..match_.end.column;
+
+            replaced.push_str(&line[cursor..range.start]);
+            replaced.push_str(&query.replacement(
+                line,
+                range.clone(),
+                replacement,
+            ));
+
+            cursor = range.end;
+        }
+
+        replaced.push_str(&line[cursor..]);
+        lines.push(replaced);
+    }
+
+    Some((first..last + 1, lines.join("\n")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphics::text;
+
+    #[test]
+    fn find_matches_and_wrap_around() {
+        let editor = text::Editor::with_text("key = 1\nother = key\nkey");
+        let matches = find(&editor, &Query::from("key"));
+
+        assert_eq!(
+            matches
+                .iter()
+                .map(|match_| match_.start)
+                .collect::<Vec<_>>(),
+            vec![
+                Position::new(0, 0),
+                Position::new(1, 8),
+                Position::new(2, 0),
+            ]
+        );
+
+        assert_eq!(next(&matches, Position::new(0, 1)), Some(matches[1]));
+        assert_eq!(next(&matches, Position::new(2, 1)), Some(matches[0]));
+        assert_eq!(previous(&matches, Position::new(1, 8)), Some(matches[0]));
+        assert_eq!(previous(&matches, Position::new(0, 0)), Some(matches[2]));
+
+        assert!(find(&editor, &Query::from("")).is_empty());
+    }
+
+    #[test]
+    fn replace_all_matches_in_the_changed_lines() {
+        let editor = text::Editor::with_text("a\nkey = key\nb\nkey\nc");
+        let query = Query::from("key");
+        let matches = find(&editor, &query);
+
+        assert_eq!(
+            replace_all(&editor, &query, &matches, "name"),
+            Some((1..4, String::from("name = name\nb\nname")))
+        );
+    }
+}
@@ -2,6 +2,7 @@
 //!
 //! A [`TextInput`] has some local [`State`].
 mod editor;
+mod history;
 mod value;
 
 pub mod cursor;
@@ -10,10 +11,12 @@ pub use cursor::Cursor;
 pub use value::Value;
 
 use editor::Editor;
+use history::{History, Snapshot};
 
 use crate::core::alignment;
 use crate::core::clipboard::{self, Clipboard};
 use crate::core::event::{self, Event};
+use crate::core::input_method::{self, InputMethod};
 use crate::core::keyboard;
 use crate::core::keyboard::key;
 use crate::core::layout;
@@ -25,6 +28,7 @@ use crate::core::time::{Duration, Instant};
 use crate::core::touch;
 use crate::core::widget;
 use crate::core::widget::operation::{self, Operation};
+use crate::core::widget::semantics::{Role, Semantics};
 use crate::core::widget::tree::{self, Tree};
 use crate::core::window;
 use crate::core::{
@@ -79,6 +83,7 @@ pub struct TextInput<
     on_paste: Option<Box<dyn Fn(String) -> Message + 'a>>,
     on_submit: Option<Message>,
     icon: Option<Icon<Renderer::Font>>,
+    semantics: Semantics,
     class: Theme::Class<'a>,
 }
 
@@ -109,6 +114,7 @@ where
             on_paste: None,
             on_submit: None,
             icon: None,
+            semantics: Semantics::new(Role::TextInput),
             class: Theme::default(),
         }
     }
@@ -233,6 +239,24 @@ where
         self
     }
 
+    /// Sets the label of the [`TextInput`] announced by assistive technologies.
+    ///
+    /// By default, its placeholder is announced.
+    pub fn accessible_label(mut self, label: impl Into<String>) -> Self {
+        self.semantics = self.semantics.label(label);
+        self
+    }
+
+    /// Sets the description of the [`TextInput`] announced by assistive
+    /// technologies.
+    pub fn accessible_description(
+        mut self,
+        description: impl Into<String>,
+    ) -> Self {
+        self.semantics = self.semantics.description(description);
+        self
+    }
+
     /// Sets the style of the [`TextInput`].
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
@@ -281,6 +305,9 @@ where
             vertical_alignment: alignment::Vertical::Center,
             shaping: text::Shaping::Advanced,
             wrapping: text::Wrapping::default(),
+            ellipsis: text::Ellipsis::None,
+            max_lines: None,
+            paragraph_spacing: Pixels::ZERO,
         };
 
         state.placeholder.update(placeholder_text);
@@ -288,6 +315,9 @@ where
         let secure_value = self.is_secure.then(|| value.secure());
         let value = secure_value.as_ref().unwrap_or(value);
 
+        let preedit_value = with_preedit(value, state, self.is_secure);
+        let value = preedit_value.as_ref().unwrap_or(value);
+
         state.value.update(Text {
             content: &value.to_string(),
             ..placeholder_text
@@ -306,6 +336,9 @@ where
                 vertical_alignment: alignment::Vertical::Center,
                 shaping: text::Shaping::Advanced,
                 wrapping: text::Wrapping::default(),
+                ellipsis: text::Ellipsis::None,
+                max_lines: None,
+                paragraph_spacing: Pixels::ZERO,
             };
 
             state.icon.update(icon_text);
@@ -410,14 +443,20 @@ where
             );
         }
 
-        let text = value.to_string();
+        let is_empty = value.is_empty() && state.preedit.is_none();
+        let preedit = preedit_range(value, state);
 
         let (cursor, offset, is_selecting) = if let Some(focus) = state
             .is_focused
             .as_ref()
             .filter(|focus| focus.is_window_focused)
         {
-            match state.cursor.state(value) {
+            let cursor = match preedit {
+                Some((_, end)) => cursor::State::Index(end),
+                None => state.cursor.state(value),
+            };
+
+            match cursor {
                 cursor::State::Index(position) => {
                     let (text_value_width, offset) =
                         measure_cursor_and_scroll_offset(
@@ -498,8 +537,33 @@ where
             (None, 0.0, false)
         };
 
+        let underline =
+            preedit.filter(|_| state.is_focused()).map(|(start, end)| {
+                let (start_position, _) = measure_cursor_and_scroll_offset(
+                    state.value.raw(),
+                    text_bounds,
+                    start,
+                );
+
+                let (end_position, _) = measure_cursor_and_scroll_offset(
+                    state.value.raw(),
+                    text_bounds,
+                    end,
+                );
+
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: text_bounds.x + start_position,
+                        y: text_bounds.y + text_bounds.height - 1.0,
+                        width: end_position - start_position,
+                        height: 1.0,
+                    },
+                    ..renderer::Quad::default()
+                }
+            });
+
         let draw = |renderer: &mut Renderer, viewport| {
-            let paragraph = if text.is_empty() {
+            let paragraph = if is_empty {
                 state.placeholder.raw()
             } else {
                 state.value.raw()
@@ -508,7 +572,7 @@ where
             let alignment_offset = alignment_offset(
                 text_bounds.width,
                 paragraph.min_width(),
-                self.alignment,
+                layout.direction().align_x(self.alignment),
             );
 
             if let Some((cursor, color)) = cursor {
@@ -522,11 +586,20 @@ where
                 renderer.with_translation(Vector::ZERO, |_| {});
             }
 
+            if let Some(underline) = underline {
+                renderer.with_translation(
+                    Vector::new(alignment_offset - offset, 0.0),
+                    |renderer| {
+                        renderer.fill_quad(underline, style.value);
+                    },
+                );
+            }
+
             renderer.fill_paragraph(
                 paragraph,
                 Point::new(text_bounds.x, text_bounds.center_y())
                     + Vector::new(alignment_offset - offset, 0.0),
-                if text.is_empty() {
+                if is_empty {
                     style.placeholder
                 } else {
                     style.value
@@ -542,6 +615,78 @@ where
             draw(renderer, text_bounds);
         }
     }
+
+    /// Returns a [`Snapshot`] of the current value of the [`TextInput`] for
+    /// its edit history, unless it is secure.
+    fn snapshot(&self, cursor: Cursor) -> Option<Snapshot> {
+        (!self.is_secure).then(|| Snapshot {
+            value: self.value.clone(),
+            cursor,
+        })
+    }
+
+    /// Records an edit of the [`TextInput`] in the given [`History`].
+    fn record(
+        &self,
+        history: &mut History,
+        before: Option<Snapshot>,
+        cursor: Cursor,
+        typed: Option<char>,
+    ) {
+        if let (Some(before), Some(after)) = (before, self.snapshot(cursor)) {
+            history.push(before, after, typed);
+        }
+    }
+
+    /// Returns the [`InputMethod`] requested by the [`TextInput`], with its
+    /// text cursor placed at the given [`Layout`].
+    fn input_method(
+        &self,
+        state: &State<Renderer::Paragraph>,
+        layout: Layout<'_>,
+    ) -> InputMethod {
+        let text_bounds = layout.children().next().unwrap().bounds();
+
+        let value = if self.is_secure {
+            self.value.secure()
+        } else {
+            self.value.clone()
+        };
+
+        let index = match preedit_range(&value, state) {
+            Some((_, end)) => end,
+            None => match state.cursor.state(&value) {
+                cursor::State::Index(index) => index,
+                cursor::State::Selection { end, .. } => end,
+            },
+        };
+
+        let (position, offset) = measure_cursor_and_scroll_offset(
+            state.value.raw(),
+            text_bounds,
+            index,
+        );
+
+        let alignment_offset = alignment_offset(
+            text_bounds.width,
+            state.value.raw().min_width(),
+            layout.direction().align_x(self.alignment),
+        );
+
+        InputMethod::Enabled {
+            cursor: Rectangle {
+                x: text_bounds.x + alignment_offset + position - offset,
+                y: text_bounds.y,
+                width: 1.0,
+                height: text_bounds.height,
+            },
+            purpose: if self.is_secure {
+                input_method::Purpose::Secure
+            } else {
+                input_method::Purpose::Normal
+            },
+        }
+    }
 }
 
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -584,17 +729,46 @@ where
         self.layout(tree, renderer, limits, None)
     }
 
+    /// Unless it has a label, the placeholder of the [`TextInput`] is
+    /// announced.
+    fn semantics(
+        &self,
+        _tree: &Tree,
+        _layout: Layout<'_>,
+    ) -> Option<Semantics> {
+        let semantics =
+            self.semantics.clone().disabled(self.on_input.is_none());
+
+        Some(if self.placeholder.is_empty() {
+            semantics
+        } else {
+            semantics.or_label(self.placeholder.as_str())
+        })
+    }
+
     fn operate(
         &self,
         tree: &mut Tree,
-        _layout: Layout<'_>,
+        layout: Layout<'_>,
         _renderer: &Renderer,
         operation: &mut dyn Operation,
     ) {
+        let id = self.id.as_ref().map(|id| &id.0);
+
+        operation.kind(id, layout.bounds(), "text_input");
+
+        if let Some(semantics) = self.semantics(tree, layout) {
+            operation.semantics(id, layout.bounds(), &semantics);
+        }
+
         let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
 
-        operation.focusable(state, self.id.as_ref().map(|id| &id.0));
-        operation.text_input(state, self.id.as_ref().map(|id| &id.0));
+        operation.focusable(state, id, layout.bounds());
+        operation.text_input(state, id, layout.bounds());
+
+        if !self.is_secure {
+            operation.text(id, layout.bounds(), &self.value);
+        }
     }
 
     fn on_event(
@@ -617,6 +791,7 @@ where
                 self.font,
                 self.size,
                 self.line_height,
+                self.is_secure,
             );
         };
 
@@ -650,7 +825,7 @@ where
                         let alignment_offset = alignment_offset(
                             text_bounds.width,
                             state.value.raw().min_width(),
-                            self.alignment,
+                            layout.direction().align_x(self.alignment),
                         );
 
                         cursor_position.x - text_bounds.x - alignment_offset
@@ -738,7 +913,7 @@ where
                         let alignment_offset = alignment_offset(
                             text_bounds.width,
                             state.value.raw().min_width(),
-                            self.alignment,
+                            layout.direction().align_x(self.alignment),
                         );
 
                         position.x - text_bounds.x - alignment_offset
@@ -774,6 +949,17 @@ where
                     let modifiers = state.keyboard_modifiers;
                     focus.updated_at = Instant::now();
 
+                    let key = mirror_arrow_keys(
+                        key,
+                        &if self.is_secure {
+                            self.value.secure()
+                        } else {
+                            self.value.clone()
+                        },
+                        state.cursor.end(&self.value),
+                        layout.direction(),
+                    );
+
                     match key.as_ref() {
                         keyboard::Key::Character("c")
                             if state.keyboard_modifiers.command()
@@ -807,6 +993,8 @@ where
                                 );
                             }
 
+                            let before = self.snapshot(state.cursor);
+
                             let mut editor =
                                 Editor::new(&mut self.value, &mut state.cursor);
                             editor.delete();
@@ -814,6 +1002,13 @@ where
                             let message = (on_input)(editor.contents());
                             shell.publish(message);
 
+                            self.record(
+                                &mut state.history,
+                                before,
+                                state.cursor,
+                                None,
+                            );
+
                             update_cache(state, &self.value);
 
                             return event::Status::Captured;
@@ -840,6 +1035,8 @@ where
                                 }
                             };
 
+                            let before = self.snapshot(state.cursor);
+
                             let mut editor =
                                 Editor::new(&mut self.value, &mut state.cursor);
 
@@ -852,12 +1049,46 @@ where
                             };
                             shell.publish(message);
 
+                            self.record(
+                                &mut state.history,
+                                before,
+                                state.cursor,
+                                None,
+                            );
+
                             state.is_pasting = Some(content);
 
                             update_cache(state, &self.value);
 
                             return event::Status::Captured;
                         }
+                        keyboard::Key::Character(key @ ("z" | "y"))
+                            if state.keyboard_modifiers.command() =>
+                        {
+                            let Some(on_input) = &self.on_input else {
+                                return event::Status::Ignored;
+                            };
+
+                            let snapshot = if key == "z" && !modifiers.shift() {
+                                state.history.undo(&self.value)
+                            } else {
+                                state.history.redo(&self.value)
+                            };
+
+                            if let Some(Snapshot { value, cursor }) = snapshot {
+                                self.value = value;
+                                state.cursor = cursor;
+                                state.is_pasting = None;
+
+                                let message =
+                                    (on_input)(self.value.to_string());
+                                shell.publish(message);
+
+                                update_cache(state, &self.value);
+                            }
+
+                            return event::Status::Captured;
+                        }
                         keyboard::Key::Character("a")
                             if state.keyboard_modifiers.command() =>
                         {
@@ -878,6 +1109,8 @@ where
                         if let Some(c) =
                             text.chars().next().filter(|c| !c.is_control())
                         {
+                            let before = self.snapshot(state.cursor);
+
                             let mut editor =
                                 Editor::new(&mut self.value, &mut state.cursor);
 
@@ -886,6 +1119,13 @@ where
                             let message = (on_input)(editor.contents());
                             shell.publish(message);
 
+                            self.record(
+                                &mut state.history,
+                                before,
+                                state.cursor,
+                                Some(c),
+                            );
+
                             focus.updated_at = Instant::now();
 
                             update_cache(state, &self.value);
@@ -919,6 +1159,8 @@ where
                                 }
                             }
 
+                            let before = self.snapshot(state.cursor);
+
                             let mut editor =
                                 Editor::new(&mut self.value, &mut state.cursor);
                             editor.backspace();
@@ -926,6 +1168,13 @@ where
                             let message = (on_input)(editor.contents());
                             shell.publish(message);
 
+                            self.record(
+                                &mut state.history,
+                                before,
+                                state.cursor,
+                                None,
+                            );
+
                             update_cache(state, &self.value);
                         }
                         keyboard::Key::Named(key::Named::Delete) => {
@@ -950,6 +1199,8 @@ where
                                 }
                             }
 
+                            let before = self.snapshot(state.cursor);
+
                             let mut editor =
                                 Editor::new(&mut self.value, &mut state.cursor);
                             editor.delete();
@@ -957,6 +1208,13 @@ where
                             let message = (on_input)(editor.contents());
                             shell.publish(message);
 
+                            self.record(
+                                &mut state.history,
+                                before,
+                                state.cursor,
+                                None,
+                            );
+
                             update_cache(state, &self.value);
                         }
                         keyboard::Key::Named(key::Named::Home) => {
@@ -1119,7 +1377,81 @@ where
                                 millis_until_redraw as u64,
                             ),
                         ));
+
+                        if self.on_input.is_some() {
+                            shell.request_input_method(
+                                self.input_method(state, layout),
+                            );
+                        }
                     }
+                } else if state.preedit.take().is_some() {
+                    update_cache(state, &self.value);
+                }
+            }
+            Event::InputMethod(event) => {
+                let state = state::<Renderer>(tree);
+
+                match event {
+                    input_method::Event::Preedit(content, _) => {
+                        if self.on_input.is_none() {
+                            return event::Status::Ignored;
+                        }
+
+                        if let Some(focus) = &mut state.is_focused {
+                            focus.updated_at = Instant::now();
+
+                            state.preedit =
+                                (!content.is_empty()).then_some(content);
+
+                            update_cache(state, &self.value);
+
+                            return event::Status::Captured;
+                        }
+                    }
+                    input_method::Event::Commit(content) => {
+                        let Some(on_input) = &self.on_input else {
+                            return event::Status::Ignored;
+                        };
+
+                        if let Some(focus) = &mut state.is_focused {
+                            focus.updated_at = Instant::now();
+
+                            state.preedit = None;
+                            state.is_pasting = None;
+
+                            let content: String = content
+                                .chars()
+                                .filter(|c| !c.is_control())
+                                .collect();
+
+                            let before = self.snapshot(state.cursor);
+
+                            let mut editor =
+                                Editor::new(&mut self.value, &mut state.cursor);
+
+                            editor.paste(Value::new(&content));
+
+                            let message = (on_input)(editor.contents());
+                            shell.publish(message);
+
+                            self.record(
+                                &mut state.history,
+                                before,
+                                state.cursor,
+                                None,
+                            );
+
+                            update_cache(state, &self.value);
+
+                            return event::Status::Captured;
+                        }
+                    }
+                    input_method::Event::Closed => {
+                        if state.preedit.take().is_some() {
+                            update_cache(state, &self.value);
+                        }
+                    }
+                    input_method::Event::Opened => {}
                 }
             }
             _ => {}
@@ -1268,6 +1600,8 @@ pub struct State<P: text::Paragraph> {
     is_pasting: Option<Value>,
     last_click: Option<mouse::Click>,
     cursor: Cursor,
+    preedit: Option<String>,
+    history: History,
     keyboard_modifiers: keyboard::Modifiers,
     // TODO: Add stateful horizontal scrolling offset
 }
@@ -1397,6 +1731,45 @@ fn offset<P: text::Paragraph>(
     }
 }
 
+/// Returns the range of the text being composed by an input method in the
+/// given [`Value`], if any.
+///
+/// The composed text is displayed at the start of the current selection.
+fn preedit_range<P: text::Paragraph>(
+    value: &Value,
+    state: &State<P>,
+) -> Option<(usize, usize)> {
+    let preedit = state.preedit.as_deref()?;
+
+    let start = match state.cursor.state(value) {
+        cursor::State::Index(index) => index,
+        cursor::State::Selection { start, end } => start.min(end),
+    };
+
+    Some((start, start + Value::new(preedit).len()))
+}
+
+/// Returns the given [`Value`] with the text being composed by an input
+/// method inserted, if any.
+///
+/// The composed text is masked if the [`Value`] is secure.
+fn with_preedit<P: text::Paragraph>(
+    value: &Value,
+    state: &State<P>,
+    is_secure: bool,
+) -> Option<Value> {
+    let preedit = state.preedit.as_deref()?;
+    let (start, _) = preedit_range(value, state)?;
+
+    let preedit = Value::new(preedit);
+    let preedit = if is_secure { preedit.secure() } else { preedit };
+
+    let mut value = value.clone();
+    value.insert_many(start, preedit);
+
+    Some(value)
+}
+
 fn measure_cursor_and_scroll_offset(
     paragraph: &impl text::Paragraph,
     text_bounds: Rectangle,
@@ -1445,6 +1818,7 @@ fn replace_paragraph<Renderer>(
     font: Option<Renderer::Font>,
     text_size: Option<Pixels>,
     line_height: text::LineHeight,
+    is_secure: bool,
 ) where
     Renderer: text::Renderer,
 {
@@ -1454,6 +1828,12 @@ fn replace_paragraph<Renderer>(
     let mut children_layout = layout.children();
     let text_bounds = children_layout.next().unwrap().bounds();
 
+    let secure_value = is_secure.then(|| value.secure());
+    let value = secure_value.as_ref().unwrap_or(value);
+
+    let preedit_value = with_preedit(value, state, is_secure);
+    let value = preedit_value.as_ref().unwrap_or(value);
+
     state.value = paragraph::Plain::new(Text {
         font,
         line_height,
@@ -1464,6 +1844,9 @@ fn replace_paragraph<Renderer>(
         vertical_alignment: alignment::Vertical::Top,
         shaping: text::Shaping::Advanced,
         wrapping: text::Wrapping::default(),
+        ellipsis: text::Ellipsis::None,
+        max_lines: None,
+        paragraph_spacing: Pixels::ZERO,
     });
 }
 
@@ -1569,6 +1952,56 @@ pub fn default(theme: &Theme, status: Status) -> Style {
     }
 }
 
+/// Swaps the left and right arrow keys, so the cursor of a [`TextInput`]
+/// laid out right to left moves along the arrows.
+/// Swaps the horizontal arrow keys when the text at the cursor runs from
+/// right to left, so they move the cursor in the direction they point to.
+///
+/// The embedding level of the character before the cursor decides, falling
+/// back to the one after it and, for an empty [`Value`], to the
+/// [`Direction`] of the layout.
+fn mirror_arrow_keys(
+    key: keyboard::Key,
+    value: &Value,
+    cursor: usize,
+    direction: layout::Direction,
+) -> keyboard::Key {
+    use unicode_bidi::{BidiInfo, Level};
+
+    let mirrored = match &key {
+        keyboard::Key::Named(key::Named::ArrowLeft) => {
+            keyboard::Key::Named(key::Named::ArrowRight)
+        }
+        keyboard::Key::Named(key::Named::ArrowRight) => {
+            keyboard::Key::Named(key::Named::ArrowLeft)
+        }
+        _ => return key,
+    };
+
+    let base = if direction.is_right_to_left() {
+        Level::rtl()
+    } else {
+        Level::ltr()
+    };
+
+    let text = value.to_string();
+    let offset = value.select(0, cursor).to_string().len();
+    let bidi = BidiInfo::new(&text, Some(base));
+
+    let level = text[..offset]
+        .char_indices()
+        .next_back()
+        .map(|(index, _)| index)
+        .or((offset < text.len()).then_some(offset))
+        .map_or(base, |index| bidi.levels[index]);
+
+    if level.is_rtl() {
+        mirrored
+    } else {
+        key
+    }
+}
+
 fn alignment_offset(
     text_bounds_width: f32,
     text_min_width: f32,
@@ -1578,7 +2011,9 @@ fn alignment_offset(
         0.0
     } else {
         match alignment {
-            alignment::Horizontal::Left => 0.0,
+            alignment::Horizontal::Left | alignment::Horizontal::Justified => {
+                0.0
+            }
             alignment::Horizontal::Center => {
                 (text_bounds_width - text_min_width) / 2.0
             }
@@ -1586,3 +2021,50 @@ fn alignment_offset(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mirror(text: &str, cursor: usize, direction: layout::Direction) -> bool {
+        mirror_arrow_keys(
+            keyboard::Key::Named(key::Named::ArrowLeft),
+            &Value::new(text),
+            cursor,
+            direction,
+        ) == keyboard::Key::Named(key::Named::ArrowRight)
+    }
+
+    #[test]
+    fn mirror_arrow_keys_with_bidi_level_at_cursor() {
+        use layout::Direction::{LeftToRight, RightToLeft};
+
+        assert!(!mirror("hello", 2, LeftToRight));
+        assert!(!mirror("hello", 2, RightToLeft));
+        assert!(mirror("שלום", 2, LeftToRight));
+        assert!(mirror("שלום", 2, RightToLeft));
+
+        // Mixed text follows the character before the cursor
+        assert!(!mirror("abc שלום", 2, LeftToRight));
+        assert!(mirror("abc שלום", 6, LeftToRight));
+        assert!(mirror("שלום abc", 0, LeftToRight));
+        assert!(!mirror("שלום abc", 6, RightToLeft));
+
+        // Empty values follow the layout
+        assert!(!mirror("", 0, LeftToRight));
+        assert!(mirror("", 0, RightToLeft));
+    }
+
+    #[test]
+    fn keep_other_keys() {
+        assert_eq!(
+            mirror_arrow_keys(
+                keyboard::Key::Named(key::Named::Home),
+                &Value::new("שלום"),
+                0,
+                layout::Direction::RightToLeft,
+            ),
+            keyboard::Key::Named(key::Named::Home)
+        );
+    }
+}
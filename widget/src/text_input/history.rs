@@ -0,0 +1,109 @@
+//! Keep track of the values produced by a text input.
+use crate::text_input::{Cursor, Value};
+
+/// The edit history of a text input.
+///
+/// It only contains the values that the text input produced itself, so
+/// it can detect when the application changed its value in between.
+#[derive(Debug, Clone, Default)]
+pub struct History {
+    undo: Vec<Entry>,
+    redo: Vec<Entry>,
+}
+
+/// A [`Value`] with its [`Cursor`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Snapshot {
+    pub value: Value,
+    pub cursor: Cursor,
+}
+
+#[derive(Debug, Clone)]
+struct Entry {
+    before: Snapshot,
+    after: Snapshot,
+    typed: Option<char>,
+}
+
+impl History {
+    /// Records the change from one [`Snapshot`] to another.
+    ///
+    /// Consecutive typing is coalesced into a single change, until some
+    /// whitespace is followed by a new word.
+    pub fn push(
+        &mut self,
+        before: Snapshot,
+        after: Snapshot,
+        typed: Option<char>,
+    ) {
+        if before.value == after.value {
+            return;
+        }
+
+        self.redo.clear();
+
+        if let (Some(c), Some(last)) = (typed, self.undo.last_mut()) {
+            let is_continuation = last.typed.is_some_and(|previous| {
+                !previous.is_whitespace() || c.is_whitespace()
+            });
+
+            if is_continuation && last.after == before {
+                last.after = after;
+                last.typed = Some(c);
+
+                return;
+            }
+        }
+
+        self.undo.push(Entry {
+            before,
+            after,
+            typed,
+        });
+    }
+
+    /// Reverts the last change, returning the [`Snapshot`] to restore.
+    ///
+    /// The history is cleared if the current [`Value`] is not the one
+    /// produced by the last change.
+    pub fn undo(&mut self, current: &Value) -> Option<Snapshot> {
+        let entry = self.undo.pop()?;
+
+        if entry.after.value != *current {
+            self.clear();
+            return None;
+        }
+
+        let snapshot = entry.before.clone();
+        self.redo.push(entry);
+
+        Some(snapshot)
+    }
+
+    /// Applies the last reverted change again, returning the [`Snapshot`]
+    /// to restore.
+    ///
+    /// The history is cleared if the current [`Value`] is not the one
+    /// restored by the last undo.
+    pub fn redo(&mut self, current: &Value) -> Option<Snapshot> {
+        let entry = self.redo.pop()?;
+
+        if entry.before.value != *current {
+            self.clear();
+            return None;
+        }
+
+        let snapshot = entry.after.clone();
+        self.undo.push(Entry {
+            typed: None,
+            ..entry
+        });
+
+        Some(snapshot)
+    }
+
+    fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+    }
+}
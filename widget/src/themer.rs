@@ -5,7 +5,7 @@ use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
 use crate::core::widget::tree::{self, Tree};
-use crate::core::widget::Operation;
+use crate::core::widget::{Operation, Semantics};
 use crate::core::{
     Background, Clipboard, Color, Element, Layout, Length, Point, Rectangle,
     Shell, Size, Vector, Widget,
@@ -86,6 +86,10 @@ where
         self.content.as_widget().diff(tree);
     }
 
+    fn semantics(&self, tree: &Tree, layout: Layout<'_>) -> Option<Semantics> {
+        self.content.as_widget().semantics(tree, layout)
+    }
+
     fn size(&self) -> Size<Length> {
         self.content.as_widget().size()
     }
@@ -7,6 +7,7 @@ use crate::core::renderer;
 use crate::core::text;
 use crate::core::touch;
 use crate::core::widget;
+use crate::core::widget::semantics::{Role, Semantics};
 use crate::core::widget::tree::{self, Tree};
 use crate::core::{
     Border, Clipboard, Color, Element, Event, Layout, Length, Pixels,
@@ -50,6 +51,7 @@ pub struct Toggler<
     text_wrapping: text::Wrapping,
     spacing: f32,
     font: Option<Renderer::Font>,
+    semantics: Semantics,
     class: Theme::Class<'a>,
 }
 
@@ -90,6 +92,7 @@ where
             text_wrapping: text::Wrapping::default(),
             spacing: Self::DEFAULT_SIZE / 2.0,
             font: None,
+            semantics: Semantics::new(Role::Switch),
             class: Theme::default(),
         }
     }
@@ -153,6 +156,24 @@ where
         self
     }
 
+    /// Sets the label of the [`Toggler`] announced by assistive technologies.
+    ///
+    /// By default, its visible label, if any, is announced.
+    pub fn accessible_label(mut self, label: impl Into<String>) -> Self {
+        self.semantics = self.semantics.label(label);
+        self
+    }
+
+    /// Sets the description of the [`Toggler`] announced by assistive
+    /// technologies.
+    pub fn accessible_description(
+        mut self,
+        description: impl Into<String>,
+    ) -> Self {
+        self.semantics = self.semantics.description(description);
+        self
+    }
+
     /// Sets the style of the [`Toggler`].
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
@@ -225,6 +246,9 @@ where
                         alignment::Vertical::Top,
                         self.text_shaping,
                         self.text_wrapping,
+                        text::Ellipsis::None,
+                        None,
+                        Pixels::ZERO,
                     )
                 } else {
                     layout::Node::new(Size::ZERO)
@@ -233,6 +257,37 @@ where
         )
     }
 
+    fn semantics(
+        &self,
+        _tree: &Tree,
+        _layout: Layout<'_>,
+    ) -> Option<Semantics> {
+        let semantics = self.semantics.clone().toggled(self.is_toggled);
+
+        Some(match &self.label {
+            Some(label) => semantics.or_label(label.as_ref()),
+            None => semantics,
+        })
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        operation.kind(None, layout.bounds(), "toggler");
+
+        if let Some(semantics) = self.semantics(tree, layout) {
+            operation.semantics(None, layout.bounds(), &semantics);
+        }
+
+        if let Some(label) = &self.label {
+            operation.text(None, layout.bounds(), label);
+        }
+    }
+
     fn on_event(
         &mut self,
         _state: &mut Tree,
@@ -349,9 +404,14 @@ where
             style.background,
         );
 
+        // The knob rests at the end of the track when toggled, which is on
+        // the left in right-to-left layouts
+        let is_at_right =
+            self.is_toggled != layout.direction().is_right_to_left();
+
         let toggler_foreground_bounds = Rectangle {
             x: bounds.x
-                + if self.is_toggled {
+                + if is_at_right {
                     bounds.width - 2.0 * space - (bounds.height - (4.0 * space))
                 } else {
                     2.0 * space
@@ -13,7 +13,9 @@ use crate::core::layout::{self, Layout};
 use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::touch;
+use crate::core::widget::semantics::{self, Role, Semantics};
 use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Operation;
 use crate::core::{
     self, Clipboard, Element, Length, Pixels, Point, Rectangle, Shell, Size,
     Widget,
@@ -54,6 +56,7 @@ where
     on_release: Option<Message>,
     width: f32,
     height: Length,
+    semantics: Semantics,
     class: Theme::Class<'a>,
 }
 
@@ -100,6 +103,7 @@ where
             on_release: None,
             width: Self::DEFAULT_WIDTH,
             height: Length::Fill,
+            semantics: Semantics::new(Role::Slider),
             class: Theme::default(),
         }
     }
@@ -149,6 +153,22 @@ where
         self
     }
 
+    /// Sets the label of the [`VerticalSlider`] announced by assistive technologies.
+    pub fn accessible_label(mut self, label: impl Into<String>) -> Self {
+        self.semantics = self.semantics.label(label);
+        self
+    }
+
+    /// Sets the description of the [`VerticalSlider`] announced by assistive
+    /// technologies.
+    pub fn accessible_description(
+        mut self,
+        description: impl Into<String>,
+    ) -> Self {
+        self.semantics = self.semantics.description(description);
+        self
+    }
+
     /// Sets the style of the [`VerticalSlider`].
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
@@ -200,6 +220,36 @@ where
         layout::atomic(limits, self.width, self.height)
     }
 
+    fn semantics(
+        &self,
+        _tree: &Tree,
+        _layout: Layout<'_>,
+    ) -> Option<Semantics> {
+        Some(self.semantics.clone().value(semantics::Value {
+            current: self.value.into(),
+            min: (*self.range.start()).into(),
+            max: (*self.range.end()).into(),
+            step: Some(self.step.into()),
+        }))
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.kind(None, layout.bounds(), "vertical_slider");
+
+        let semantics =
+            Widget::<Message, Theme, Renderer>::semantics(self, tree, layout);
+
+        if let Some(semantics) = semantics {
+            operation.semantics(None, layout.bounds(), &semantics);
+        }
+    }
+
     fn on_event(
         &mut self,
         tree: &mut Tree,
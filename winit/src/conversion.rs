@@ -2,6 +2,7 @@
 //!
 //! [`winit`]: https://github.com/rust-windowing/winit
 //! [`iced_runtime`]: https://github.com/iced-rs/iced/tree/0.12/runtime
+use crate::core::input_method;
 use crate::core::keyboard;
 use crate::core::mouse;
 use crate::core::touch;
@@ -283,6 +284,9 @@ pub fn window_event(
 
             Some(Event::Window(window::Event::Moved(Point::new(x, y))))
         }
+        WindowEvent::Ime(ime) => {
+            Some(Event::InputMethod(input_method_event(ime)))
+        }
         _ => None,
     }
 }
@@ -506,6 +510,38 @@ pub fn touch_event(
     }
 }
 
+/// Converts an `Ime` event from [`winit`] to an [`iced`] input method event.
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+/// [`iced`]: https://github.com/iced-rs/iced/tree/0.12
+pub fn input_method_event(ime: winit::event::Ime) -> input_method::Event {
+    match ime {
+        winit::event::Ime::Enabled => input_method::Event::Opened,
+        winit::event::Ime::Preedit(content, selection) => {
+            input_method::Event::Preedit(
+                content,
+                selection.map(|(start, end)| start..end),
+            )
+        }
+        winit::event::Ime::Commit(content) => {
+            input_method::Event::Commit(content)
+        }
+        winit::event::Ime::Disabled => input_method::Event::Closed,
+    }
+}
+
+/// Converts an [`input_method::Purpose`] to a [`winit`] IME purpose.
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+pub fn ime_purpose(
+    purpose: input_method::Purpose,
+) -> winit::window::ImePurpose {
+    match purpose {
+        input_method::Purpose::Normal => winit::window::ImePurpose::Normal,
+        input_method::Purpose::Secure => winit::window::ImePurpose::Password,
+    }
+}
+
 /// Converts a `VirtualKeyCode` from [`winit`] to an [`iced`] key code.
 ///
 /// [`winit`]: https://github.com/rust-windowing/winit
@@ -1,9 +1,13 @@
 //! Create interactive, native cross-platform applications for WGPU.
+mod accessibility;
+mod session;
 mod state;
 mod window_manager;
 
 pub use state::State;
 
+pub use crate::core::theme::{default, Appearance, DefaultStyle};
+
 use crate::conversion;
 use crate::core;
 use crate::core::mouse;
@@ -11,7 +15,7 @@ use crate::core::renderer;
 use crate::core::time::Instant;
 use crate::core::widget::operation;
 use crate::core::window;
-use crate::core::{Color, Element, Point, Size, Theme};
+use crate::core::{Element, Point, Size};
 use crate::futures::futures::channel::mpsc;
 use crate::futures::futures::channel::oneshot;
 use crate::futures::futures::task;
@@ -25,6 +29,8 @@ use crate::runtime::Debug;
 use crate::runtime::{self, Action, Task};
 use crate::{Clipboard, Error, Proxy, Settings};
 
+use accessibility::Accessibility;
+use session::Session;
 use window_manager::WindowManager;
 
 use rustc_hash::FxHashMap;
@@ -42,7 +48,9 @@ use std::sync::Arc;
 /// [`Task`] in some of its methods.
 ///
 /// When using a [`Program`] with the `debug` feature enabled, a debug view
-/// can be toggled by pressing `F12`.
+/// can be toggled by pressing `F12`. Additionally, its events can be recorded
+/// to a file by setting the `ICED_RECORD` environment variable and replayed
+/// later by setting the `ICED_REPLAY` environment variable.
 pub trait Program
 where
     Self: Sized,
@@ -138,37 +146,6 @@ where
     }
 }
 
-/// The appearance of a program.
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub struct Appearance {
-    /// The background [`Color`] of the application.
-    pub background_color: Color,
-
-    /// The default text [`Color`] of the application.
-    pub text_color: Color,
-}
-
-/// The default style of a [`Program`].
-pub trait DefaultStyle {
-    /// Returns the default style of a [`Program`].
-    fn default_style(&self) -> Appearance;
-}
-
-impl DefaultStyle for Theme {
-    fn default_style(&self) -> Appearance {
-        default(self)
-    }
-}
-
-/// The default [`Appearance`] of a [`Program`] with the built-in [`Theme`].
-pub fn default(theme: &Theme) -> Appearance {
-    let palette = theme.extended_palette();
-
-    Appearance {
-        background_color: palette.background.base.color,
-        text_color: palette.background.base.text,
-    }
-}
 /// Runs a [`Program`] with an executor, compositor, and the provided
 /// settings.
 pub fn run<P, C>(
@@ -668,6 +645,8 @@ async fn run_instance<P, C>(
     let mut ui_caches = FxHashMap::default();
     let mut user_interfaces = ManuallyDrop::new(FxHashMap::default());
     let mut clipboard = Clipboard::unconnected();
+    let mut session = Session::from_env();
+    let mut accessibility = Accessibility::new();
 
     debug.startup_finished();
 
@@ -714,6 +693,8 @@ async fn run_instance<P, C>(
                 );
                 let _ = ui_caches.insert(id, user_interface::Cache::default());
 
+                accessibility.open(id, &window.raw);
+
                 if make_visible {
                     window.raw.set_visible(true);
                 }
@@ -769,6 +750,7 @@ async fn run_instance<P, C>(
                             &mut user_interfaces,
                             &mut window_manager,
                             &mut ui_caches,
+                            &mut accessibility,
                             &mut is_window_opening,
                         );
                         actions += 1;
@@ -793,7 +775,7 @@ async fn run_instance<P, C>(
                             window::Event::RedrawRequested(Instant::now()),
                         );
 
-                        let cursor = window.state.cursor();
+                        let cursor = session.cursor(id, window.state.cursor());
 
                         let ui = user_interfaces
                             .get_mut(&id)
@@ -807,6 +789,14 @@ async fn run_instance<P, C>(
                             &mut messages,
                         );
 
+                        if let user_interface::State::Updated {
+                            input_method,
+                            ..
+                        } = ui_state
+                        {
+                            window.request_input_method(input_method);
+                        }
+
                         debug.draw_started();
                         let new_mouse_interaction = ui.draw(
                             &mut window.renderer,
@@ -838,6 +828,7 @@ async fn run_instance<P, C>(
                             match ui_state {
                                 user_interface::State::Updated {
                                     redraw_request: Some(redraw_request),
+                                    ..
                                 } => match redraw_request {
                                     window::RedrawRequest::NextFrame => {
                                         window.raw.request_redraw();
@@ -885,7 +876,7 @@ async fn run_instance<P, C>(
                                     &renderer::Style {
                                         text_color: window.state.text_color(),
                                     },
-                                    window.state.cursor(),
+                                    session.cursor(id, window.state.cursor()),
                                 );
                             debug.draw_finished();
 
@@ -911,6 +902,15 @@ async fn run_instance<P, C>(
                                 window.state.viewport_version();
                         }
 
+                        accessibility.update(
+                            id,
+                            user_interfaces
+                                .get_mut(&id)
+                                .expect("Get user interface"),
+                            &window.renderer,
+                            window.state.scale_factor(),
+                        );
+
                         debug.render_started();
                         match compositor.present(
                             &mut window.renderer,
@@ -989,9 +989,16 @@ async fn run_instance<P, C>(
                                 &mut user_interfaces,
                                 &mut window_manager,
                                 &mut ui_caches,
+                                &mut accessibility,
                                 &mut is_window_opening,
                             );
                         } else {
+                            accessibility.process_event(
+                                id,
+                                &window.raw,
+                                &window_event,
+                            );
+
                             window.state.update(
                                 &window.raw,
                                 &window_event,
@@ -1008,6 +1015,57 @@ async fn run_instance<P, C>(
                         }
                     }
                     event::Event::AboutToWait => {
+                        if let Some(next_event) = session.process(&mut events) {
+                            let _ =
+                                control_sender.start_send(Control::ChangeFlow(
+                                    ControlFlow::WaitUntil(next_event),
+                                ));
+                        }
+
+                        #[cfg(feature = "accessibility")]
+                        for (id, action) in accessibility.actions() {
+                            let (Some(window), Some(ui)) = (
+                                window_manager.get_mut(id),
+                                user_interfaces.get_mut(&id),
+                            ) else {
+                                continue;
+                            };
+
+                            match action {
+                                runtime::accessibility::Action::Operate(
+                                    mut operation,
+                                ) => {
+                                    ui.operate(
+                                        &window.renderer,
+                                        operation.as_mut(),
+                                    );
+                                }
+                                runtime::accessibility::Action::Simulate {
+                                    cursor,
+                                    events,
+                                } => {
+                                    let cursor = cursor
+                                        .map(mouse::Cursor::Available)
+                                        .unwrap_or_else(|| {
+                                            session.cursor(
+                                                id,
+                                                window.state.cursor(),
+                                            )
+                                        });
+
+                                    let _ = ui.update(
+                                        &events,
+                                        cursor,
+                                        &mut window.renderer,
+                                        &mut clipboard,
+                                        &mut messages,
+                                    );
+                                }
+                            }
+
+                            window.raw.request_redraw();
+                        }
+
                         if events.is_empty() && messages.is_empty() {
                             continue;
                         }
@@ -1036,7 +1094,7 @@ async fn run_instance<P, C>(
                                 .expect("Get user interface")
                                 .update(
                                     &window_events,
-                                    window.state.cursor(),
+                                    session.cursor(id, window.state.cursor()),
                                     &mut window.renderer,
                                     &mut clipboard,
                                     &mut messages,
@@ -1188,6 +1246,7 @@ fn run_action<P, C>(
     >,
     window_manager: &mut WindowManager<P, C>,
     ui_caches: &mut FxHashMap<window::Id, user_interface::Cache>,
+    accessibility: &mut Accessibility,
     is_window_opening: &mut bool,
 ) where
     P: Program,
@@ -1195,6 +1254,7 @@ fn run_action<P, C>(
     P::Theme: DefaultStyle,
 {
     use crate::runtime::clipboard;
+    use crate::runtime::font;
     use crate::runtime::system;
     use crate::runtime::window;
 
@@ -1229,6 +1289,7 @@ fn run_action<P, C>(
             window::Action::Close(id) => {
                 let _ = ui_caches.remove(&id);
                 let _ = interfaces.remove(&id);
+                accessibility.close(id);
 
                 if let Some(window) = window_manager.remove(id) {
                     if clipboard.window_id() == Some(window.raw.id()) {
@@ -1480,12 +1541,50 @@ fn run_action<P, C>(
                 }
             }
         }
-        Action::LoadFont { bytes, channel } => {
-            // TODO: Error handling (?)
-            compositor.load_font(bytes.clone());
+        Action::Font(action) => match action {
+            font::Action::Load { bytes, channel } => {
+                // TODO: Error handling (?)
+                compositor.load_font(bytes.clone());
 
-            let _ = channel.send(Ok(()));
-        }
+                let _ = channel.send(Ok(()));
+            }
+            font::Action::List { channel } => {
+                let faces = graphics::text::font_system()
+                    .read()
+                    .expect("Read font system")
+                    .faces();
+
+                let _ = channel.send(faces);
+            }
+            font::Action::IsAvailable { font, channel } => {
+                let is_available = graphics::text::font_system()
+                    .read()
+                    .expect("Read font system")
+                    .is_available(font);
+
+                let _ = channel.send(is_available);
+            }
+            font::Action::SetFallbacks(families) => {
+                graphics::text::font_system()
+                    .write()
+                    .expect("Write font system")
+                    .set_fallbacks(families);
+
+                for (id, window) in window_manager.iter_mut() {
+                    if let Some(ui) = interfaces.remove(&id) {
+                        let _ = interfaces.insert(
+                            id,
+                            ui.relayout(
+                                window.state.logical_size(),
+                                &mut window.renderer,
+                            ),
+                        );
+                    }
+
+                    window.raw.request_redraw();
+                }
+            }
+        },
         Action::Exit => {
             control_sender
                 .start_send(Control::Exit)
@@ -0,0 +1,483 @@
+//! Expose the windows of a [`Program`] to assistive technologies.
+//!
+//! When the `accessibility` feature is enabled, every window gets an
+//! [AccessKit] adapter. The accessibility tree of a window is only built
+//! once an assistive technology, like a screen reader, becomes active.
+//!
+//! The adapters are the platform glue of `accesskit_winit`, written against
+//! the `winit` fork used by this crate.
+//!
+//! [`Program`]: super::Program
+//! [AccessKit]: https://accesskit.dev
+use crate::core::window;
+use crate::runtime::user_interface::UserInterface;
+
+#[cfg(feature = "accessibility")]
+use crate::runtime::accessibility::{self, accesskit};
+#[cfg(feature = "accessibility")]
+use rustc_hash::FxHashMap;
+#[cfg(feature = "accessibility")]
+use std::sync::atomic::{self, AtomicBool};
+#[cfg(feature = "accessibility")]
+use std::sync::{mpsc, Arc};
+
+/// The accessibility adapters of the windows of a [`Program`].
+///
+/// [`Program`]: super::Program
+#[allow(missing_debug_implementations)]
+pub struct Accessibility {
+    #[cfg(feature = "accessibility")]
+    windows: FxHashMap<window::Id, Window>,
+    #[cfg(feature = "accessibility")]
+    sender: mpsc::Sender<(window::Id, accesskit::ActionRequest)>,
+    #[cfg(feature = "accessibility")]
+    receiver: mpsc::Receiver<(window::Id, accesskit::ActionRequest)>,
+}
+
+#[cfg(feature = "accessibility")]
+struct Window {
+    adapter: Adapter,
+    tree: Option<accessibility::Tree>,
+    is_activated: Arc<AtomicBool>,
+}
+
+impl Accessibility {
+    /// Creates a new [`Accessibility`] with no windows.
+    #[cfg(feature = "accessibility")]
+    pub fn new() -> Self {
+        let (sender, receiver) = mpsc::channel();
+
+        Self {
+            windows: FxHashMap::default(),
+            sender,
+            receiver,
+        }
+    }
+
+    /// Creates a new [`Accessibility`] with no windows.
+    #[cfg(not(feature = "accessibility"))]
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    /// Creates the adapter of a new window.
+    ///
+    /// This must be called before the window is made visible.
+    #[cfg(feature = "accessibility")]
+    pub fn open(
+        &mut self,
+        id: window::Id,
+        window: &Arc<winit::window::Window>,
+    ) {
+        let is_activated = Arc::new(AtomicBool::new(false));
+
+        let handler = Handler {
+            id,
+            window: window.clone(),
+            sender: self.sender.clone(),
+            is_activated: is_activated.clone(),
+        };
+
+        let adapter = Adapter::new(
+            window,
+            handler.clone(),
+            handler,
+            NoopDeactivationHandler,
+        );
+
+        let _ = self.windows.insert(
+            id,
+            Window {
+                adapter,
+                tree: None,
+                is_activated,
+            },
+        );
+    }
+
+    /// Creates the adapter of a new window.
+    #[cfg(not(feature = "accessibility"))]
+    pub fn open(
+        &mut self,
+        _id: window::Id,
+        _window: &std::sync::Arc<winit::window::Window>,
+    ) {
+    }
+
+    /// Removes the adapter of a closed window.
+    #[cfg(feature = "accessibility")]
+    pub fn close(&mut self, id: window::Id) {
+        let _ = self.windows.remove(&id);
+    }
+
+    /// Removes the adapter of a closed window.
+    #[cfg(not(feature = "accessibility"))]
+    pub fn close(&mut self, _id: window::Id) {}
+
+    /// Lets the adapter of a window process a window event.
+    #[cfg(feature = "accessibility")]
+    pub fn process_event(
+        &mut self,
+        id: window::Id,
+        window: &winit::window::Window,
+        event: &winit::event::WindowEvent,
+    ) {
+        if let Some(entry) = self.windows.get_mut(&id) {
+            entry.adapter.process_event(window, event);
+        }
+    }
+
+    /// Lets the adapter of a window process a window event.
+    #[cfg(not(feature = "accessibility"))]
+    pub fn process_event(
+        &mut self,
+        _id: window::Id,
+        _window: &winit::window::Window,
+        _event: &winit::event::WindowEvent,
+    ) {
+    }
+
+    /// Updates the accessibility tree of a window with its current
+    /// [`UserInterface`], if any assistive technology is active.
+    #[cfg(feature = "accessibility")]
+    pub fn update<Message, Theme, Renderer>(
+        &mut self,
+        id: window::Id,
+        ui: &mut UserInterface<'_, Message, Theme, Renderer>,
+        renderer: &Renderer,
+        scale_factor: f64,
+    ) where
+        Renderer: crate::core::Renderer,
+    {
+        use crate::core::widget::operation::{self, Operation};
+
+        let Some(Window {
+            adapter,
+            tree,
+            is_activated,
+        }) = self.windows.get_mut(&id)
+        else {
+            return;
+        };
+
+        adapter.update_if_active(|| {
+            let mut builder = accessibility::build();
+
+            ui.operate(renderer, &mut operation::black_box(&mut builder));
+
+            let operation::Outcome::Some(new_tree) = builder.finish() else {
+                unreachable!("accessibility tree is always built");
+            };
+
+            // Assistive technologies need the full tree when they
+            // activate; afterwards, only the nodes that changed are sent
+            let update = match tree {
+                Some(tree)
+                    if !is_activated.swap(false, atomic::Ordering::Relaxed) =>
+                {
+                    new_tree.diff(tree, scale_factor)
+                }
+                _ => new_tree.update(scale_factor),
+            };
+
+            *tree = Some(new_tree);
+
+            update
+        });
+    }
+
+    /// Updates the accessibility tree of a window with its current
+    /// [`UserInterface`], if any assistive technology is active.
+    #[cfg(not(feature = "accessibility"))]
+    pub fn update<Message, Theme, Renderer>(
+        &mut self,
+        _id: window::Id,
+        _ui: &mut UserInterface<'_, Message, Theme, Renderer>,
+        _renderer: &Renderer,
+        _scale_factor: f64,
+    ) where
+        Renderer: crate::core::Renderer,
+    {
+    }
+
+    /// Returns the actions requested by assistive technologies since the
+    /// last call, for each window.
+    #[cfg(feature = "accessibility")]
+    pub fn actions(&mut self) -> Vec<(window::Id, accessibility::Action)> {
+        self.receiver
+            .try_iter()
+            .flat_map(|(id, request)| {
+                self.windows
+                    .get(&id)
+                    .and_then(|window| window.tree.as_ref())
+                    .map(|tree| tree.perform(&request))
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(move |action| (id, action))
+            })
+            .collect()
+    }
+}
+
+#[cfg(feature = "accessibility")]
+#[derive(Clone)]
+struct Handler {
+    id: window::Id,
+    window: Arc<winit::window::Window>,
+    sender: mpsc::Sender<(window::Id, accesskit::ActionRequest)>,
+    is_activated: Arc<AtomicBool>,
+}
+
+#[cfg(feature = "accessibility")]
+impl accesskit::ActivationHandler for Handler {
+    fn request_initial_tree(&mut self) -> Option<accesskit::TreeUpdate> {
+        self.is_activated.store(true, atomic::Ordering::Relaxed);
+
+        // The tree is provided on the next redraw
+        self.window.request_redraw();
+
+        None
+    }
+}
+
+#[cfg(feature = "accessibility")]
+impl accesskit::ActionHandler for Handler {
+    fn do_action(&mut self, request: accesskit::ActionRequest) {
+        let _ = self.sender.send((self.id, request));
+
+        // Wake up the event loop
+        self.window.request_redraw();
+    }
+}
+
+#[cfg(feature = "accessibility")]
+struct NoopDeactivationHandler;
+
+#[cfg(feature = "accessibility")]
+impl accesskit::DeactivationHandler for NoopDeactivationHandler {
+    fn deactivate_accessibility(&mut self) {}
+}
+
+/// The AccessKit adapter of a window for the current platform.
+#[cfg(feature = "accessibility")]
+struct Adapter {
+    #[cfg(target_os = "windows")]
+    raw: accesskit_windows::SubclassingAdapter,
+    #[cfg(target_os = "macos")]
+    raw: accesskit_macos::SubclassingAdapter,
+    #[cfg(any(
+        target_os = "linux",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "netbsd",
+        target_os = "openbsd"
+    ))]
+    raw: accesskit_unix::Adapter,
+}
+
+#[cfg(all(feature = "accessibility", target_os = "windows"))]
+impl Adapter {
+    fn new(
+        window: &winit::window::Window,
+        activation_handler: impl accesskit::ActivationHandler + 'static,
+        action_handler: impl accesskit::ActionHandler + Send + 'static,
+        _deactivation_handler: impl accesskit::DeactivationHandler + 'static,
+    ) -> Self {
+        use crate::core::window::raw_window_handle::{
+            HasWindowHandle, RawWindowHandle,
+        };
+
+        let hwnd = match window.window_handle().map(|handle| handle.as_raw()) {
+            Ok(RawWindowHandle::Win32(handle)) => handle.hwnd.get() as *mut _,
+            _ => unreachable!("windows have a Win32 handle"),
+        };
+
+        Self {
+            raw: accesskit_windows::SubclassingAdapter::new(
+                accesskit_windows::HWND(hwnd),
+                activation_handler,
+                action_handler,
+            ),
+        }
+    }
+
+    fn update_if_active(
+        &mut self,
+        update: impl FnOnce() -> accesskit::TreeUpdate,
+    ) {
+        if let Some(events) = self.raw.update_if_active(update) {
+            events.raise();
+        }
+    }
+
+    fn process_event(
+        &mut self,
+        _window: &winit::window::Window,
+        _event: &winit::event::WindowEvent,
+    ) {
+        // The subclassed window procedure tracks focus by itself
+    }
+}
+
+#[cfg(all(feature = "accessibility", target_os = "macos"))]
+impl Adapter {
+    fn new(
+        window: &winit::window::Window,
+        activation_handler: impl accesskit::ActivationHandler + 'static,
+        action_handler: impl accesskit::ActionHandler + 'static,
+        _deactivation_handler: impl accesskit::DeactivationHandler + 'static,
+    ) -> Self {
+        use crate::core::window::raw_window_handle::{
+            HasWindowHandle, RawWindowHandle,
+        };
+
+        let view = match window.window_handle().map(|handle| handle.as_raw()) {
+            Ok(RawWindowHandle::AppKit(handle)) => handle.ns_view.as_ptr(),
+            _ => unreachable!("macOS windows have an AppKit handle"),
+        };
+
+        // SAFETY: The handlers of the adapter hold an `Arc` of the window,
+        // so its view stays alive throughout the lifetime of the adapter.
+        #[allow(unsafe_code)]
+        let raw = unsafe {
+            accesskit_macos::SubclassingAdapter::new(
+                view,
+                activation_handler,
+                action_handler,
+            )
+        };
+
+        Self { raw }
+    }
+
+    fn update_if_active(
+        &mut self,
+        update: impl FnOnce() -> accesskit::TreeUpdate,
+    ) {
+        if let Some(events) = self.raw.update_if_active(update) {
+            events.raise();
+        }
+    }
+
+    fn process_event(
+        &mut self,
+        _window: &winit::window::Window,
+        event: &winit::event::WindowEvent,
+    ) {
+        if let winit::event::WindowEvent::Focused(is_focused) = event {
+            if let Some(events) = self.raw.update_view_focus_state(*is_focused)
+            {
+                events.raise();
+            }
+        }
+    }
+}
+
+#[cfg(all(
+    feature = "accessibility",
+    any(
+        target_os = "linux",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "netbsd",
+        target_os = "openbsd"
+    )
+))]
+impl Adapter {
+    fn new(
+        _window: &winit::window::Window,
+        activation_handler: impl accesskit::ActivationHandler + Send + 'static,
+        action_handler: impl accesskit::ActionHandler + Send + 'static,
+        deactivation_handler: impl accesskit::DeactivationHandler + Send + 'static,
+    ) -> Self {
+        Self {
+            raw: accesskit_unix::Adapter::new(
+                activation_handler,
+                action_handler,
+                deactivation_handler,
+            ),
+        }
+    }
+
+    fn update_if_active(
+        &mut self,
+        update: impl FnOnce() -> accesskit::TreeUpdate,
+    ) {
+        self.raw.update_if_active(update);
+    }
+
+    fn process_event(
+        &mut self,
+        window: &winit::window::Window,
+        event: &winit::event::WindowEvent,
+    ) {
+        use winit::dpi::{PhysicalPosition, PhysicalSize};
+        use winit::event::WindowEvent;
+
+        fn rectangle(
+            position: Option<PhysicalPosition<i32>>,
+            size: PhysicalSize<u32>,
+        ) -> accesskit::Rect {
+            let (x, y): (f64, f64) =
+                position.unwrap_or_default().cast::<f64>().into();
+            let (width, height): (f64, f64) = size.cast::<f64>().into();
+
+            accesskit::Rect::from_origin_size((x, y), (width, height))
+        }
+
+        match event {
+            WindowEvent::Moved(_) | WindowEvent::Resized(_) => {
+                self.raw.set_root_window_bounds(
+                    rectangle(
+                        window.outer_position().ok(),
+                        window.outer_size(),
+                    ),
+                    rectangle(
+                        window.inner_position().ok(),
+                        window.inner_size(),
+                    ),
+                );
+            }
+            WindowEvent::Focused(is_focused) => {
+                self.raw.update_window_focus_state(*is_focused);
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(all(
+    feature = "accessibility",
+    not(any(
+        target_os = "windows",
+        target_os = "macos",
+        target_os = "linux",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "netbsd",
+        target_os = "openbsd"
+    ))
+))]
+impl Adapter {
+    fn new(
+        _window: &winit::window::Window,
+        _activation_handler: impl accesskit::ActivationHandler + 'static,
+        _action_handler: impl accesskit::ActionHandler + 'static,
+        _deactivation_handler: impl accesskit::DeactivationHandler + 'static,
+    ) -> Self {
+        Self {}
+    }
+
+    fn update_if_active(
+        &mut self,
+        _update: impl FnOnce() -> accesskit::TreeUpdate,
+    ) {
+    }
+
+    fn process_event(
+        &mut self,
+        _window: &winit::window::Window,
+        _event: &winit::event::WindowEvent,
+    ) {
+    }
+}
@@ -0,0 +1,215 @@
+//! Record and replay the event sessions of a [`Program`].
+//!
+//! When the `debug` feature is enabled, a session can be recorded by
+//! setting the `ICED_RECORD` environment variable to the path of the file
+//! where the events will be stored. Then, the session can be replayed by
+//! setting the `ICED_REPLAY` environment variable to the path of that same
+//! file.
+//!
+//! While replaying, any keyboard, mouse, and touch input coming from the
+//! windowing system is ignored. Instead, the recorded input events are fed
+//! to the user interface at the same pace they were recorded.
+//!
+//! [`Program`]: super::Program
+use crate::core::mouse;
+use crate::core::time::Instant;
+use crate::core::window;
+use crate::core::Event;
+
+#[cfg(feature = "debug")]
+use crate::core::touch;
+#[cfg(feature = "debug")]
+use crate::runtime::recording::{Entry, Recorder, Recording};
+#[cfg(feature = "debug")]
+use rustc_hash::FxHashMap;
+#[cfg(feature = "debug")]
+use std::collections::VecDeque;
+
+/// The event session of a [`Program`](super::Program).
+#[derive(Debug)]
+pub struct Session {
+    #[cfg(feature = "debug")]
+    mode: Mode,
+}
+
+#[cfg(feature = "debug")]
+#[derive(Debug)]
+enum Mode {
+    Idle,
+    Recording(Recorder),
+    Replaying {
+        start: Instant,
+        entries: VecDeque<Entry>,
+        cursors: FxHashMap<window::Id, mouse::Cursor>,
+    },
+}
+
+impl Session {
+    /// Creates a new [`Session`] configured by the `ICED_RECORD` and
+    /// `ICED_REPLAY` environment variables.
+    #[cfg(feature = "debug")]
+    pub fn from_env() -> Self {
+        use std::env;
+
+        let mode = if let Ok(path) = env::var("ICED_REPLAY") {
+            match Recording::load(&path) {
+                Ok(recording) => Mode::Replaying {
+                    start: Instant::now(),
+                    entries: recording.into_iter().collect(),
+                    cursors: FxHashMap::default(),
+                },
+                Err(error) => {
+                    log::error!("Failed to load recording {path}: {error}");
+
+                    Mode::Idle
+                }
+            }
+        } else if let Ok(path) = env::var("ICED_RECORD") {
+            match Recorder::create(&path) {
+                Ok(recorder) => Mode::Recording(recorder),
+                Err(error) => {
+                    log::error!("Failed to create recording {path}: {error}");
+
+                    Mode::Idle
+                }
+            }
+        } else {
+            Mode::Idle
+        };
+
+        Self { mode }
+    }
+
+    /// Creates a new [`Session`] configured by the `ICED_RECORD` and
+    /// `ICED_REPLAY` environment variables.
+    #[cfg(not(feature = "debug"))]
+    pub fn from_env() -> Self {
+        Self {}
+    }
+
+    /// Records the given events, if the [`Session`] is recording.
+    ///
+    /// If the [`Session`] is replaying, any input events coming from the
+    /// windowing system are discarded and the recorded events due by now
+    /// are added instead.
+    ///
+    /// It returns the [`Instant`] when the next recorded event is due, if
+    /// any.
+    #[cfg(feature = "debug")]
+    pub fn process(
+        &mut self,
+        events: &mut Vec<(window::Id, Event)>,
+    ) -> Option<Instant> {
+        match &mut self.mode {
+            Mode::Idle => None,
+            Mode::Recording(recorder) => {
+                let result = events
+                    .iter()
+                    .try_for_each(|(id, event)| recorder.record(*id, event))
+                    .and_then(|_| recorder.flush());
+
+                if let Err(error) = result {
+                    log::error!("Failed to record events: {error}");
+
+                    self.mode = Mode::Idle;
+                }
+
+                None
+            }
+            Mode::Replaying {
+                start,
+                entries,
+                cursors,
+            } => {
+                events.retain(|(_id, event)| !is_input(event));
+
+                let elapsed = start.elapsed();
+
+                while entries.front().is_some_and(|entry| entry.time <= elapsed)
+                {
+                    let Some(entry) = entries.pop_front() else {
+                        break;
+                    };
+
+                    if !is_input(&entry.event) {
+                        continue;
+                    }
+
+                    match entry.event {
+                        Event::Mouse(mouse::Event::CursorMoved {
+                            position,
+                        })
+                        | Event::Touch(
+                            touch::Event::FingerPressed { position, .. }
+                            | touch::Event::FingerMoved { position, .. },
+                        ) => {
+                            let _ = cursors.insert(
+                                entry.window,
+                                mouse::Cursor::Available(position),
+                            );
+                        }
+                        Event::Mouse(mouse::Event::CursorLeft) => {
+                            let _ = cursors.insert(
+                                entry.window,
+                                mouse::Cursor::Unavailable,
+                            );
+                        }
+                        _ => {}
+                    }
+
+                    events.push((entry.window, entry.event));
+                }
+
+                entries.front().map(|entry| *start + entry.time)
+            }
+        }
+    }
+
+    /// Records the given events, if the [`Session`] is recording.
+    #[cfg(not(feature = "debug"))]
+    pub fn process(
+        &mut self,
+        _events: &mut Vec<(window::Id, Event)>,
+    ) -> Option<Instant> {
+        None
+    }
+
+    /// Returns the mouse cursor of the given window, taking into account
+    /// any replayed events.
+    #[cfg(feature = "debug")]
+    pub fn cursor(
+        &self,
+        window: window::Id,
+        cursor: mouse::Cursor,
+    ) -> mouse::Cursor {
+        match &self.mode {
+            Mode::Replaying { cursors, .. } => cursors
+                .get(&window)
+                .copied()
+                .unwrap_or(mouse::Cursor::Unavailable),
+            _ => cursor,
+        }
+    }
+
+    /// Returns the mouse cursor of the given window, taking into account
+    /// any replayed events.
+    #[cfg(not(feature = "debug"))]
+    pub fn cursor(
+        &self,
+        _window: window::Id,
+        cursor: mouse::Cursor,
+    ) -> mouse::Cursor {
+        cursor
+    }
+}
+
+#[cfg(feature = "debug")]
+fn is_input(event: &Event) -> bool {
+    matches!(
+        event,
+        Event::Keyboard(_)
+            | Event::Mouse(_)
+            | Event::Touch(_)
+            | Event::InputMethod(_)
+    )
+}